@@ -61,6 +61,11 @@ pub struct NodeConfig {
     #[serde(default)]
     pub enable_event_processing: bool,
 
+    /// Serve the JSON-RPC methods meant for node operators, such as `sui_clearModuleCache`.
+    /// Leave this off on nodes whose JSON-RPC endpoint is public.
+    #[serde(default)]
+    pub enable_rpc_admin_methods: bool,
+
     // TODO: It will be removed down the road.
     /// Epoch duration in ms.
     /// u64::MAX means reconfiguration is disabled
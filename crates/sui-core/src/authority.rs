@@ -14,6 +14,7 @@ use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::language_storage::StructTag;
 use move_core_types::parser::parse_struct_tag;
+use move_core_types::value::MoveStructLayout;
 use move_vm_runtime::{move_vm::MoveVM, native_functions::NativeFunctionTable};
 use mysten_metrics::spawn_monitored_task;
 use parking_lot::Mutex;
@@ -27,7 +28,10 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
-use std::{collections::HashMap, pin::Pin};
+use std::{
+    collections::{HashMap, HashSet},
+    pin::Pin,
+};
 use sui_config::node::AuthorityStorePruningConfig;
 use sui_types::message_envelope::Message;
 use sui_types::parse_sui_struct_tag;
@@ -46,8 +50,8 @@ use narwhal_config::{
 use sui_adapter::{adapter, execution_mode};
 use sui_config::genesis::Genesis;
 use sui_json_rpc_types::{
-    type_and_fields_from_move_struct, DevInspectResults, SuiEvent, SuiEventEnvelope, SuiMoveValue,
-    SuiTransactionEffects,
+    type_and_fields_from_move_struct, DevInspectResults, ObjectOwnershipChange, SuiEvent,
+    SuiEventEnvelope, SuiMoveValue, SuiTransactionEffects,
 };
 use sui_macros::nondeterministic;
 use sui_protocol_config::SupportedProtocolVersions;
@@ -64,8 +68,8 @@ use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName, DynamicFieldT
 use sui_types::event::{Event, EventID};
 use sui_types::gas::{GasCostSummary, GasPrice, SuiCostTable, SuiGasStatus};
 use sui_types::messages_checkpoint::{
-    CheckpointContents, CheckpointContentsDigest, CheckpointDigest, CheckpointSequenceNumber,
-    CheckpointSummary, CheckpointTimestamp,
+    CertifiedCheckpointSummary, CheckpointContents, CheckpointContentsDigest, CheckpointDigest,
+    CheckpointSequenceNumber, CheckpointSummary, CheckpointTimestamp,
 };
 use sui_types::messages_checkpoint::{CheckpointRequest, CheckpointResponse};
 use sui_types::object::{MoveObject, Owner, PastObjectRead};
@@ -96,6 +100,7 @@ use crate::epoch::committee_store::CommitteeStore;
 use crate::epoch::epoch_metrics::EpochMetrics;
 use crate::execution_driver::execution_process;
 use crate::module_cache_gauge::ModuleCacheGauge;
+use crate::ownership_handler::{OwnershipChangeEnvelope, OwnershipHandler};
 use crate::stake_aggregator::StakeAggregator;
 use crate::{
     event_handler::EventHandler, transaction_input_checker, transaction_manager::TransactionManager,
@@ -415,6 +420,9 @@ pub struct AuthorityState {
     pub module_cache: Arc<SyncModuleCache<ResolverWrapper<AuthorityStore>>>, // TODO: use strategies (e.g. LRU?) to constraint memory usage
 
     pub event_handler: Option<Arc<EventHandler>>,
+    /// Notifies subscribers of changes to the objects owned by an address. Only present on nodes
+    /// that keep indexes.
+    pub ownership_handler: Option<Arc<OwnershipHandler>>,
     pub(crate) checkpoint_store: Arc<CheckpointStore>,
 
     committee_store: Arc<CommitteeStore>,
@@ -1122,12 +1130,21 @@ impl AuthorityState {
         cert: &VerifiedExecutableTransaction,
         effects: &TransactionEffects,
         timestamp_ms: u64,
-    ) -> SuiResult<u64> {
+        notify_ownership: bool,
+    ) -> SuiResult<(u64, Vec<OwnershipChangeEnvelope>)> {
         let changes = self
             .process_object_index(effects)
             .tap_err(|e| warn!("{e}"))?;
+        let ownership_changes = if notify_ownership {
+            Self::ownership_changes(indexes, effects, &changes).unwrap_or_else(|e| {
+                warn!(?digest, "Couldn't compute ownership changes for tx: {e}");
+                vec![]
+            })
+        } else {
+            vec![]
+        };
 
-        indexes.index_tx(
+        let seq = indexes.index_tx(
             cert.data().intent_message.value.sender(),
             cert.data()
                 .intent_message
@@ -1144,10 +1161,17 @@ impl AuthorityState {
                 .move_calls()
                 .iter()
                 .map(|mc| (mc.package, mc.module.clone(), mc.function.clone())),
+            cert.data()
+                .intent_message
+                .value
+                .kind
+                .single_transactions()
+                .map(TransactionKindType::from),
             changes,
             digest,
             timestamp_ms,
-        )
+        )?;
+        Ok((seq, ownership_changes))
     }
 
     fn process_object_index(
@@ -1248,6 +1272,57 @@ impl AuthorityState {
         })
     }
 
+    /// Derive the objects each address gained or lost in a transaction from the owner index
+    /// changes of the transaction. Must be called before the changes are applied to the index,
+    /// which still holds the types of the objects that were lost.
+    fn ownership_changes(
+        indexes: &IndexStore,
+        effects: &TransactionEffects,
+        changes: &ObjectIndexChanges,
+    ) -> SuiResult<Vec<OwnershipChangeEnvelope>> {
+        let tx_digest = effects.transaction_digest;
+        // A mutated object that left no old owner entry behind stayed with its owner.
+        let mutated = effects
+            .mutated
+            .iter()
+            .map(|(oref, _)| oref.0)
+            .collect::<HashSet<_>>();
+        let moved = changes
+            .deleted_owners
+            .iter()
+            .map(|(_, id)| *id)
+            .chain(changes.deleted_dynamic_fields.iter().map(|(_, id)| *id))
+            .collect::<HashSet<_>>();
+
+        let mut ownership_changes = vec![];
+        for ((owner, object_id), info) in &changes.new_owners {
+            if mutated.contains(object_id) && !moved.contains(object_id) {
+                continue;
+            }
+            ownership_changes.push(OwnershipChangeEnvelope {
+                owner: *owner,
+                change: ObjectOwnershipChange::Gained {
+                    object_id: *object_id,
+                    type_: info.type_.to_string(),
+                    tx_digest: Some(tx_digest),
+                },
+            });
+        }
+        for (owner, object_id) in &changes.deleted_owners {
+            if let Some(info) = indexes.get_owner_object_info(*owner, *object_id)? {
+                ownership_changes.push(OwnershipChangeEnvelope {
+                    owner: *owner,
+                    change: ObjectOwnershipChange::Lost {
+                        object_id: *object_id,
+                        type_: info.type_.to_string(),
+                        tx_digest,
+                    },
+                });
+            }
+        }
+        Ok(ownership_changes)
+    }
+
     fn try_create_dynamic_field_info(&self, o: &Object) -> SuiResult<Option<DynamicFieldInfo>> {
         // Skip if not a move object
         let Some(move_object) =  o.data.try_as_move().cloned() else {
@@ -1320,6 +1395,11 @@ impl AuthorityState {
 
         // Index tx
         if let Some(indexes) = &self.indexes {
+            // Ownership changes are only computed while someone is subscribed to them
+            let notify_ownership = match &self.ownership_handler {
+                Some(ownership_handler) => ownership_handler.has_subscribers().await,
+                None => false,
+            };
             let res = self
                 .index_tx(
                     indexes.as_ref(),
@@ -1327,12 +1407,20 @@ impl AuthorityState {
                     certificate,
                     effects,
                     timestamp_ms,
+                    notify_ownership,
                 )
                 .tap_ok(|_| self.metrics.post_processing_total_tx_indexed.inc())
                 .tap_err(|e| error!(?tx_digest, "Post processing - Couldn't index tx: {e}"));
 
+            // Notify ownership subscribers
+            if let (Some(ownership_handler), Ok((_, changes))) = (&self.ownership_handler, &res) {
+                if !changes.is_empty() {
+                    ownership_handler.process_changes(changes.clone()).await;
+                }
+            }
+
             // Emit events
-            if let (Some(event_handler), Ok(seq)) = (&self.event_handler, res) {
+            if let (Some(event_handler), Ok((seq, _))) = (&self.event_handler, res) {
                 event_handler
                     .process_events(effects, timestamp_ms, seq)
                     .await
@@ -1522,6 +1610,9 @@ impl AuthorityState {
             handler.regular_cleanup_task();
             Arc::new(handler)
         });
+        let ownership_handler = indexes
+            .as_ref()
+            .map(|_| Arc::new(OwnershipHandler::default()));
         let metrics = Arc::new(AuthorityMetrics::new(prometheus_registry));
         let (tx_ready_certificates, rx_ready_certificates) = unbounded_channel();
         let transaction_manager = Arc::new(TransactionManager::new(
@@ -1553,6 +1644,7 @@ impl AuthorityState {
             // this is because they largely deal with different types of MoveStructs
             module_cache,
             event_handler,
+            ownership_handler,
             checkpoint_store,
             committee_store,
             transaction_manager,
@@ -1836,6 +1928,19 @@ impl AuthorityState {
     }
 
     pub async fn get_object_read(&self, object_id: &ObjectID) -> Result<ObjectRead, SuiError> {
+        self.get_object_read_with_layout(object_id, |object| {
+            object.get_layout(ObjectFormatOptions::default(), self.module_cache.as_ref())
+        })
+        .await
+    }
+
+    /// Like `get_object_read`, but with the layout of an existing object built by `get_layout`,
+    /// so that callers can reuse layouts they have already built.
+    pub async fn get_object_read_with_layout(
+        &self,
+        object_id: &ObjectID,
+        get_layout: impl FnOnce(&Object) -> SuiResult<Option<MoveStructLayout>>,
+    ) -> Result<ObjectRead, SuiError> {
         match self.database.get_latest_parent_entry(*object_id)? {
             None => Ok(ObjectRead::NotExists(*object_id)),
             Some((obj_ref, _)) => {
@@ -1849,10 +1954,7 @@ impl AuthorityState {
                             })
                         }
                         Some(object) => {
-                            let layout = object.get_layout(
-                                ObjectFormatOptions::default(),
-                                self.module_cache.as_ref(),
-                            )?;
+                            let layout = get_layout(&object)?;
                             Ok(ObjectRead::Exists(obj_ref, object, layout))
                         }
                     }
@@ -1963,6 +2065,79 @@ impl AuthorityState {
         }
     }
 
+    /// The objects of the owner, up to `MAX_GET_OWNED_OBJECT_SIZE` of them, along with whether the
+    /// owner owns more objects than were returned.
+    pub fn get_owner_objects_bounded(
+        &self,
+        owner: SuiAddress,
+    ) -> SuiResult<(Vec<ObjectInfo>, bool)> {
+        if let Some(indexes) = &self.indexes {
+            let objects = indexes.get_owner_objects(owner)?;
+            let truncated = match objects.last() {
+                Some(last) if objects.len() == MAX_GET_OWNED_OBJECT_SIZE => {
+                    match last.object_id.next_increment() {
+                        Ok(next) => indexes
+                            .get_owner_objects_iterator(owner, next, 1)?
+                            .next()
+                            .is_some(),
+                        // The last object has the largest possible ID
+                        Err(_) => false,
+                    }
+                }
+                _ => false,
+            };
+            Ok((objects, truncated))
+        } else {
+            Err(SuiError::IndexStoreNotAvailable)
+        }
+    }
+
+    /// A page of up to `MAX_GET_OWNED_OBJECT_SIZE` objects of the owner, starting at object ID
+    /// `start`, along with the start of the next page if the page is full.
+    pub fn get_owner_objects_page(
+        &self,
+        owner: SuiAddress,
+        start: ObjectID,
+    ) -> SuiResult<(Vec<ObjectInfo>, Option<ObjectID>)> {
+        if let Some(indexes) = &self.indexes {
+            let objects = indexes
+                .get_owner_objects_iterator(owner, start, MAX_GET_OWNED_OBJECT_SIZE)?
+                .collect::<Vec<_>>();
+            let next = match objects.last() {
+                Some(last) if objects.len() == MAX_GET_OWNED_OBJECT_SIZE => {
+                    last.object_id.next_increment().ok()
+                }
+                _ => None,
+            };
+            Ok((objects, next))
+        } else {
+            Err(SuiError::IndexStoreNotAvailable)
+        }
+    }
+
+    /// Iterate over all objects of the owner, in object ID order. Unlike
+    /// `get_owner_objects_iterator` there is no limit on the number of objects, the owner index is
+    /// read one page at a time.
+    pub fn get_all_owner_objects_iterator(
+        &self,
+        owner: SuiAddress,
+    ) -> impl Iterator<Item = SuiResult<ObjectInfo>> + '_ {
+        let mut page = vec![].into_iter();
+        let mut next = Some(ObjectID::ZERO);
+        std::iter::from_fn(move || loop {
+            if let Some(info) = page.next() {
+                return Some(Ok(info));
+            }
+            match self.get_owner_objects_page(owner, next.take()?) {
+                Ok((objects, next_start)) => {
+                    page = objects.into_iter();
+                    next = next_start;
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        })
+    }
+
     pub fn get_owner_objects_iterator(
         &self,
         owner: SuiAddress,
@@ -2011,9 +2186,23 @@ impl AuthorityState {
         owner: ObjectID,
         cursor: Option<ObjectID>,
         limit: usize,
+        field_type: Option<DynamicFieldType>,
     ) -> SuiResult<Vec<DynamicFieldInfo>> {
         if let Some(indexes) = &self.indexes {
-            indexes.get_dynamic_fields(owner, cursor, limit)
+            indexes.get_dynamic_fields(owner, cursor, limit, field_type)
+        } else {
+            Err(SuiError::IndexStoreNotAvailable)
+        }
+    }
+
+    pub fn get_objects_by_type(
+        &self,
+        type_: &StructTag,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> SuiResult<Vec<ObjectInfo>> {
+        if let Some(indexes) = &self.indexes {
+            indexes.get_objects_by_type(type_, cursor, limit)
         } else {
             Err(SuiError::IndexStoreNotAvailable)
         }
@@ -2082,6 +2271,60 @@ impl AuthorityState {
             .get_transactions(query, cursor, limit, reverse)
     }
 
+    pub fn get_transactions_from_addr_by_timestamp(
+        &self,
+        address: SuiAddress,
+        start_time: u64,
+        end_time: u64,
+        cursor: Option<(u64, TransactionDigest)>,
+        limit: usize,
+        reverse: bool,
+    ) -> Result<Vec<(u64, TransactionDigest)>, anyhow::Error> {
+        Ok(self
+            .get_indexes()?
+            .get_transactions_from_addr_by_timestamp(
+                address, start_time, end_time, cursor, limit, reverse,
+            )?)
+    }
+
+    /// Returns the digest of the transaction that produced the given effects.
+    pub fn get_transaction_digest_by_effects_digest(
+        &self,
+        effects_digest: &TransactionEffectsDigest,
+    ) -> SuiResult<TransactionDigest> {
+        self.database
+            .get_effects(effects_digest)?
+            .map(|effects| effects.transaction_digest)
+            .ok_or(SuiError::TransactionEffectsNotFound {
+                digest: *effects_digest,
+            })
+    }
+
+    pub fn get_latest_transaction_by_address(
+        &self,
+        address: SuiAddress,
+    ) -> Result<Option<TransactionDigest>, anyhow::Error> {
+        Ok(self
+            .get_indexes()?
+            .get_latest_transaction_by_address(address)?)
+    }
+
+    /// The transactions of the given checkpoint sent from or to `address`, in checkpoint order.
+    pub fn get_checkpoint_transactions_for_address(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+        address: SuiAddress,
+    ) -> Result<Vec<TransactionDigest>, anyhow::Error> {
+        let digests = self
+            .get_checkpoint_contents_by_sequence_number(sequence_number)?
+            .iter()
+            .map(|digests| digests.transaction)
+            .collect();
+        Ok(self
+            .get_indexes()?
+            .filter_transactions_involving_addr(address, digests)?)
+    }
+
     fn get_checkpoint_store(&self) -> Arc<CheckpointStore> {
         self.checkpoint_store.clone()
     }
@@ -2094,15 +2337,120 @@ impl AuthorityState {
             .ok_or_else(|| anyhow!("Latest checkpoint sequence number not found"))
     }
 
+    /// The highest checkpoint synced by this node, which may be ahead of the highest executed
+    /// checkpoint returned by `get_latest_checkpoint_sequence_number`.
+    pub fn get_latest_synced_checkpoint_sequence_number(
+        &self,
+    ) -> Result<CheckpointSequenceNumber, anyhow::Error> {
+        self.get_checkpoint_store()
+            .get_highest_synced_checkpoint_seq_number()?
+            .ok_or_else(|| anyhow!("Latest synced checkpoint sequence number not found"))
+    }
+
+    /// The first checkpoint of `epoch`, known once the previous epoch has ended.
+    pub fn get_epoch_first_checkpoint_sequence_number(
+        &self,
+        epoch: EpochId,
+    ) -> Result<Option<CheckpointSequenceNumber>, anyhow::Error> {
+        if epoch == 0 {
+            return Ok(Some(0));
+        }
+        Ok(self
+            .get_checkpoint_store()
+            .get_epoch_last_checkpoint(epoch - 1)?
+            .map(|checkpoint| checkpoint.summary.sequence_number + 1))
+    }
+
+    /// The last checkpoint of `epoch`, known once the epoch has ended.
+    pub fn get_epoch_last_checkpoint_sequence_number(
+        &self,
+        epoch: EpochId,
+    ) -> Result<Option<CheckpointSequenceNumber>, anyhow::Error> {
+        Ok(self
+            .get_checkpoint_store()
+            .get_epoch_last_checkpoint(epoch)?
+            .map(|checkpoint| checkpoint.summary.sequence_number))
+    }
+
+    /// The reference gas price in effect during `epoch`, read from the system state written by
+    /// the transaction that started it: genesis for the first epoch, the epoch change transaction
+    /// ending the previous epoch otherwise. `None` if the epoch has not started, or if this node
+    /// no longer stores that checkpoint or state.
+    pub fn get_epoch_reference_gas_price(
+        &self,
+        epoch: EpochId,
+    ) -> Result<Option<u64>, anyhow::Error> {
+        let checkpoint = if epoch == 0 {
+            0
+        } else {
+            match self.get_epoch_last_checkpoint_sequence_number(epoch - 1)? {
+                Some(checkpoint) => checkpoint,
+                None => return Ok(None),
+            }
+        };
+        let checkpoint_store = self.get_checkpoint_store();
+        let Some(summary) = checkpoint_store.get_checkpoint_by_sequence_number(checkpoint)? else {
+            return Ok(None);
+        };
+        let Some(contents) =
+            checkpoint_store.get_checkpoint_contents(&summary.into_inner().content_digest())?
+        else {
+            return Ok(None);
+        };
+        // The transaction starting the epoch is the last one of its checkpoint
+        let Some(digests) = contents.iter().last() else {
+            return Ok(None);
+        };
+        let Some(effects) = self.database.get_executed_effects(&digests.transaction)? else {
+            return Ok(None);
+        };
+        let Some(((_, version, _), _)) = effects
+            .created
+            .iter()
+            .chain(&effects.mutated)
+            .find(|((id, _, _), _)| *id == SUI_SYSTEM_STATE_OBJECT_ID)
+        else {
+            return Ok(None);
+        };
+        let Some(object) = self
+            .database
+            .get_object_by_key(&SUI_SYSTEM_STATE_OBJECT_ID, *version)?
+        else {
+            return Ok(None);
+        };
+        let move_object = object
+            .data
+            .try_as_move()
+            .ok_or_else(|| anyhow!("Sui System State object must be a Move object"))?;
+        let system_state = bcs::from_bytes::<SuiSystemState>(move_object.contents())?;
+        Ok(Some(system_state.reference_gas_price))
+    }
+
     pub fn get_checkpoint_summary_by_sequence_number(
         &self,
         sequence_number: CheckpointSequenceNumber,
     ) -> Result<CheckpointSummary, anyhow::Error> {
+        Ok(self
+            .get_certified_checkpoint_by_sequence_number(sequence_number)?
+            .summary)
+    }
+
+    pub fn get_checkpoint_summary_by_digest(
+        &self,
+        digest: CheckpointDigest,
+    ) -> Result<CheckpointSummary, anyhow::Error> {
+        Ok(self.get_certified_checkpoint_by_digest(digest)?.summary)
+    }
+
+    pub fn get_certified_checkpoint_by_sequence_number(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<CertifiedCheckpointSummary, anyhow::Error> {
         let verified_checkpoint = self
             .get_checkpoint_store()
             .get_checkpoint_by_sequence_number(sequence_number)?;
         match verified_checkpoint {
-            Some(verified_checkpoint) => Ok(verified_checkpoint.into_inner().summary),
+            Some(verified_checkpoint) => Ok(verified_checkpoint.into_inner()),
             None => Err(anyhow!(
                 "Verified checkpoint not found for sequence number {}",
                 sequence_number
@@ -2110,15 +2458,15 @@ impl AuthorityState {
         }
     }
 
-    pub fn get_checkpoint_summary_by_digest(
+    pub fn get_certified_checkpoint_by_digest(
         &self,
         digest: CheckpointDigest,
-    ) -> Result<CheckpointSummary, anyhow::Error> {
+    ) -> Result<CertifiedCheckpointSummary, anyhow::Error> {
         let verified_checkpoint = self
             .get_checkpoint_store()
             .get_checkpoint_by_digest(&digest)?;
         match verified_checkpoint {
-            Some(verified_checkpoint) => Ok(verified_checkpoint.into_inner().summary),
+            Some(verified_checkpoint) => Ok(verified_checkpoint.into_inner()),
             None => Err(anyhow!(
                 "Verified checkpoint not found for digest: {}",
                 Base58::encode(digest)
@@ -1084,6 +1084,17 @@ impl AuthorityStore {
             .find_object_lt_or_eq_version(object_id, version)
     }
 
+    /// Returns the latest entry in the parents_sync index for this object with a version less
+    /// than or equal to the given version, including deletion entries.
+    pub fn find_parent_entry_lt_or_eq_version(
+        &self,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    ) -> Result<Option<(ObjectRef, TransactionDigest)>, SuiError> {
+        self.perpetual_tables
+            .find_parent_entry_lt_or_eq_version(object_id, version)
+    }
+
     /// Returns the last entry we have for this object in the parents_sync index used
     /// to facilitate client and authority sync. In turn the latest entry provides the
     /// latest object_reference, and also the latest transaction that has interacted with
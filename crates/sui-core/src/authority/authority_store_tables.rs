@@ -159,6 +159,21 @@ impl AuthorityPerpetualTables {
         iter.reverse().next().map(|(_, o)| o)
     }
 
+    pub fn find_parent_entry_lt_or_eq_version(
+        &self,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    ) -> Result<Option<(ObjectRef, TransactionDigest)>, SuiError> {
+        let mut iterator =
+            self.parent_sync
+                .iter()
+                .skip_prior_to(&(object_id, version, ObjectDigest::MAX))?;
+
+        Ok(iterator
+            .next()
+            .filter(|(obj_ref, _)| obj_ref.0 == object_id))
+    }
+
     pub fn get_latest_parent_entry(
         &self,
         object_id: ObjectID,
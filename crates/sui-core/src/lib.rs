@@ -19,6 +19,7 @@ pub mod metrics;
 mod module_cache_gauge;
 pub mod narwhal_manager;
 mod notify_once;
+pub mod ownership_handler;
 pub mod quorum_driver;
 pub mod safe_client;
 mod stake_aggregator;
@@ -0,0 +1,66 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::StreamExt;
+use tokio_stream::Stream;
+use tracing::error;
+
+use sui_json_rpc_types::ObjectOwnershipChange;
+use sui_types::base_types::SuiAddress;
+use sui_types::filter::Filter;
+
+use crate::event_handler::EVENT_DISPATCH_BUFFER_SIZE;
+use crate::streamer::Streamer;
+
+#[cfg(test)]
+#[path = "unit_tests/ownership_handler_tests.rs"]
+mod ownership_handler_tests;
+
+/// A change to the objects owned by `owner`.
+#[derive(Clone, Debug)]
+pub struct OwnershipChangeEnvelope {
+    pub owner: SuiAddress,
+    pub change: ObjectOwnershipChange,
+}
+
+impl Filter<OwnershipChangeEnvelope> for SuiAddress {
+    fn matches(&self, item: &OwnershipChangeEnvelope) -> bool {
+        &item.owner == self
+    }
+}
+
+/// Dispatches the ownership changes of committed transactions to subscribers of the affected
+/// addresses.
+pub struct OwnershipHandler {
+    streamer: Streamer<OwnershipChangeEnvelope, SuiAddress>,
+}
+
+impl Default for OwnershipHandler {
+    fn default() -> Self {
+        Self {
+            streamer: Streamer::spawn(EVENT_DISPATCH_BUFFER_SIZE),
+        }
+    }
+}
+
+impl OwnershipHandler {
+    pub async fn process_changes(&self, changes: Vec<OwnershipChangeEnvelope>) {
+        // serially dispatch changes to keep them in transaction order.
+        for envelope in changes {
+            if let Err(e) = self.streamer.send(envelope).await {
+                error!(error =? e, "Failed to send OwnershipChangeEnvelope to dispatch");
+            }
+        }
+    }
+
+    /// Whether any address is subscribed to, so that changes are worth computing.
+    pub async fn has_subscribers(&self) -> bool {
+        self.streamer.has_subscribers().await
+    }
+
+    pub fn subscribe(&self, owner: SuiAddress) -> impl Stream<Item = ObjectOwnershipChange> {
+        self.streamer
+            .subscribe(owner)
+            .map(|envelope| envelope.change)
+    }
+}
@@ -19,7 +19,7 @@ use tracing::{debug, warn};
 type Subscribers<T, F> = Arc<RwLock<BTreeMap<String, (Sender<T>, F)>>>;
 
 /// The Streamer splits a mpsc channel into multiple mpsc channels using the subscriber's `Filter<T>` object.
-/// Data will be sent to the subscribers in parallel and the subscription will be dropped if it received a send error.
+/// Data is sent to each subscriber in the order it was received, and the subscription will be dropped if it received a send error.
 pub struct Streamer<T, F: Filter<T>> {
     streamer_queue: Sender<T>,
     subscribers: Subscribers<T, F>,
@@ -46,27 +46,31 @@ where
         streamer
     }
 
+    // Sends are awaited one at a time, rather than spawned, so that a subscriber never receives an
+    // item before the ones received ahead of it.
     async fn send_to_all_subscribers(subscribers: Subscribers<T, F>, data: T) {
         for (id, (subscriber, filter)) in subscribers.read().await.clone() {
             if !(filter.matches(&data)) {
                 continue;
             }
-            let data = data.clone();
-            let subscribers = subscribers.clone();
-            spawn_monitored_task!(async move {
-                match subscriber.send(data).await {
-                    Ok(_) => {
-                        debug!("Sending Move event to subscriber [{id}].")
-                    }
-                    Err(e) => {
-                        subscribers.write().await.remove(&id);
-                        warn!("Error sending event, removing subscriber [{id}] from subscriber list. Error: {e}");
-                    }
+            match subscriber.send(data.clone()).await {
+                Ok(_) => {
+                    debug!("Sending Move event to subscriber [{id}].")
                 }
-            });
+                Err(e) => {
+                    subscribers.write().await.remove(&id);
+                    warn!("Error sending event, removing subscriber [{id}] from subscriber list. Error: {e}");
+                }
+            }
         }
     }
 
+    /// Whether there is any subscriber. Subscribers that went away are only removed once data is
+    /// sent to them, so they may still be counted.
+    pub async fn has_subscribers(&self) -> bool {
+        !self.subscribers.read().await.is_empty()
+    }
+
     /// Subscribe to the data stream filtered by the filter object.
     pub fn subscribe(&self, filter: F) -> impl Stream<Item = T> {
         let handle = Handle::current();
@@ -46,6 +46,7 @@ use sui_macros::sim_test;
 use sui_protocol_config::{ProtocolConfig, SupportedProtocolVersions};
 use sui_types::dynamic_field::DynamicFieldType;
 use sui_types::epoch_data::EpochData;
+use sui_types::messages_checkpoint::VerifiedCheckpoint;
 use sui_types::object::Data;
 use sui_types::{
     base_types::dbg_addr,
@@ -3262,6 +3263,91 @@ async fn test_store_get_dynamic_field() {
     assert_eq!(TypeTag::Bool, fields[0].name.type_)
 }
 
+#[tokio::test]
+async fn test_store_get_dynamic_fields_by_type() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object_id = ObjectID::random();
+    let (authority_state, object_basics) =
+        init_state_with_ids_and_object_basics(vec![(sender, gas_object_id)]).await;
+
+    let mut object_ids = vec![];
+    for _ in 0..5 {
+        let effects = create_move_object(
+            &object_basics.0,
+            &authority_state,
+            &gas_object_id,
+            &sender,
+            &sender_key,
+        )
+        .await
+        .unwrap();
+        assert!(effects.status.is_ok());
+        object_ids.push(effects.created[0].0 .0);
+    }
+    let (outer, inners) = object_ids.split_first().unwrap();
+
+    // Attach two dynamic fields and two dynamic object fields to the same parent.
+    for (function, inner) in [
+        "add_field",
+        "add_ofield",
+        "add_field_with_bytearray_name",
+        "add_ofield_with_bytearray_name",
+    ]
+    .into_iter()
+    .zip(inners)
+    {
+        let effects = call_move(
+            &authority_state,
+            &gas_object_id,
+            &sender,
+            &sender_key,
+            &object_basics.0,
+            "object_basics",
+            function,
+            vec![],
+            vec![TestCallArg::Object(*outer), TestCallArg::Object(*inner)],
+        )
+        .await
+        .unwrap();
+        assert!(effects.status.is_ok());
+    }
+
+    let all_fields = authority_state
+        .get_dynamic_fields(*outer, None, usize::MAX, None)
+        .unwrap();
+    assert_eq!(all_fields.len(), 4);
+
+    for field_type in [
+        DynamicFieldType::DynamicField,
+        DynamicFieldType::DynamicObject,
+    ] {
+        let expected: Vec<_> = all_fields
+            .iter()
+            .filter(|field| field.type_ == field_type)
+            .map(|field| field.object_id)
+            .collect();
+        assert_eq!(expected.len(), 2);
+
+        let fields = authority_state
+            .get_dynamic_fields(*outer, None, usize::MAX, Some(field_type.clone()))
+            .unwrap();
+        assert_eq!(
+            expected,
+            fields
+                .iter()
+                .map(|field| field.object_id)
+                .collect::<Vec<_>>()
+        );
+
+        // The filter is applied before the limit, so a page holds only matching fields.
+        let page = authority_state
+            .get_dynamic_fields(*outer, None, 1, Some(field_type))
+            .unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(expected[0], page[0].object_id);
+    }
+}
+
 async fn create_and_retrieve_df_info(function: &IdentStr) -> (SuiAddress, Vec<DynamicFieldInfo>) {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
     let gas_object_id = ObjectID::random();
@@ -3328,7 +3414,7 @@ async fn create_and_retrieve_df_info(function: &IdentStr) -> (SuiAddress, Vec<Dy
     (
         sender,
         authority_state
-            .get_dynamic_fields(outer_v0.0, None, usize::MAX)
+            .get_dynamic_fields(outer_v0.0, None, usize::MAX, None)
             .unwrap(),
     )
 }
@@ -4545,6 +4631,37 @@ async fn test_blocked_move_calls() {
     );
 }
 
+#[tokio::test]
+async fn test_latest_synced_and_executed_checkpoints() {
+    let state = init_state().await;
+    let (authorities, committee) = make_committee_key(&mut StdRng::from_seed([1u8; 32]));
+    let checkpoint = |seq_num| {
+        VerifiedCheckpoint::new_unchecked(mock_certified_checkpoint(
+            authorities.iter(),
+            committee.clone(),
+            seq_num,
+        ))
+    };
+
+    // State sync has verified checkpoints that are not executed yet.
+    state
+        .checkpoint_store
+        .update_highest_executed_checkpoint(&checkpoint(3))
+        .unwrap();
+    state
+        .checkpoint_store
+        .update_highest_synced_checkpoint(&checkpoint(5))
+        .unwrap();
+
+    assert_eq!(3, state.get_latest_checkpoint_sequence_number().unwrap());
+    assert_eq!(
+        5,
+        state
+            .get_latest_synced_checkpoint_sequence_number()
+            .unwrap()
+    );
+}
+
 #[tokio::test]
 async fn test_tallying_rule_score_updates() {
     let seed = [1u8; 32];
@@ -0,0 +1,53 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::StreamExt;
+use sui_json_rpc_types::ObjectOwnershipChange;
+use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest};
+
+use crate::ownership_handler::{OwnershipChangeEnvelope, OwnershipHandler};
+
+#[tokio::test]
+async fn test_changes_delivered_in_order() {
+    let handler = OwnershipHandler::default();
+    let owner = SuiAddress::random_for_testing_only();
+    let other = SuiAddress::random_for_testing_only();
+    let mut stream = Box::pin(handler.subscribe(owner));
+
+    // Changes to an object gained and lost repeatedly, interleaved with changes for another
+    // subscriber, must reach the owner in the order they were processed.
+    let _other_stream = handler.subscribe(other);
+    let object_id = ObjectID::random();
+    let mut expected = vec![];
+    let mut changes = vec![];
+    for i in 0..200 {
+        let tx_digest = TransactionDigest::random();
+        let change = if i % 2 == 0 {
+            ObjectOwnershipChange::Gained {
+                object_id,
+                type_: "0x2::coin::Coin<0x2::sui::SUI>".into(),
+                tx_digest: Some(tx_digest),
+            }
+        } else {
+            ObjectOwnershipChange::Lost {
+                object_id,
+                type_: "0x2::coin::Coin<0x2::sui::SUI>".into(),
+                tx_digest,
+            }
+        };
+        expected.push(change.clone());
+        changes.push(OwnershipChangeEnvelope { owner, change });
+        changes.push(OwnershipChangeEnvelope {
+            owner: other,
+            change: ObjectOwnershipChange::SnapshotComplete,
+        });
+    }
+    handler.process_changes(changes).await;
+
+    let received = stream
+        .by_ref()
+        .take(expected.len())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(expected, received);
+}
@@ -8,7 +8,7 @@ use jsonrpsee::RpcModule;
 use sui_json_rpc::api::CoinReadApiClient;
 use sui_json_rpc::api::CoinReadApiServer;
 use sui_json_rpc::SuiRpcModule;
-use sui_json_rpc_types::{Balance, CoinPage, SuiCoinMetadata};
+use sui_json_rpc_types::{Balance, Coin, CoinPage, CoinSortOrder, SuiCoinMetadata};
 use sui_open_rpc::Module;
 use sui_types::balance::Supply;
 use sui_types::base_types::{ObjectID, SuiAddress};
@@ -33,9 +33,10 @@ impl CoinReadApiServer for CoinReadApi {
         coin_type: Option<String>,
         cursor: Option<ObjectID>,
         limit: Option<usize>,
+        sort: Option<CoinSortOrder>,
     ) -> RpcResult<CoinPage> {
         self.fullnode
-            .get_coins(owner, coin_type, cursor, limit)
+            .get_coins(owner, coin_type, cursor, limit, sort)
             .await
     }
 
@@ -48,6 +49,18 @@ impl CoinReadApiServer for CoinReadApi {
         self.fullnode.get_all_coins(owner, cursor, limit).await
     }
 
+    async fn select_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        amount: u128,
+        exclude: Option<Vec<ObjectID>>,
+    ) -> RpcResult<Vec<Coin>> {
+        self.fullnode
+            .select_coins(owner, coin_type, amount, exclude)
+            .await
+    }
+
     async fn get_balance(
         &self,
         owner: SuiAddress,
@@ -64,6 +77,13 @@ impl CoinReadApiServer for CoinReadApi {
         self.fullnode.get_coin_metadata(coin_type).await
     }
 
+    async fn multi_get_coin_metadata(
+        &self,
+        coin_types: Vec<String>,
+    ) -> RpcResult<Vec<Option<SuiCoinMetadata>>> {
+        self.fullnode.multi_get_coin_metadata(coin_types).await
+    }
+
     async fn get_total_supply(&self, coin_type: String) -> RpcResult<Supply> {
         self.fullnode.get_total_supply(coin_type).await
     }
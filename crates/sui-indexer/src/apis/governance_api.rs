@@ -46,6 +46,13 @@ impl GovernanceReadApiServer for GovernanceReadApi {
     async fn get_reference_gas_price(&self) -> RpcResult<u64> {
         self.fullnode.get_reference_gas_price().await
     }
+
+    async fn get_reference_gas_price_history(
+        &self,
+        epochs: usize,
+    ) -> RpcResult<Vec<(EpochId, u64)>> {
+        self.fullnode.get_reference_gas_price_history(epochs).await
+    }
 }
 
 impl SuiRpcModule for GovernanceReadApi {
@@ -5,24 +5,36 @@ use crate::errors::IndexerError;
 use crate::models::checkpoints::get_latest_checkpoint_sequence_number;
 use crate::{get_pg_pool_connection, PgConnectionPool};
 use async_trait::async_trait;
+use fastcrypto::encoding::Base64;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::http_client::HttpClient;
-use jsonrpsee::RpcModule;
+use jsonrpsee::types::{SubscriptionEmptyError, SubscriptionResult};
+use jsonrpsee::{RpcModule, SubscriptionSink};
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use sui_json::SuiJsonValue;
 use sui_json_rpc::api::ReadApiClient;
 use sui_json_rpc::api::ReadApiServer;
 use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_types::{
-    Checkpoint, CheckpointId, DynamicFieldPage, GetObjectDataResponse, GetPastObjectDataResponse,
-    GetRawObjectDataResponse, MoveFunctionArgType, SuiMoveNormalizedFunction,
-    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo, SuiTransactionResponse,
-    TransactionsPage,
+    AddressSummary, Checkpoint, CheckpointDigestsPage, CheckpointId, CheckpointSignatureInfo,
+    CheckpointTips, CommandEffect, DynamicFieldPage, EffectsAndEvents, GetObjectDataResponse,
+    GetPastObjectDataResponse, GetRawObjectDataResponse, IntentInfo, ModuleAbi,
+    MoveFunctionArgType, ObjectOwnershipChange, ObjectWithFields, ObjectsPage, OwnedObjectRef,
+    OwnershipProof, PackagePreloadResult, SharedObjectInfo, StorageHistoryPage, SuiExecutionStatus,
+    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
+    SuiMoveNormalizedStructResult, SuiObjectDataOptions, SuiObjectInfo, SuiObjectRef,
+    SuiObjectResponse, SuiTransactionEffects, SuiTransactionResponse,
+    SuiTransactionResponseOptions, SuiTransactionSignature, SuiTransactionStatus,
+    TimestampedTransactionsPage, TransactionTimestampCursor, TransactionsInRangePage,
+    TransactionsPage, TypeCompatibility,
 };
 use sui_open_rpc::Module;
-use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, TxSequenceNumber};
-use sui_types::digests::{CheckpointContentsDigest, CheckpointDigest, TransactionDigest};
-use sui_types::dynamic_field::DynamicFieldName;
+use sui_types::base_types::{ObjectDigest, ObjectID, SequenceNumber, SuiAddress, TxSequenceNumber};
+use sui_types::digests::{
+    CheckpointContentsDigest, CheckpointDigest, TransactionDigest, TransactionEffectsDigest,
+};
+use sui_types::dynamic_field::{DynamicFieldName, DynamicFieldType};
 use sui_types::messages_checkpoint::{
     CheckpointContents, CheckpointSequenceNumber, CheckpointSummary,
 };
@@ -56,14 +68,54 @@ impl ReadApiServer for ReadApi {
         self.fullnode.get_objects_owned_by_address(address).await
     }
 
+    async fn get_objects_owned_by_address_updated_since(
+        &self,
+        address: SuiAddress,
+        updated_since_checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<SuiObjectInfo>> {
+        self.fullnode
+            .get_objects_owned_by_address_updated_since(address, updated_since_checkpoint)
+            .await
+    }
+
+    async fn get_owned_storage_rebate(&self, owner: SuiAddress) -> RpcResult<u64> {
+        self.fullnode.get_owned_storage_rebate(owner).await
+    }
+
+    async fn get_address_summary(&self, address: SuiAddress) -> RpcResult<AddressSummary> {
+        self.fullnode.get_address_summary(address).await
+    }
+
     async fn get_dynamic_fields(
         &self,
         parent_object_id: ObjectID,
         cursor: Option<ObjectID>,
         limit: Option<usize>,
+        field_type: Option<DynamicFieldType>,
     ) -> RpcResult<DynamicFieldPage> {
         self.fullnode
-            .get_dynamic_fields(parent_object_id, cursor, limit)
+            .get_dynamic_fields(parent_object_id, cursor, limit, field_type)
+            .await
+    }
+
+    async fn get_objects_by_type(
+        &self,
+        struct_type: String,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        self.fullnode
+            .get_objects_by_type(struct_type, cursor, limit)
+            .await
+    }
+
+    async fn get_object_with_fields(
+        &self,
+        object_id: ObjectID,
+        fields_limit: Option<usize>,
+    ) -> RpcResult<ObjectWithFields> {
+        self.fullnode
+            .get_object_with_fields(object_id, fields_limit)
             .await
     }
 
@@ -71,6 +123,38 @@ impl ReadApiServer for ReadApi {
         self.fullnode.get_object(object_id).await
     }
 
+    async fn get_object_at_least_version(
+        &self,
+        object_id: ObjectID,
+        min_version: SequenceNumber,
+        timeout_ms: Option<u64>,
+    ) -> RpcResult<GetObjectDataResponse> {
+        self.fullnode
+            .get_object_at_least_version(object_id, min_version, timeout_ms)
+            .await
+    }
+
+    async fn get_object_digest(&self, object_id: ObjectID) -> RpcResult<ObjectDigest> {
+        self.fullnode.get_object_digest(object_id).await
+    }
+
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>> {
+        self.fullnode.multi_get_objects(object_ids).await
+    }
+
+    async fn get_object_with_options(
+        &self,
+        object_id: ObjectID,
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<SuiObjectResponse> {
+        self.fullnode
+            .get_object_with_options(object_id, options)
+            .await
+    }
+
     async fn get_dynamic_field_object(
         &self,
         parent_object_id: ObjectID,
@@ -81,10 +165,29 @@ impl ReadApiServer for ReadApi {
             .await
     }
 
+    async fn get_dynamic_field_object_by_value(
+        &self,
+        parent_object_id: ObjectID,
+        value: SuiJsonValue,
+    ) -> RpcResult<GetObjectDataResponse> {
+        self.fullnode
+            .get_dynamic_field_object_by_value(parent_object_id, value)
+            .await
+    }
+
     async fn get_total_transaction_number(&self) -> RpcResult<u64> {
         self.fullnode.get_total_transaction_number().await
     }
 
+    async fn get_total_transaction_number_at_checkpoint(
+        &self,
+        sequence_number: Option<CheckpointSequenceNumber>,
+    ) -> RpcResult<u64> {
+        self.fullnode
+            .get_total_transaction_number_at_checkpoint(sequence_number)
+            .await
+    }
+
     async fn get_transactions_in_range(
         &self,
         start: TxSequenceNumber,
@@ -93,6 +196,19 @@ impl ReadApiServer for ReadApi {
         self.fullnode.get_transactions_in_range(start, end).await
     }
 
+    async fn get_transactions_in_range_paged(
+        &self,
+        start: TxSequenceNumber,
+        end: TxSequenceNumber,
+        cursor: Option<TxSequenceNumber>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<TransactionsInRangePage> {
+        self.fullnode
+            .get_transactions_in_range_paged(start, end, cursor, limit, descending_order)
+            .await
+    }
+
     async fn get_transaction(
         &self,
         digest: TransactionDigest,
@@ -100,6 +216,84 @@ impl ReadApiServer for ReadApi {
         self.fullnode.get_transaction(digest).await
     }
 
+    async fn get_transaction_with_options(
+        &self,
+        digest: TransactionDigest,
+        options: Option<SuiTransactionResponseOptions>,
+    ) -> RpcResult<SuiTransactionResponse> {
+        self.fullnode
+            .get_transaction_with_options(digest, options)
+            .await
+    }
+
+    async fn get_transaction_status_by_bytes(
+        &self,
+        tx_bytes: Base64,
+    ) -> RpcResult<SuiTransactionStatus> {
+        self.fullnode
+            .get_transaction_status_by_bytes(tx_bytes)
+            .await
+    }
+
+    async fn get_transaction_intent_info(&self, tx_bytes: Base64) -> RpcResult<IntentInfo> {
+        self.fullnode.get_transaction_intent_info(tx_bytes).await
+    }
+
+    async fn get_transaction_by_effects_digest(
+        &self,
+        effects_digest: TransactionEffectsDigest,
+    ) -> RpcResult<SuiTransactionResponse> {
+        self.fullnode
+            .get_transaction_by_effects_digest(effects_digest)
+            .await
+    }
+
+    async fn get_latest_transaction_by_address(
+        &self,
+        address: SuiAddress,
+    ) -> RpcResult<Option<SuiTransactionResponse>> {
+        self.fullnode
+            .get_latest_transaction_by_address(address)
+            .await
+    }
+
+    async fn get_transaction_signatures(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<SuiTransactionSignature>> {
+        self.fullnode.get_transaction_signatures(digest).await
+    }
+
+    async fn get_transaction_effects(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionEffects> {
+        self.fullnode.get_transaction_effects(digest).await
+    }
+
+    async fn get_transaction_effects_and_events(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<EffectsAndEvents> {
+        self.fullnode
+            .get_transaction_effects_and_events(digest)
+            .await
+    }
+
+    async fn get_transaction_command_effects(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<CommandEffect>> {
+        self.fullnode.get_transaction_command_effects(digest).await
+    }
+
+    async fn get_transaction_execution_status(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Option<SuiExecutionStatus>> {
+        self.fullnode.get_transaction_execution_status(digest).await
+    }
+
     async fn get_normalized_move_modules_by_package(
         &self,
         package: ObjectID,
@@ -119,6 +313,10 @@ impl ReadApiServer for ReadApi {
             .await
     }
 
+    async fn get_module_abi(&self, package: ObjectID, module_name: String) -> RpcResult<ModuleAbi> {
+        self.fullnode.get_module_abi(package, module_name).await
+    }
+
     async fn get_normalized_move_struct(
         &self,
         package: ObjectID,
@@ -130,17 +328,49 @@ impl ReadApiServer for ReadApi {
             .await
     }
 
+    async fn multi_get_normalized_move_structs_by_type(
+        &self,
+        types: Vec<String>,
+    ) -> RpcResult<Vec<SuiMoveNormalizedStructResult>> {
+        self.fullnode
+            .multi_get_normalized_move_structs_by_type(types)
+            .await
+    }
+
     async fn get_normalized_move_function(
         &self,
         package: ObjectID,
         module_name: String,
         function_name: String,
+        include_calls: Option<bool>,
     ) -> RpcResult<SuiMoveNormalizedFunction> {
         self.fullnode
-            .get_normalized_move_function(package, module_name, function_name)
+            .get_normalized_move_function(package, module_name, function_name, include_calls)
             .await
     }
 
+    async fn move_function_exists(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        function_name: String,
+    ) -> RpcResult<bool> {
+        self.fullnode
+            .move_function_exists(package, module_name, function_name)
+            .await
+    }
+
+    async fn preload_packages(
+        &self,
+        packages: Vec<ObjectID>,
+    ) -> RpcResult<Vec<PackagePreloadResult>> {
+        self.fullnode.preload_packages(packages).await
+    }
+
+    async fn clear_module_cache(&self, package: Option<ObjectID>) -> RpcResult<usize> {
+        self.fullnode.clear_module_cache(package).await
+    }
+
     async fn get_move_function_arg_types(
         &self,
         package: ObjectID,
@@ -164,6 +394,46 @@ impl ReadApiServer for ReadApi {
             .await
     }
 
+    async fn get_transactions_from_address_by_timestamp(
+        &self,
+        address: SuiAddress,
+        start_time: u64,
+        end_time: u64,
+        cursor: Option<TransactionTimestampCursor>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<TimestampedTransactionsPage> {
+        self.fullnode
+            .get_transactions_from_address_by_timestamp(
+                address,
+                start_time,
+                end_time,
+                cursor,
+                limit,
+                descending_order,
+            )
+            .await
+    }
+
+    fn stream_transactions(
+        &self,
+        mut _sink: SubscriptionSink,
+        _query: TransactionQuery,
+        _start_cursor: Option<TransactionDigest>,
+    ) -> SubscriptionResult {
+        // subscription not supported by subscription yet
+        Err(SubscriptionEmptyError)
+    }
+
+    fn subscribe_address_objects(
+        &self,
+        mut _sink: SubscriptionSink,
+        _address: SuiAddress,
+    ) -> SubscriptionResult {
+        // subscription not supported by subscription yet
+        Err(SubscriptionEmptyError)
+    }
+
     async fn try_get_past_object(
         &self,
         object_id: ObjectID,
@@ -172,14 +442,92 @@ impl ReadApiServer for ReadApi {
         self.fullnode.try_get_past_object(object_id, version).await
     }
 
+    async fn multi_get_objects_at_checkpoint(
+        &self,
+        object_ids: Vec<ObjectID>,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<GetPastObjectDataResponse>> {
+        self.fullnode
+            .multi_get_objects_at_checkpoint(object_ids, checkpoint)
+            .await
+    }
+
+    async fn get_owned_object_refs_consistent(
+        &self,
+        owner: SuiAddress,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<SuiObjectRef>> {
+        self.fullnode
+            .get_owned_object_refs_consistent(owner, object_ids)
+            .await
+    }
+
+    async fn get_shared_object_info(&self, object_id: ObjectID) -> RpcResult<SharedObjectInfo> {
+        self.fullnode.get_shared_object_info(object_id).await
+    }
+
+    async fn get_object_storage_history(
+        &self,
+        object_id: ObjectID,
+        cursor: Option<SequenceNumber>,
+        limit: Option<usize>,
+    ) -> RpcResult<StorageHistoryPage> {
+        self.fullnode
+            .get_object_storage_history(object_id, cursor, limit)
+            .await
+    }
+
     async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<CheckpointSequenceNumber> {
         Ok(self.get_latest_checkpoint_sequence_number().await? as u64)
     }
 
+    async fn get_checkpoint_tips(&self) -> RpcResult<CheckpointTips> {
+        self.fullnode.get_checkpoint_tips().await
+    }
+
     async fn get_checkpoint(&self, id: CheckpointId) -> RpcResult<Checkpoint> {
         self.fullnode.get_checkpoint(id).await
     }
 
+    async fn get_checkpoint_by_transaction(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Checkpoint> {
+        self.fullnode.get_checkpoint_by_transaction(digest).await
+    }
+
+    async fn get_transaction_by_checkpoint_index(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        index: usize,
+    ) -> RpcResult<SuiTransactionResponse> {
+        self.fullnode
+            .get_transaction_by_checkpoint_index(checkpoint, index)
+            .await
+    }
+
+    async fn get_checkpoint_transactions_for_address(
+        &self,
+        id: CheckpointId,
+        address: SuiAddress,
+    ) -> RpcResult<Vec<SuiTransactionResponse>> {
+        self.fullnode
+            .get_checkpoint_transactions_for_address(id, address)
+            .await
+    }
+
+    async fn get_object_ownership_proof(&self, object_id: ObjectID) -> RpcResult<OwnershipProof> {
+        self.fullnode.get_object_ownership_proof(object_id).await
+    }
+
+    async fn get_genesis_checkpoint(&self) -> RpcResult<Checkpoint> {
+        self.fullnode.get_genesis_checkpoint().await
+    }
+
+    async fn get_genesis_objects(&self) -> RpcResult<Vec<OwnedObjectRef>> {
+        self.fullnode.get_genesis_objects().await
+    }
+
     async fn get_checkpoint_summary_by_digest(
         &self,
         digest: CheckpointDigest,
@@ -187,6 +535,13 @@ impl ReadApiServer for ReadApi {
         self.fullnode.get_checkpoint_summary_by_digest(digest).await
     }
 
+    async fn get_checkpoint_signatures(
+        &self,
+        id: CheckpointId,
+    ) -> RpcResult<CheckpointSignatureInfo> {
+        self.fullnode.get_checkpoint_signatures(id).await
+    }
+
     async fn get_checkpoint_summary(
         &self,
         sequence_number: CheckpointSequenceNumber,
@@ -203,6 +558,35 @@ impl ReadApiServer for ReadApi {
             .await
     }
 
+    async fn verify_checkpoint_contents(
+        &self,
+        summary: CheckpointSummary,
+        contents: CheckpointContents,
+    ) -> RpcResult<bool> {
+        self.fullnode
+            .verify_checkpoint_contents(summary, contents)
+            .await
+    }
+
+    async fn get_checkpoint_digests_paged(
+        &self,
+        id: CheckpointId,
+        cursor: Option<usize>,
+        limit: Option<usize>,
+    ) -> RpcResult<CheckpointDigestsPage> {
+        self.fullnode
+            .get_checkpoint_digests_paged(id, cursor, limit)
+            .await
+    }
+
+    async fn export_checkpoints_bcs(
+        &self,
+        start: CheckpointSequenceNumber,
+        end: CheckpointSequenceNumber,
+    ) -> RpcResult<Base64> {
+        self.fullnode.export_checkpoints_bcs(start, end).await
+    }
+
     async fn get_checkpoint_contents(
         &self,
         sequence_number: CheckpointSequenceNumber,
@@ -213,6 +597,25 @@ impl ReadApiServer for ReadApi {
     async fn get_raw_object(&self, object_id: ObjectID) -> RpcResult<GetRawObjectDataResponse> {
         self.fullnode.get_raw_object(object_id).await
     }
+
+    async fn get_object_move_bcs(&self, object_id: ObjectID) -> RpcResult<Base64> {
+        self.fullnode.get_object_move_bcs(object_id).await
+    }
+
+    async fn check_type_compatibility(
+        &self,
+        old: String,
+        new: String,
+    ) -> RpcResult<TypeCompatibility> {
+        self.fullnode.check_type_compatibility(old, new).await
+    }
+
+    async fn get_transaction_dependencies(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<TransactionDigest>> {
+        self.fullnode.get_transaction_dependencies(digest).await
+    }
 }
 
 impl SuiRpcModule for ReadApi {
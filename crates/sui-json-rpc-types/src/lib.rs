@@ -19,9 +19,12 @@ use move_binary_format::normalized::{
     Struct as NormalizedStruct, Type as NormalizedType,
 };
 use move_bytecode_utils::module_cache::GetModule;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
-use move_core_types::value::{MoveStruct, MoveStructLayout, MoveValue};
+use move_core_types::value::{
+    MoveFieldLayout, MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue,
+};
 use schemars::JsonSchema;
 use serde::ser::Error;
 use serde::Deserialize;
@@ -31,12 +34,12 @@ use serde_with::serde_as;
 use sui_json::SuiJsonValue;
 use sui_protocol_config::ProtocolConfig;
 use sui_types::base_types::{
-    ObjectDigest, ObjectID, ObjectInfo, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
-    TransactionEffectsDigest,
+    AuthorityName, ExecutionDigests, ObjectDigest, ObjectID, ObjectInfo, ObjectRef, SequenceNumber,
+    SuiAddress, TransactionDigest, TransactionEffectsDigest, TxSequenceNumber,
 };
 use sui_types::coin::CoinMetadata;
-use sui_types::committee::EpochId;
-use sui_types::crypto::SuiAuthorityStrongQuorumSignInfo;
+use sui_types::committee::{Committee, CommitteeDigest, EpochId, StakeUnit};
+use sui_types::crypto::{PublicKey, SuiAuthorityStrongQuorumSignInfo};
 use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::error::{ExecutionError, SuiError};
 use sui_types::event::{BalanceChangeType, Event, EventID};
@@ -54,11 +57,16 @@ use sui_types::messages_checkpoint::{
     CheckpointTimestamp, EndOfEpochData,
 };
 use sui_types::move_package::{disassemble_modules, MovePackage};
+use sui_types::multisig::{ThresholdUnit, WeightUnit};
 use sui_types::object::{
     Data, MoveObject, Object, ObjectFormatOptions, ObjectRead, Owner, PastObjectRead,
 };
 use sui_types::signature::GenericSignature;
-use sui_types::{parse_sui_struct_tag, parse_sui_type_tag};
+use sui_types::{
+    parse_sui_struct_tag, parse_sui_type_tag, MOVE_STDLIB_ADDRESS, MOVE_STDLIB_OBJECT_ID,
+    SUI_CLOCK_OBJECT_ID, SUI_FRAMEWORK_ADDRESS, SUI_FRAMEWORK_OBJECT_ID,
+    SUI_SYSTEM_STATE_OBJECT_ID,
+};
 use tracing::warn;
 
 #[cfg(test)]
@@ -67,9 +75,14 @@ mod rpc_types_tests;
 
 pub type SuiMoveTypeParameterIndex = u16;
 pub type TransactionsPage = Page<TransactionDigest, TransactionDigest>;
+pub type TimestampedTransactionsPage = Page<TransactionDigest, TransactionTimestampCursor>;
 pub type EventPage = Page<SuiEventEnvelope, EventID>;
 pub type CoinPage = Page<Coin, ObjectID>;
 pub type DynamicFieldPage = Page<DynamicFieldInfo, ObjectID>;
+pub type ObjectsPage = Page<SuiObjectInfo, ObjectID>;
+pub type StorageHistoryPage = Page<StorageHistoryEntry, SequenceNumber>;
+pub type CheckpointDigestsPage = Page<ExecutionDigests, usize>;
+pub type TransactionsInRangePage = Page<TransactionDigest, TxSequenceNumber>;
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -80,6 +93,32 @@ pub struct Balance {
     pub locked_balance: HashMap<EpochId, u128>,
 }
 
+/// An overview of the objects owned by an address, gathered in a single scan of its objects.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressSummary {
+    /// The total balance of the SUI coins of the address, locked coins excluded
+    pub sui_balance: u128,
+    /// The number of coin objects of any coin type, locked coins included
+    pub coin_object_count: usize,
+    /// The total principal of the staked SUI owned by the address
+    pub staked_sui: u128,
+    /// The number of objects of each type, keyed by the type as in `getObjectsOwnedByAddress`
+    pub object_count_by_type: BTreeMap<String, usize>,
+    /// Whether the address owns more objects than the scan covers, in which case the summary only
+    /// reflects the objects returned by `getObjectsOwnedByAddress`
+    pub truncated: bool,
+}
+
+/// The order of the coins within a page of coins. Pages are always cut by object ID, so sorting by
+/// balance only reorders the coins of each page, rather than all coins of the owner.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
+pub enum CoinSortOrder {
+    ObjectId,
+    BalanceDesc,
+    BalanceAsc,
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Coin {
@@ -98,7 +137,32 @@ impl Coin {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+/// A single stored version of an object, as returned by `getObjectStorageHistory`.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageHistoryEntry {
+    pub version: SequenceNumber,
+    /// The digest of the transaction that produced this version
+    pub previous_transaction: TransactionDigest,
+    pub storage_rebate: u64,
+    pub object_size: usize,
+}
+
+/// The references needed to use a shared object as a transaction input, as returned by
+/// `getSharedObjectInfo`.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedObjectInfo {
+    pub object_id: ObjectID,
+    /// The version at which the object became shared
+    pub initial_shared_version: SequenceNumber,
+    /// The latest version of the object known to this node
+    pub version: SequenceNumber,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub enum SuiMoveAbility {
     Copy,
     Drop,
@@ -106,38 +170,66 @@ pub enum SuiMoveAbility {
     Key,
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub struct SuiMoveAbilitySet {
     pub abilities: Vec<SuiMoveAbility>,
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub enum SuiMoveVisibility {
     Private,
     Public,
     Friend,
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub struct SuiMoveStructTypeParameter {
     pub constraints: SuiMoveAbilitySet,
     pub is_phantom: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub struct SuiMoveNormalizedField {
+    /// The position of the field in the struct declaration, which is also its position in the
+    /// BCS encoding of the struct
+    #[serde(default)]
+    pub index: usize,
     pub name: String,
     pub type_: SuiMoveNormalizedType,
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub struct SuiMoveNormalizedStruct {
     pub abilities: SuiMoveAbilitySet,
     pub type_parameters: Vec<SuiMoveStructTypeParameter>,
     pub fields: Vec<SuiMoveNormalizedField>,
+    /// The BCS serialized size of every value of this struct, if it is the same for all values,
+    /// i.e. the struct recursively contains no vectors and no fields of its type parameters.
+    pub fixed_size: Option<usize>,
+}
+
+/// The normalized struct a type string resolved to, or why it could not be resolved.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum SuiMoveNormalizedStructResult {
+    Resolved(SuiMoveNormalizedStruct),
+    Error { error: String },
+}
+
+/// What preloading a package did, one for each package given to `preloadPackages`.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum PackagePreloadResult {
+    /// Some modules of the package were normalized and cached by this call
+    Cached,
+    /// Every module of the package was already cached
+    AlreadyCached,
+    Error {
+        error: String,
+    },
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub enum SuiMoveNormalizedType {
     Bool,
     U8,
@@ -160,19 +252,69 @@ pub enum SuiMoveNormalizedType {
     MutableReference(Box<SuiMoveNormalizedType>),
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
 pub struct SuiMoveNormalizedFunction {
     pub visibility: SuiMoveVisibility,
     pub is_entry: bool,
     pub type_parameters: Vec<SuiMoveAbilitySet>,
     pub parameters: Vec<SuiMoveNormalizedType>,
     pub return_: Vec<SuiMoveNormalizedType>,
+    /// The functions called directly from the body of this function, in the order of their first
+    /// call. Only present when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calls: Option<Vec<SuiMoveFunctionRef>>,
+    /// Whether this is the module initializer, which only runs when the module is published and
+    /// cannot be called by a transaction.
+    #[serde(default)]
+    pub is_init: bool,
+    /// A rough indication of how expensive calling this function is, see
+    /// `SuiMoveFunctionComplexity`. Only present for non-native functions returned by
+    /// `getNormalizedMoveFunction`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub complexity_hint: Option<SuiMoveFunctionComplexity>,
+}
+
+/// A best-effort estimate of the cost of calling a function, derived from the number of
+/// instructions in its body and whether the body loops. It is not authoritative: the actual gas
+/// cost depends on the arguments, on the callees and on the state the function operates on, and
+/// is only known by executing the call.
+#[derive(
+    Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, Eq, PartialEq, Ord, PartialOrd,
+)]
+pub enum SuiMoveFunctionComplexity {
+    /// A short body without loops, such as a getter
+    Low,
+    /// A long body, or a loop with a short body and no calls
+    Medium,
+    /// A very long body, or a loop that is long or calls other functions
+    High,
+}
+
+/// Name of the function that initializes a module when it is published.
+const MODULE_INIT_FN_NAME: &str = "init";
+
+impl SuiMoveNormalizedFunction {
+    /// Convert the function named `name`, which determines whether it is the module initializer.
+    pub fn from_named(name: &str, function: SuiNormalizedFunction) -> Self {
+        Self {
+            is_init: name == MODULE_INIT_FN_NAME,
+            ..function.into()
+        }
+    }
+}
+
+/// A Move function, identified by its defining module.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+pub struct SuiMoveFunctionRef {
+    pub address: String,
+    pub module: String,
+    pub function: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub struct SuiMoveModuleId {
-    address: String,
-    name: String,
+    pub address: String,
+    pub name: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -180,9 +322,138 @@ pub struct SuiMoveNormalizedModule {
     pub file_format_version: u32,
     pub address: String,
     pub name: String,
+    /// The modules this module declares as friends, which may call its `public(friend)` functions
     pub friends: Vec<SuiMoveModuleId>,
     pub structs: BTreeMap<String, SuiMoveNormalizedStruct>,
     pub exposed_functions: BTreeMap<String, SuiMoveNormalizedFunction>,
+    /// The module's constant pool. Constants have no names in bytecode, so they are identified by
+    /// their index in the pool.
+    #[serde(default)]
+    pub constants: Vec<SuiMoveNormalizedConstant>,
+}
+
+/// An entry of a module's constant pool.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+pub struct SuiMoveNormalizedConstant {
+    #[serde(rename = "type")]
+    pub type_: SuiMoveNormalizedType,
+    /// The decoded value, absent if the constant could not be decoded
+    pub value: Option<SuiMoveValue>,
+    /// The BCS serialized value, Base64 encoded
+    #[serde_as(as = "Base64")]
+    #[schemars(with = "Base64")]
+    pub bytes: Vec<u8>,
+}
+
+/// A compact description of the interface of a module, intended for code generation. Types are
+/// written as type strings, e.g. `&mut 0x2::coin::Coin<T0>`, where `T<n>` is the n-th type
+/// parameter of the enclosing struct or function.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleAbi {
+    pub address: String,
+    pub name: String,
+    /// The structs of the module, ordered by name
+    pub structs: Vec<StructAbi>,
+    /// The functions of the module callable from outside of it, ordered by name
+    pub functions: Vec<FunctionAbi>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StructAbi {
+    pub name: String,
+    pub abilities: Vec<SuiMoveAbility>,
+    pub type_parameters: Vec<TypeParameterAbi>,
+    /// The fields of the struct, in declaration order
+    pub fields: Vec<FieldAbi>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldAbi {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionAbi {
+    pub name: String,
+    pub visibility: SuiMoveVisibility,
+    pub is_entry: bool,
+    pub type_parameters: Vec<TypeParameterAbi>,
+    pub parameters: Vec<String>,
+    #[serde(rename = "return")]
+    pub return_: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeParameterAbi {
+    /// The name the parameter is referred to by in type strings, `T<index>`
+    pub name: String,
+    pub constraints: Vec<SuiMoveAbility>,
+    /// Always false for the type parameters of a function
+    pub is_phantom: bool,
+}
+
+impl From<SuiMoveNormalizedModule> for ModuleAbi {
+    fn from(module: SuiMoveNormalizedModule) -> Self {
+        Self {
+            address: module.address,
+            name: module.name,
+            structs: module
+                .structs
+                .into_iter()
+                .map(|(name, struct_)| StructAbi {
+                    name,
+                    abilities: struct_.abilities.abilities,
+                    type_parameters: struct_
+                        .type_parameters
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, parameter)| TypeParameterAbi {
+                            name: format!("T{i}"),
+                            constraints: parameter.constraints.abilities,
+                            is_phantom: parameter.is_phantom,
+                        })
+                        .collect(),
+                    fields: struct_
+                        .fields
+                        .into_iter()
+                        .map(|field| FieldAbi {
+                            name: field.name,
+                            type_: field.type_.to_string(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            functions: module
+                .exposed_functions
+                .into_iter()
+                .map(|(name, function)| FunctionAbi {
+                    name,
+                    visibility: function.visibility,
+                    is_entry: function.is_entry,
+                    type_parameters: function
+                        .type_parameters
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, constraints)| TypeParameterAbi {
+                            name: format!("T{i}"),
+                            constraints: constraints.abilities,
+                            is_phantom: false,
+                        })
+                        .collect(),
+                    parameters: function.parameters.iter().map(|t| t.to_string()).collect(),
+                    return_: function.return_.iter().map(|t| t.to_string()).collect(),
+                })
+                .collect(),
+        }
+    }
 }
 
 impl From<NormalizedModule> for SuiMoveNormalizedModule {
@@ -208,9 +479,11 @@ impl From<NormalizedModule> for SuiMoveNormalizedModule {
                 .exposed_functions
                 .into_iter()
                 .map(|(name, function)| {
-                    (name.to_string(), SuiMoveNormalizedFunction::from(function))
+                    let function = SuiMoveNormalizedFunction::from_named(name.as_str(), function);
+                    (name.to_string(), function)
                 })
                 .collect::<BTreeMap<String, SuiMoveNormalizedFunction>>(),
+            constants: vec![],
         }
     }
 }
@@ -239,6 +512,9 @@ impl From<SuiNormalizedFunction> for SuiMoveNormalizedFunction {
                 .into_iter()
                 .map(SuiMoveNormalizedType::from)
                 .collect::<Vec<SuiMoveNormalizedType>>(),
+            calls: None,
+            is_init: false,
+            complexity_hint: None,
         }
     }
 }
@@ -255,8 +531,10 @@ impl From<NormalizedStruct> for SuiMoveNormalizedStruct {
             fields: struct_
                 .fields
                 .into_iter()
+                .enumerate()
                 .map(SuiMoveNormalizedField::from)
                 .collect::<Vec<SuiMoveNormalizedField>>(),
+            fixed_size: None,
         }
     }
 }
@@ -270,15 +548,49 @@ impl From<StructTypeParameter> for SuiMoveStructTypeParameter {
     }
 }
 
-impl From<NormalizedField> for SuiMoveNormalizedField {
-    fn from(normalized_field: NormalizedField) -> Self {
+impl From<(usize, NormalizedField)> for SuiMoveNormalizedField {
+    fn from((index, normalized_field): (usize, NormalizedField)) -> Self {
         Self {
+            index,
             name: normalized_field.name.to_string(),
             type_: SuiMoveNormalizedType::from(normalized_field.type_),
         }
     }
 }
 
+/// Writes the type in type string notation, e.g. `vector<0x2::coin::Coin<T0>>`.
+impl Display for SuiMoveNormalizedType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SuiMoveNormalizedType::Bool => write!(f, "bool"),
+            SuiMoveNormalizedType::U8 => write!(f, "u8"),
+            SuiMoveNormalizedType::U16 => write!(f, "u16"),
+            SuiMoveNormalizedType::U32 => write!(f, "u32"),
+            SuiMoveNormalizedType::U64 => write!(f, "u64"),
+            SuiMoveNormalizedType::U128 => write!(f, "u128"),
+            SuiMoveNormalizedType::U256 => write!(f, "u256"),
+            SuiMoveNormalizedType::Address => write!(f, "address"),
+            SuiMoveNormalizedType::Signer => write!(f, "signer"),
+            SuiMoveNormalizedType::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                write!(f, "{address}::{module}::{name}")?;
+                if !type_arguments.is_empty() {
+                    write!(f, "<{}>", type_arguments.iter().join(", "))?;
+                }
+                Ok(())
+            }
+            SuiMoveNormalizedType::Vector(t) => write!(f, "vector<{t}>"),
+            SuiMoveNormalizedType::TypeParameter(i) => write!(f, "T{i}"),
+            SuiMoveNormalizedType::Reference(t) => write!(f, "&{t}"),
+            SuiMoveNormalizedType::MutableReference(t) => write!(f, "&mut {t}"),
+        }
+    }
+}
+
 impl From<NormalizedType> for SuiMoveNormalizedType {
     fn from(type_: NormalizedType) -> Self {
         match type_ {
@@ -335,6 +647,224 @@ impl From<AbilitySet> for SuiMoveAbilitySet {
     }
 }
 
+impl From<TypeTag> for SuiMoveNormalizedType {
+    fn from(tag: TypeTag) -> Self {
+        match tag {
+            TypeTag::Bool => SuiMoveNormalizedType::Bool,
+            TypeTag::U8 => SuiMoveNormalizedType::U8,
+            TypeTag::U16 => SuiMoveNormalizedType::U16,
+            TypeTag::U32 => SuiMoveNormalizedType::U32,
+            TypeTag::U64 => SuiMoveNormalizedType::U64,
+            TypeTag::U128 => SuiMoveNormalizedType::U128,
+            TypeTag::U256 => SuiMoveNormalizedType::U256,
+            TypeTag::Address => SuiMoveNormalizedType::Address,
+            TypeTag::Signer => SuiMoveNormalizedType::Signer,
+            TypeTag::Vector(v) => {
+                SuiMoveNormalizedType::Vector(Box::new(SuiMoveNormalizedType::from(*v)))
+            }
+            TypeTag::Struct(s) => SuiMoveNormalizedType::Struct {
+                address: s.address.to_hex_literal(),
+                module: s.module.to_string(),
+                name: s.name.to_string(),
+                type_arguments: s
+                    .type_params
+                    .into_iter()
+                    .map(SuiMoveNormalizedType::from)
+                    .collect::<Vec<SuiMoveNormalizedType>>(),
+            },
+        }
+    }
+}
+
+impl SuiMoveNormalizedType {
+    /// Replace the type parameters in this type with the given type arguments,
+    /// returns an error if a type parameter index is out of bounds.
+    pub fn subst(&self, type_args: &[SuiMoveNormalizedType]) -> Result<Self, anyhow::Error> {
+        Ok(match self {
+            Self::TypeParameter(idx) => type_args
+                .get(*idx as usize)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Type parameter index {idx} out of bounds"))?,
+            Self::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => Self::Struct {
+                address: address.clone(),
+                module: module.clone(),
+                name: name.clone(),
+                type_arguments: type_arguments
+                    .iter()
+                    .map(|t| t.subst(type_args))
+                    .collect::<Result<_, _>>()?,
+            },
+            Self::Vector(t) => Self::Vector(Box::new(t.subst(type_args)?)),
+            Self::Reference(t) => Self::Reference(Box::new(t.subst(type_args)?)),
+            Self::MutableReference(t) => Self::MutableReference(Box::new(t.subst(type_args)?)),
+            _ => self.clone(),
+        })
+    }
+
+    /// Return the layout of this type if values of it can be passed as pure arguments, i.e.
+    /// primitives, vectors of pure values, strings, IDs and options of pure values.
+    pub fn pure_layout(&self) -> Option<MoveTypeLayout> {
+        Some(match self {
+            Self::Bool => MoveTypeLayout::Bool,
+            Self::U8 => MoveTypeLayout::U8,
+            Self::U16 => MoveTypeLayout::U16,
+            Self::U32 => MoveTypeLayout::U32,
+            Self::U64 => MoveTypeLayout::U64,
+            Self::U128 => MoveTypeLayout::U128,
+            Self::U256 => MoveTypeLayout::U256,
+            Self::Address => MoveTypeLayout::Address,
+            Self::Vector(t) => MoveTypeLayout::Vector(Box::new(t.pure_layout()?)),
+            Self::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                let address = AccountAddress::from_hex_literal(address).ok()?;
+                let field = |name: &str, layout| {
+                    MoveFieldLayout::new(Identifier::new(name).unwrap(), layout)
+                };
+                let fields = match (module.as_str(), name.as_str()) {
+                    ("string", "String") | ("ascii", "String")
+                        if address == MOVE_STDLIB_ADDRESS =>
+                    {
+                        vec![field(
+                            "bytes",
+                            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+                        )]
+                    }
+                    ("object", "ID") if address == SUI_FRAMEWORK_ADDRESS => {
+                        vec![field("bytes", MoveTypeLayout::Address)]
+                    }
+                    ("option", "Option")
+                        if address == MOVE_STDLIB_ADDRESS && type_arguments.len() == 1 =>
+                    {
+                        let inner = type_arguments[0].pure_layout()?;
+                        vec![field("vec", MoveTypeLayout::Vector(Box::new(inner)))]
+                    }
+                    _ => return None,
+                };
+                MoveTypeLayout::Struct(MoveStructLayout::WithTypes {
+                    type_: StructTag {
+                        address,
+                        module: Identifier::new(module.as_str()).ok()?,
+                        name: Identifier::new(name.as_str()).ok()?,
+                        type_params: vec![],
+                    },
+                    fields,
+                })
+            }
+            _ => return None,
+        })
+    }
+}
+
+impl SuiMoveNormalizedStruct {
+    /// Return the fields of this struct with all type parameters replaced by the given
+    /// type arguments. The number of type arguments must match the struct's type parameters.
+    pub fn instantiate_fields(
+        &self,
+        type_args: &[SuiMoveNormalizedType],
+    ) -> Result<Vec<SuiMoveNormalizedField>, anyhow::Error> {
+        if type_args.len() != self.type_parameters.len() {
+            return Err(anyhow::anyhow!(
+                "Expected {} type arguments, got {}; generic structs require a concrete instantiation",
+                self.type_parameters.len(),
+                type_args.len()
+            ));
+        }
+        self.fields
+            .iter()
+            .map(|field| {
+                Ok(SuiMoveNormalizedField {
+                    index: field.index,
+                    name: field.name.clone(),
+                    type_: field.type_.subst(type_args)?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum SuiMoveTypeDifference {
+    /// The two structs have a different number of fields.
+    FieldCount { old: usize, new: usize },
+    /// The field at this position has a different name.
+    FieldName {
+        index: usize,
+        old: String,
+        new: String,
+    },
+    /// The field at this position has a different type.
+    FieldType {
+        index: usize,
+        name: String,
+        old: SuiMoveNormalizedType,
+        new: SuiMoveNormalizedType,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeCompatibility {
+    /// True if both types have the same BCS layout, i.e. the same number of fields with the same
+    /// layouts in the same order. Field names are not part of the layout.
+    pub compatible: bool,
+    /// The differences between the two types, including field name differences, which do not
+    /// make the types incompatible.
+    pub differences: Vec<SuiMoveTypeDifference>,
+}
+
+impl TypeCompatibility {
+    /// Compare two lists of fully instantiated fields position by position. Fields of different
+    /// types are only reported if `same_layout` finds that their values are laid out differently,
+    /// as is not the case of structs declared alike in different packages.
+    pub fn check<E>(
+        old: &[SuiMoveNormalizedField],
+        new: &[SuiMoveNormalizedField],
+        mut same_layout: impl FnMut(&SuiMoveNormalizedType, &SuiMoveNormalizedType) -> Result<bool, E>,
+    ) -> Result<Self, E> {
+        let mut compatible = true;
+        let mut differences = vec![];
+        if old.len() != new.len() {
+            compatible = false;
+            differences.push(SuiMoveTypeDifference::FieldCount {
+                old: old.len(),
+                new: new.len(),
+            });
+        }
+        for (index, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+            if old.name != new.name {
+                differences.push(SuiMoveTypeDifference::FieldName {
+                    index,
+                    old: old.name.clone(),
+                    new: new.name.clone(),
+                });
+            }
+            if old.type_ != new.type_ && !same_layout(&old.type_, &new.type_)? {
+                compatible = false;
+                differences.push(SuiMoveTypeDifference::FieldType {
+                    index,
+                    name: new.name.clone(),
+                    old: old.type_.clone(),
+                    new: new.type_.clone(),
+                });
+            }
+        }
+        Ok(Self {
+            compatible,
+            differences,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ObjectValueKind {
     ByImmutableReference,
@@ -348,6 +878,27 @@ pub enum MoveFunctionArgType {
     Object(ObjectValueKind),
 }
 
+/// The object changes of a single command of a programmable transaction.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandEffect {
+    /// The index of the command in the transaction
+    pub command_index: usize,
+    pub created: Vec<OwnedObjectRef>,
+    pub mutated: Vec<OwnedObjectRef>,
+    pub deleted: Vec<SuiObjectRef>,
+}
+
+/// The effects of a transaction along with its events, as read together by indexers.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectsAndEvents {
+    pub effects: SuiTransactionEffects,
+    /// The events of the effects, with the IDs to resume event queries from and the timestamp
+    /// of the transaction.
+    pub events: Vec<SuiEventEnvelope>,
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SuiTransactionResponse {
@@ -360,6 +911,61 @@ pub struct SuiTransactionResponse {
     /// The checkpoint number when this transaction was included and hence finalized.
     /// This is only returned in the read api, not in the transaction execution api.
     pub checkpoint: Option<CheckpointSequenceNumber>,
+    /// The state of the gas coin right after this transaction, present when requested with
+    /// `show_gas_object`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_object: Option<SuiGasObjectState>,
+    /// The transaction and effects digests as they appear in checkpoint contents, present when
+    /// requested with `show_execution_digests`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_digests: Option<ExecutionDigests>,
+}
+
+/// The state of the coin that paid for a transaction's gas, as left by that transaction.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", rename = "GasObjectState")]
+pub struct SuiGasObjectState {
+    pub reference: SuiObjectRef,
+    /// The balance left in the gas coin
+    pub balance: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Default, Eq, PartialEq)]
+#[serde(
+    rename_all = "camelCase",
+    rename = "TransactionResponseOptions",
+    default
+)]
+pub struct SuiTransactionResponseOptions {
+    /// Whether to include the state of the gas coin after the transaction, default to false
+    pub show_gas_object: bool,
+    /// Whether to resolve the types of the objects passed to Move calls, default to false
+    pub show_object_argument_types: bool,
+    /// Whether to include the transaction and effects digests, default to false
+    pub show_execution_digests: bool,
+}
+
+impl SuiTransactionResponseOptions {
+    pub fn gas_object() -> Self {
+        Self {
+            show_gas_object: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn object_argument_types() -> Self {
+        Self {
+            show_object_argument_types: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn execution_digests() -> Self {
+        Self {
+            show_execution_digests: true,
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
@@ -514,6 +1120,7 @@ impl Display for SuiParsedMergeCoinResponse {
 pub type SuiRawObject = SuiObject<SuiRawData>;
 pub type SuiParsedObject = SuiObject<SuiParsedData>;
 
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq)]
 #[serde(rename_all = "camelCase", rename = "Object")]
 pub struct SuiObject<T: SuiData> {
@@ -528,6 +1135,26 @@ pub struct SuiObject<T: SuiData> {
     /// the present storage gas price.
     pub storage_rebate: u64,
     pub reference: SuiObjectRef,
+    /// Why the Move type of the object could not be resolved. The content in `data` then has no
+    /// fields, and the BCS bytes of the object's Move value are returned in `raw_content`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_resolution_error: Option<String>,
+    /// The BCS bytes of the object's Move value, present along with `type_resolution_error`.
+    #[serde_as(as = "Option<Base64>")]
+    #[schemars(with = "Option<Base64>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_content: Option<Vec<u8>>,
+    /// Whether the object is owned by an address, so that it can be received by a transaction
+    /// with a `Receiving` argument. Shared, immutable and object owned objects cannot be received.
+    #[serde(default)]
+    pub receivable: bool,
+    /// Whether the object is one of the well-known objects of the system, such as the system state
+    /// or the clock.
+    #[serde(default)]
+    pub is_system_object: bool,
+    /// Which well-known object of the system this is, present when `is_system_object` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_object_kind: Option<SuiSystemObjectKind>,
 }
 
 impl TryInto<Object> for SuiObject<SuiRawData> {
@@ -653,6 +1280,7 @@ impl<T: SuiData> SuiObject<T> {
 
     pub fn try_from(o: Object, layout: Option<MoveStructLayout>) -> Result<Self, anyhow::Error> {
         let oref = o.compute_object_reference();
+        let system_object_kind = SuiSystemObjectKind::from_id(&oref.0);
         let data = match o.data {
             Data::Move(m) => {
                 let layout = layout.ok_or(SuiError::ObjectSerializationError {
@@ -668,6 +1296,68 @@ impl<T: SuiData> SuiObject<T> {
             previous_transaction: o.previous_transaction,
             storage_rebate: o.storage_rebate,
             reference: oref.into(),
+            type_resolution_error: None,
+            raw_content: None,
+            receivable: is_receivable(&o.owner),
+            is_system_object: system_object_kind.is_some(),
+            system_object_kind,
+        })
+    }
+}
+
+fn is_receivable(owner: &Owner) -> bool {
+    matches!(owner, Owner::AddressOwner(_))
+}
+
+/// The well-known objects of the system, which live at hardcoded object IDs.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
+pub enum SuiSystemObjectKind {
+    MoveStdlib,
+    SuiFramework,
+    SystemState,
+    Clock,
+}
+
+impl SuiSystemObjectKind {
+    /// The kind of the system object with the given ID, or None for any other object. Only the ID
+    /// is compared, the object itself is not read.
+    pub fn from_id(id: &ObjectID) -> Option<Self> {
+        match *id {
+            MOVE_STDLIB_OBJECT_ID => Some(Self::MoveStdlib),
+            SUI_FRAMEWORK_OBJECT_ID => Some(Self::SuiFramework),
+            SUI_SYSTEM_STATE_OBJECT_ID => Some(Self::SystemState),
+            SUI_CLOCK_OBJECT_ID => Some(Self::Clock),
+            _ => None,
+        }
+    }
+}
+
+impl SuiParsedObject {
+    /// Build the response for a Move object whose type could not be resolved, e.g. because the
+    /// package defining it is unavailable. The object reference and the raw BCS bytes of its Move
+    /// value are kept, so that it can still be displayed.
+    pub fn with_unresolved_type(o: Object, error: String) -> Result<Self, anyhow::Error> {
+        let oref = o.compute_object_reference();
+        let system_object_kind = SuiSystemObjectKind::from_id(&oref.0);
+        let m = match o.data {
+            Data::Move(m) => m,
+            Data::Package(_) => return Err(anyhow::anyhow!("Package objects have no Move type")),
+        };
+        Ok(Self {
+            data: SuiParsedData::MoveObject(SuiParsedMoveObject {
+                type_: m.type_.to_string(),
+                has_public_transfer: m.has_public_transfer(),
+                fields: SuiMoveStruct::WithFields(BTreeMap::new()),
+            }),
+            owner: o.owner,
+            previous_transaction: o.previous_transaction,
+            storage_rebate: o.storage_rebate,
+            reference: oref.into(),
+            type_resolution_error: Some(error),
+            raw_content: Some(m.into_contents()),
+            receivable: is_receivable(&o.owner),
+            is_system_object: system_object_kind.is_some(),
+            system_object_kind,
         })
     }
 }
@@ -1067,6 +1757,200 @@ impl<T: SuiData> TryFrom<ObjectRead> for SuiObjectRead<T> {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Default, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", rename = "ObjectDataOptions", default)]
+pub struct SuiObjectDataOptions {
+    /// Whether to include the BCS bytes of the object next to its parsed content, default to false
+    pub show_bcs: bool,
+    /// Whether to include a summary of the transaction that last changed the object, default to
+    /// false
+    pub show_previous_transaction_summary: bool,
+    /// The deepest level of nesting kept in the parsed content, counting the object's fields as
+    /// level 1. Deeper values are replaced by a `{"$truncated": true}` placeholder. Default to
+    /// no limit.
+    pub content_max_depth: Option<usize>,
+    /// Whether to include the abilities of the object's Move type, default to false
+    pub show_type_abilities: bool,
+    /// Whether to look up the objects referred to by the `ID` and `UID` values nested in the
+    /// object's fields and include their types, default to false
+    pub resolve_nested_refs: bool,
+}
+
+impl SuiObjectDataOptions {
+    pub fn bcs() -> Self {
+        Self {
+            show_bcs: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn previous_transaction_summary() -> Self {
+        Self {
+            show_previous_transaction_summary: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn type_abilities() -> Self {
+        Self {
+            show_type_abilities: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn nested_refs() -> Self {
+        Self {
+            resolve_nested_refs: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// An object together with the first page of its dynamic fields.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectWithFields {
+    pub object: GetObjectDataResponse,
+    pub dynamic_fields: DynamicFieldPage,
+}
+
+/// Whether the node has executed a transaction.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename = "TransactionStatus", rename_all = "camelCase", tag = "status")]
+pub enum SuiTransactionStatus {
+    /// The node has not executed the transaction
+    NotExecuted { digest: TransactionDigest },
+    /// The node has executed the transaction, the checkpoint is absent until the transaction is
+    /// included in one
+    Executed {
+        digest: TransactionDigest,
+        checkpoint: Option<CheckpointSequenceNumber>,
+    },
+}
+
+/// The intent a transaction's data is wrapped in before signing, as returned by
+/// `getTransactionIntentInfo`.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IntentInfo {
+    /// The kind of message signed, e.g. `TransactionData`
+    pub scope: String,
+    pub version: u8,
+    /// The application the signature is intended for, e.g. `Sui`
+    pub app_id: String,
+    /// The BCS serialized intent, which prefixes the transaction data in the signed message
+    pub intent_bytes: Base64,
+    /// The digest of the transaction data
+    pub digest: TransactionDigest,
+}
+
+/// A change to the set of objects owned by an address.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum ObjectOwnershipChange {
+    /// The address now owns the object. The transaction digest is absent for objects reported as
+    /// part of the initial snapshot of a subscription.
+    #[serde(rename_all = "camelCase")]
+    Gained {
+        object_id: ObjectID,
+        #[serde(rename = "type")]
+        type_: String,
+        tx_digest: Option<TransactionDigest>,
+    },
+    /// The address no longer owns the object, because it was transferred, wrapped or deleted.
+    #[serde(rename_all = "camelCase")]
+    Lost {
+        object_id: ObjectID,
+        #[serde(rename = "type")]
+        type_: String,
+        tx_digest: TransactionDigest,
+    },
+    /// Every object the address owned when subscribing has been reported.
+    SnapshotComplete,
+}
+
+/// A compact summary of a transaction, describing what last changed an object.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", rename = "TransactionSummary")]
+pub struct SuiTransactionSummary {
+    pub digest: TransactionDigest,
+    pub status: SuiExecutionStatus,
+    pub sender: SuiAddress,
+    pub timestamp_ms: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase", rename = "ObjectResponse")]
+pub struct SuiObjectResponse {
+    pub details: GetObjectDataResponse,
+    /// The raw BCS content of the object, present when requested with `show_bcs`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bcs: Option<SuiRawData>,
+    /// A summary of the transaction that last changed the object, present when requested with
+    /// `show_previous_transaction_summary` unless the object was created at genesis
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_transaction_summary: Option<SuiTransactionSummary>,
+    /// The abilities of the object's Move type, present when requested with `show_type_abilities`
+    /// unless the object is a package
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_abilities: Option<SuiObjectTypeAbilities>,
+    /// The objects referred to from the object's fields, in field order, present when requested
+    /// with `resolve_nested_refs` unless the object is a package
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nested_refs: Option<Vec<SuiNestedObjectRef>>,
+}
+
+/// A reference to another object held in the fields of an object.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiNestedObjectRef {
+    /// The path of the field holding the reference, e.g. `pool.coins[2]`
+    pub path: String,
+    pub object_id: ObjectID,
+    #[serde(flatten)]
+    pub resolution: SuiNestedObjectResolution,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum SuiNestedObjectResolution {
+    /// The referenced object is live
+    Resolved {
+        #[serde(rename = "type")]
+        type_: String,
+        version: SequenceNumber,
+        /// A one line description of the referenced object
+        summary: String,
+    },
+    /// No live object has the referenced ID, e.g. because it is wrapped or was deleted
+    Unresolved,
+    /// The reference was not looked up, the object holds more references than are resolved
+    /// by a single request
+    NotLookedUp,
+}
+
+/// The abilities of an object's Move type, taking its type arguments into account.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiObjectTypeAbilities {
+    pub has_key: bool,
+    /// Whether the object can be transferred or wrapped outside of the module defining its type
+    pub has_store: bool,
+    pub has_copy: bool,
+    pub has_drop: bool,
+}
+
+impl From<AbilitySet> for SuiObjectTypeAbilities {
+    fn from(abilities: AbilitySet) -> Self {
+        Self {
+            has_key: abilities.has_key(),
+            has_store: abilities.has_store(),
+            has_copy: abilities.has_copy(),
+            has_drop: abilities.has_drop(),
+        }
+    }
+}
+
 pub type GetPastObjectDataResponse = SuiPastObjectRead<SuiParsedData>;
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
@@ -1086,6 +1970,11 @@ pub enum SuiPastObjectRead<T: SuiData> {
         asked_version: SequenceNumber,
         latest_version: SequenceNumber,
     },
+    /// The object exists but was created after the asked checkpoint
+    NotCreatedAtCheckpoint {
+        object_id: ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    },
 }
 
 impl<T: SuiData> SuiPastObjectRead<T> {
@@ -1113,6 +2002,10 @@ impl<T: SuiData> SuiPastObjectRead<T> {
                 asked_version: *asked_version,
                 latest_version: *latest_version,
             }),
+            Self::NotCreatedAtCheckpoint { object_id, .. } => Err(SuiError::ObjectNotFound {
+                object_id: *object_id,
+                version: None,
+            }),
         }
     }
 
@@ -1140,6 +2033,10 @@ impl<T: SuiData> SuiPastObjectRead<T> {
                 asked_version,
                 latest_version,
             }),
+            Self::NotCreatedAtCheckpoint { object_id, .. } => Err(SuiError::ObjectNotFound {
+                object_id,
+                version: None,
+            }),
         }
     }
 }
@@ -1180,7 +2077,16 @@ pub enum SuiMoveValue {
     Address(SuiAddress),
     Vector(Vec<SuiMoveValue>),
     String(String),
-    UID { id: ObjectID },
+    UID {
+        id: ObjectID,
+    },
+    /// Placeholder for a value nested deeper than the requested `contentMaxDepth`, serialized as
+    /// `{"$truncated": true}`. `$` cannot appear in a Move identifier, so the placeholder never
+    /// clashes with a struct field.
+    Truncated {
+        #[serde(rename = "$truncated")]
+        truncated: bool,
+    },
     Struct(SuiMoveStruct),
     Option(Box<Option<SuiMoveValue>>),
 }
@@ -1196,9 +2102,32 @@ impl SuiMoveValue {
             SuiMoveValue::Address(v) => json!(v),
             SuiMoveValue::String(v) => json!(v),
             SuiMoveValue::UID { id } => json!({ "id": id }),
+            SuiMoveValue::Truncated { truncated } => json!({ "$truncated": truncated }),
             SuiMoveValue::Option(v) => json!(v),
         }
     }
+
+    fn truncate(&mut self, max_depth: usize, depth: usize) {
+        if depth > max_depth {
+            *self = SuiMoveValue::Truncated { truncated: true };
+            return;
+        }
+        match self {
+            SuiMoveValue::Vector(values) => {
+                for value in values {
+                    value.truncate(max_depth, depth + 1);
+                }
+            }
+            SuiMoveValue::Struct(move_struct) => move_struct.truncate_at(max_depth, depth + 1),
+            // An option wraps its value without adding a level of nesting
+            SuiMoveValue::Option(value) => {
+                if let Some(value) = value.as_mut() {
+                    value.truncate(max_depth, depth);
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 impl Display for SuiMoveValue {
@@ -1210,6 +2139,7 @@ impl Display for SuiMoveValue {
             SuiMoveValue::Address(value) => write!(writer, "{}", value)?,
             SuiMoveValue::String(value) => write!(writer, "{}", value)?,
             SuiMoveValue::UID { id } => write!(writer, "{id}")?,
+            SuiMoveValue::Truncated { .. } => write!(writer, "...")?,
             SuiMoveValue::Struct(value) => write!(writer, "{}", value)?,
             SuiMoveValue::Option(value) => write!(writer, "{:?}", value)?,
             SuiMoveValue::Vector(vec) => {
@@ -1305,6 +2235,27 @@ impl SuiMoveStruct {
             }
         }
     }
+
+    /// Replace every value nested more than `max_depth` levels below this struct with a
+    /// [`SuiMoveValue::Truncated`] placeholder. The struct's own fields are at depth 1.
+    pub fn truncate(&mut self, max_depth: usize) {
+        self.truncate_at(max_depth, 1)
+    }
+
+    fn truncate_at(&mut self, max_depth: usize, depth: usize) {
+        match self {
+            SuiMoveStruct::Runtime(values) => {
+                for value in values {
+                    value.truncate(max_depth, depth);
+                }
+            }
+            SuiMoveStruct::WithTypes { fields, .. } | SuiMoveStruct::WithFields(fields) => {
+                for value in fields.values_mut() {
+                    value.truncate(max_depth, depth);
+                }
+            }
+        }
+    }
 }
 
 impl Display for SuiMoveStruct {
@@ -1523,6 +2474,12 @@ pub struct SuiTransactionData {
     pub transactions: Vec<SuiTransactionKind>,
     pub sender: SuiAddress,
     pub gas_data: SuiGasData,
+    /// The gas budget declared by the transaction, same as `gas_data.budget`.
+    #[serde(default)]
+    pub gas_budget: u64,
+    /// The gas price declared by the transaction, same as `gas_data.price`.
+    #[serde(default)]
+    pub gas_price: u64,
 }
 
 impl Display for SuiTransactionData {
@@ -1568,6 +2525,8 @@ impl TryFrom<TransactionData> for SuiTransactionData {
                 price: data.gas_price(),
                 budget: data.gas_budget(),
             },
+            gas_budget: data.gas_budget(),
+            gas_price: data.gas_price(),
         })
     }
 }
@@ -1579,6 +2538,61 @@ pub struct SuiTransaction {
     pub tx_signatures: Vec<GenericSignature>,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename = "TransactionSignature")]
+pub enum SuiTransactionSignature {
+    /// A signature from a single key pair, encoded as `flag || signature || pubkey`
+    Signature(GenericSignature),
+    MultiSig(SuiMultiSig),
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename = "MultiSig", rename_all = "camelCase")]
+pub struct SuiMultiSig {
+    /// The public keys of the multisig address with their weights
+    pub public_keys: Vec<SuiMultiSigPublicKey>,
+    /// The total weight of signatures required to authenticate the multisig
+    pub threshold: ThresholdUnit,
+    /// The combined signature, encoded as `flag || bcs_bytes(MultiSig)`
+    pub signature: GenericSignature,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename = "MultiSigPublicKey", rename_all = "camelCase")]
+pub struct SuiMultiSigPublicKey {
+    pub public_key: PublicKey,
+    pub weight: WeightUnit,
+    /// Whether a signature of this public key is part of the combined signature
+    pub signed: bool,
+}
+
+impl From<GenericSignature> for SuiTransactionSignature {
+    fn from(signature: GenericSignature) -> Self {
+        match &signature {
+            GenericSignature::Signature(_) => Self::Signature(signature),
+            GenericSignature::MultiSig(multisig) => {
+                let signer_indices = multisig.signer_indices();
+                let public_keys = multisig
+                    .multisig_pk
+                    .pubkeys()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (public_key, weight))| SuiMultiSigPublicKey {
+                        public_key: public_key.clone(),
+                        weight: *weight,
+                        signed: signer_indices.contains(&(index as u32)),
+                    })
+                    .collect();
+                Self::MultiSig(SuiMultiSig {
+                    public_keys,
+                    threshold: *multisig.multisig_pk.threshold(),
+                    signature,
+                })
+            }
+        }
+    }
+}
+
 impl TryFrom<SenderSignedData> for SuiTransaction {
     type Error = anyhow::Error;
 
@@ -1775,6 +2789,7 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
                         )),
                     })
                     .collect::<Result<Vec<_>, _>>()?,
+                object_arguments: None,
             }),
             SingleTransactionKind::ChangeEpoch(e) => Self::ChangeEpoch(SuiChangeEpoch {
                 epoch: e.epoch,
@@ -1808,6 +2823,56 @@ pub struct SuiMoveCall {
     pub type_arguments: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub arguments: Vec<SuiJsonValue>,
+    /// The objects passed to the call, in argument order, present when requested with
+    /// `show_object_argument_types`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object_arguments: Option<Vec<SuiMoveCallObjectArgument>>,
+}
+
+/// An object passed to a Move call, as read by the transaction.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiMoveCallObjectArgument {
+    /// The position of the argument in the call. The objects of a vector argument share it.
+    pub index: usize,
+    pub object_id: ObjectID,
+    /// The version of the object the transaction read
+    pub version: SequenceNumber,
+    /// The type of the object, absent if it could not be resolved, e.g. because the version read
+    /// by the transaction has been pruned
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+}
+
+impl SuiMoveCall {
+    /// Decode the pure arguments of the call using the parameter types of the called function.
+    /// Arguments whose type can't be resolved keep their untyped decoding.
+    pub fn decode_pure_arguments(
+        &mut self,
+        arguments: &[CallArg],
+        parameters: &[SuiMoveNormalizedType],
+        type_arguments: &[SuiMoveNormalizedType],
+    ) {
+        for ((argument, sui_argument), parameter) in arguments
+            .iter()
+            .zip(self.arguments.iter_mut())
+            .zip(parameters)
+        {
+            if let CallArg::Pure(bytes) = argument {
+                let value = parameter
+                    .subst(type_arguments)
+                    .ok()
+                    .and_then(|type_| type_.pure_layout())
+                    .and_then(|layout| MoveValue::simple_deserialize(bytes, &layout).ok())
+                    .and_then(|value| {
+                        SuiJsonValue::new(SuiMoveValue::from(value).to_json_value()).ok()
+                    });
+                if let Some(value) = value {
+                    *sui_argument = value;
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -1927,6 +2992,20 @@ impl SuiTransactionEffects {
     pub fn try_from(
         effect: TransactionEffects,
         resolver: &impl GetModule,
+    ) -> Result<Self, anyhow::Error> {
+        Self::try_from_with_layouts(effect, |type_| {
+            MoveObject::get_layout_from_struct_tag(
+                type_.clone(),
+                ObjectFormatOptions::default(),
+                resolver,
+            )
+        })
+    }
+
+    /// Like `try_from`, but with the layouts of Move event types built by `get_layout`.
+    pub fn try_from_with_layouts(
+        effect: TransactionEffects,
+        get_layout: impl Fn(&StructTag) -> Result<MoveStructLayout, SuiError>,
     ) -> Result<Self, anyhow::Error> {
         Ok(Self {
             status: effect.status.into(),
@@ -1947,7 +3026,7 @@ impl SuiTransactionEffects {
             events: effect
                 .events
                 .into_iter()
-                .map(|event| SuiEvent::try_from(event, resolver))
+                .map(|event| SuiEvent::try_from_with_layout(event, &get_layout))
                 .collect::<Result<_, _>>()?,
             dependencies: effect.dependencies,
         })
@@ -2367,6 +3446,20 @@ impl TryFrom<SuiEvent> for Event {
 
 impl SuiEvent {
     pub fn try_from(event: Event, resolver: &impl GetModule) -> Result<Self, anyhow::Error> {
+        Self::try_from_with_layout(event, |type_| {
+            MoveObject::get_layout_from_struct_tag(
+                type_.clone(),
+                ObjectFormatOptions::default(),
+                resolver,
+            )
+        })
+    }
+
+    /// Like `try_from`, but with the layout of a Move event's type built by `get_layout`.
+    pub fn try_from_with_layout(
+        event: Event,
+        get_layout: impl Fn(&StructTag) -> Result<MoveStructLayout, SuiError>,
+    ) -> Result<Self, anyhow::Error> {
         Ok(match event {
             Event::MoveEvent {
                 package_id,
@@ -2378,8 +3471,9 @@ impl SuiEvent {
                 let bcs = contents.to_vec();
 
                 let (type_, fields) = if let Ok(move_struct) =
-                    Event::move_event_to_move_struct(&type_, &contents, resolver)
-                {
+                    get_layout(&type_).and_then(|layout| {
+                        Event::move_event_to_move_struct_with_layout(&contents, &layout)
+                    }) {
                     let (type_, field) = type_and_fields_from_move_struct(&type_, move_struct);
                     (type_, Some(field))
                 } else {
@@ -2963,6 +4057,70 @@ pub struct Page<T, C> {
     pub next_cursor: Option<C>,
 }
 
+/// A position in transactions ordered by timestamp, where transactions sharing a timestamp are
+/// ordered by digest.
+#[derive(Clone, Copy, Debug, JsonSchema, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTimestampCursor {
+    pub timestamp_ms: u64,
+    pub digest: TransactionDigest,
+}
+
+/// The highest checkpoints a node has synced and executed, as returned by `getCheckpointTips`.
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointTips {
+    /// The highest checkpoint whose certified summary the node has synced
+    pub highest_synced: CheckpointSequenceNumber,
+    /// The highest checkpoint the node has executed, reads reflect the state up to this checkpoint
+    pub highest_executed: CheckpointSequenceNumber,
+}
+
+/// The validators that signed a checkpoint certificate, as returned by `getCheckpointSignatures`.
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointSignatureInfo {
+    /// The epoch of the committee that signed the checkpoint
+    pub epoch: EpochId,
+    pub sequence_number: CheckpointSequenceNumber,
+    /// The signing validators with their voting power, in committee order
+    pub signers: Vec<(AuthorityName, StakeUnit)>,
+    /// The total voting power of the signing validators
+    pub signed_stake: StakeUnit,
+    /// The voting power needed to certify a checkpoint
+    pub quorum_threshold: StakeUnit,
+    /// The total voting power of the committee
+    pub total_stake: StakeUnit,
+}
+
+/// The data proving that an object was owned by `owner` as of a checkpoint, as returned by
+/// `getObjectOwnershipProof`. Given a trusted summary of the checkpoint, a client verifies that:
+/// 1. `checkpoint_summary` is that trusted summary;
+/// 2. the digest of `checkpoint_contents` is the `content_digest` of the summary;
+/// 3. `checkpoint_contents` include `transaction_digest` together with the digest of `effects`,
+///    which is the SHA3-256 hash of `TransactionEffects::` followed by the BCS bytes;
+/// 4. `effects` are the effects of `transaction_digest`, and list `object_ref` as created, mutated
+///    or unwrapped with `owner` as its owner.
+///
+/// This only proves ownership as of the checkpoint, as a later transaction may have changed it.
+#[serde_as]
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnershipProof {
+    /// The reference of the object, as left by the transaction that last changed it
+    pub object_ref: SuiObjectRef,
+    pub owner: Owner,
+    /// The transaction that last changed the object
+    pub transaction_digest: TransactionDigest,
+    /// The BCS serialized effects of the transaction
+    #[serde_as(as = "Base64")]
+    #[schemars(with = "Base64")]
+    pub effects: Vec<u8>,
+    /// The checkpoint that includes the transaction
+    pub checkpoint_summary: CheckpointSummary,
+    pub checkpoint_contents: CheckpointContents,
+}
+
 #[derive(Clone, Debug, JsonSchema, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Checkpoint {
@@ -2986,8 +4144,25 @@ pub struct Checkpoint {
     pub timestamp_ms: CheckpointTimestamp,
     /// Present only on the final checkpoint of the epoch.
     pub end_of_epoch_data: Option<EndOfEpochData>,
+    /// Whether this is the final checkpoint of the epoch.
+    #[serde(default)]
+    pub end_of_epoch: bool,
+    /// Digest of the committee of the next epoch, present only on the final checkpoint of the
+    /// epoch.
+    pub next_epoch_committee_digest: Option<CommitteeDigest>,
     /// Transaction digests
     pub transactions: Vec<TransactionDigest>,
+    /// Sequence number of the first checkpoint of this checkpoint's epoch, absent if the node
+    /// does not know where the epoch started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epoch_first_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Zero-based position of this checkpoint within its epoch, present along with
+    /// `epoch_first_checkpoint`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epoch_checkpoint_index: Option<u64>,
+    /// Number of checkpoints of this checkpoint's epoch, only known once the epoch has ended.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epoch_checkpoint_count: Option<u64>,
 }
 
 impl From<(CheckpointSummary, CheckpointContents)> for Checkpoint {
@@ -3003,6 +4178,15 @@ impl From<(CheckpointSummary, CheckpointContents)> for Checkpoint {
             end_of_epoch_data,
             ..
         } = summary;
+        let next_epoch_committee_digest = end_of_epoch_data.as_ref().and_then(|data| {
+            Committee::new(
+                epoch + 1,
+                data.next_epoch_protocol_version,
+                data.next_epoch_committee.iter().cloned().collect(),
+            )
+            .ok()
+            .map(|committee| committee.digest())
+        });
 
         Checkpoint {
             epoch,
@@ -3012,8 +4196,13 @@ impl From<(CheckpointSummary, CheckpointContents)> for Checkpoint {
             previous_digest,
             epoch_rolling_gas_cost_summary,
             timestamp_ms,
+            end_of_epoch: end_of_epoch_data.is_some(),
             end_of_epoch_data,
+            next_epoch_committee_digest,
             transactions: contents.iter().map(|digest| digest.transaction).collect(),
+            epoch_first_checkpoint: None,
+            epoch_checkpoint_index: None,
+            epoch_checkpoint_count: None,
         }
     }
 }
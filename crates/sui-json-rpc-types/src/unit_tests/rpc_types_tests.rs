@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use anyhow::anyhow;
@@ -8,14 +9,24 @@ use move_core_types::ident_str;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
 use move_core_types::value::{MoveStruct, MoveValue};
+use serde_json::json;
 
+use sui_json::SuiJsonValue;
 use sui_types::base_types::SequenceNumber;
 use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::crypto::{get_key_pair, Signature, SuiKeyPair};
 use sui_types::gas_coin::GasCoin;
+use sui_types::intent::{Intent, IntentMessage, PersonalMessage};
+use sui_types::messages::CallArg;
+use sui_types::multisig::{MultiSig, MultiSigPublicKey};
 use sui_types::object::MoveObject;
+use sui_types::signature::GenericSignature;
 use sui_types::{MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS};
 
-use crate::{SuiMoveStruct, SuiMoveValue};
+use crate::{
+    SuiMoveCall, SuiMoveNormalizedField, SuiMoveNormalizedType, SuiMoveStruct,
+    SuiMoveTypeDifference, SuiMoveValue, SuiTransactionSignature, TypeCompatibility,
+};
 
 #[test]
 fn test_move_value_to_sui_coin() {
@@ -124,6 +135,7 @@ fn test_serde() {
         SuiMoveValue::Address(SuiAddress::random_for_testing_only()),
         SuiMoveValue::Bool(true),
         SuiMoveValue::Option(Box::new(None)),
+        SuiMoveValue::Truncated { truncated: true },
         SuiMoveValue::Vector(vec![
             SuiMoveValue::Number(1000000),
             SuiMoveValue::Number(2000000),
@@ -143,3 +155,281 @@ fn test_serde() {
         )
     }
 }
+
+#[test]
+fn test_truncate_move_struct() {
+    let nested = |value| {
+        SuiMoveValue::Struct(SuiMoveStruct::WithFields(BTreeMap::from([(
+            "inner".to_string(),
+            value,
+        )])))
+    };
+    let fields = |deep| {
+        SuiMoveStruct::WithFields(BTreeMap::from([
+            ("flat".to_string(), SuiMoveValue::Bool(true)),
+            ("deep".to_string(), nested(nested(deep))),
+        ]))
+    };
+    let truncated = SuiMoveValue::Truncated { truncated: true };
+
+    // The innermost value is at depth 3
+    let mut move_struct = fields(SuiMoveValue::Number(1));
+    move_struct.truncate(3);
+    assert_eq!(fields(SuiMoveValue::Number(1)), move_struct);
+
+    move_struct.truncate(2);
+    assert_eq!(fields(truncated.clone()), move_struct);
+
+    move_struct.truncate(0);
+    assert_eq!(
+        SuiMoveStruct::WithFields(BTreeMap::from([
+            ("flat".to_string(), truncated.clone()),
+            ("deep".to_string(), truncated),
+        ])),
+        move_struct
+    );
+}
+
+fn field(index: usize, name: &str, type_: SuiMoveNormalizedType) -> SuiMoveNormalizedField {
+    SuiMoveNormalizedField {
+        index,
+        name: name.to_string(),
+        type_,
+    }
+}
+
+// Compare without resolving layouts, so that any two different types are laid out differently.
+fn check(old: &[SuiMoveNormalizedField], new: &[SuiMoveNormalizedField]) -> TypeCompatibility {
+    TypeCompatibility::check(old, new, |_, _| Ok::<_, ()>(false)).unwrap()
+}
+
+#[test]
+fn test_type_compatibility_same_layout() {
+    let old = vec![
+        field(0, "id", SuiMoveNormalizedType::Address),
+        field(1, "value", SuiMoveNormalizedType::U64),
+    ];
+    let new = old.clone();
+    let compatibility = check(&old, &new);
+    assert!(compatibility.compatible);
+    assert!(compatibility.differences.is_empty());
+}
+
+#[test]
+fn test_type_compatibility_reordered_fields() {
+    let old = vec![
+        field(0, "id", SuiMoveNormalizedType::Address),
+        field(1, "value", SuiMoveNormalizedType::U64),
+    ];
+    let new = vec![
+        field(0, "value", SuiMoveNormalizedType::U64),
+        field(1, "id", SuiMoveNormalizedType::Address),
+    ];
+    let compatibility = check(&old, &new);
+    assert!(!compatibility.compatible);
+    assert_eq!(
+        compatibility.differences[0],
+        SuiMoveTypeDifference::FieldName {
+            index: 0,
+            old: "id".to_string(),
+            new: "value".to_string(),
+        }
+    );
+    assert!(compatibility
+        .differences
+        .iter()
+        .any(|d| matches!(d, SuiMoveTypeDifference::FieldType { index: 1, .. })));
+}
+
+#[test]
+fn test_type_compatibility_renamed_fields() {
+    let old = vec![
+        field(0, "id", SuiMoveNormalizedType::Address),
+        field(1, "value", SuiMoveNormalizedType::U64),
+    ];
+    let new = vec![
+        field(0, "id", SuiMoveNormalizedType::Address),
+        field(1, "balance", SuiMoveNormalizedType::U64),
+    ];
+    let compatibility = check(&old, &new);
+    assert!(compatibility.compatible);
+    assert_eq!(
+        vec![SuiMoveTypeDifference::FieldName {
+            index: 1,
+            old: "value".to_string(),
+            new: "balance".to_string(),
+        }],
+        compatibility.differences
+    );
+}
+
+#[test]
+fn test_type_compatibility_nested_struct_layout() {
+    let nested = |address: &str| SuiMoveNormalizedType::Struct {
+        address: address.to_string(),
+        module: "config".to_string(),
+        name: "Config".to_string(),
+        type_arguments: vec![],
+    };
+    let old = vec![field(0, "config", nested("0x1234"))];
+    let new = vec![field(0, "config", nested("0x5678"))];
+
+    // The nested structs are compared by layout rather than by identity
+    let mut compared = vec![];
+    let compatibility = TypeCompatibility::check(&old, &new, |old, new| {
+        compared.push((old.clone(), new.clone()));
+        Ok::<_, ()>(true)
+    })
+    .unwrap();
+    assert!(compatibility.compatible);
+    assert!(compatibility.differences.is_empty());
+    assert_eq!(vec![(nested("0x1234"), nested("0x5678"))], compared);
+
+    let compatibility = check(&old, &new);
+    assert!(!compatibility.compatible);
+    assert!(matches!(
+        compatibility.differences[..],
+        [SuiMoveTypeDifference::FieldType { index: 0, .. }]
+    ));
+    assert_eq!(
+        Err("unresolved"),
+        TypeCompatibility::check(&old, &new, |_, _| Err("unresolved"))
+    );
+}
+
+#[test]
+fn test_type_compatibility_generic_instantiation() {
+    let generic = vec![field(0, "value", SuiMoveNormalizedType::TypeParameter(0))];
+    let instantiated = generic
+        .iter()
+        .map(|f| {
+            field(
+                f.index,
+                &f.name,
+                f.type_.subst(&[SuiMoveNormalizedType::U64]).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let concrete = vec![field(0, "value", SuiMoveNormalizedType::U64)];
+    assert!(check(&instantiated, &concrete).compatible);
+
+    let other = vec![field(0, "value", SuiMoveNormalizedType::U8)];
+    let compatibility = check(&instantiated, &other);
+    assert!(!compatibility.compatible);
+    assert!(generic[0].type_.subst(&[]).is_err());
+}
+
+#[test]
+fn test_decode_pure_arguments() {
+    let object_id = ObjectID::random();
+    let arguments = vec![
+        CallArg::Pure(bcs::to_bytes(&1_000_000u64).unwrap()),
+        CallArg::Pure(bcs::to_bytes(&vec![1u16, 2, 3]).unwrap()),
+        CallArg::Pure(bcs::to_bytes("Test Name").unwrap()),
+        CallArg::Pure(bcs::to_bytes(&7u8).unwrap()),
+        CallArg::Pure(bcs::to_bytes(&object_id).unwrap()),
+    ];
+    let parameters = vec![
+        SuiMoveNormalizedType::U64,
+        SuiMoveNormalizedType::Vector(Box::new(SuiMoveNormalizedType::U16)),
+        SuiMoveNormalizedType::Struct {
+            address: MOVE_STDLIB_ADDRESS.to_hex_literal(),
+            module: "string".to_string(),
+            name: "String".to_string(),
+            type_arguments: vec![],
+        },
+        SuiMoveNormalizedType::TypeParameter(0),
+        // An object parameter has no pure layout, the untyped decoding is kept
+        SuiMoveNormalizedType::Struct {
+            address: SUI_FRAMEWORK_ADDRESS.to_hex_literal(),
+            module: "coin".to_string(),
+            name: "Coin".to_string(),
+            type_arguments: vec![],
+        },
+    ];
+    let mut call = SuiMoveCall {
+        package: ObjectID::from(SUI_FRAMEWORK_ADDRESS),
+        module: "test".to_string(),
+        function: "test".to_string(),
+        type_arguments: vec!["u8".to_string()],
+        arguments: arguments
+            .iter()
+            .map(|arg| match arg {
+                CallArg::Pure(bytes) => SuiJsonValue::from_bcs_bytes(bytes).unwrap(),
+                _ => unreachable!(),
+            })
+            .collect(),
+        object_arguments: None,
+    };
+    let untyped_object_arg = call.arguments[4].to_json_value();
+
+    call.decode_pure_arguments(&arguments, &parameters, &[SuiMoveNormalizedType::U8]);
+
+    let values = call
+        .arguments
+        .iter()
+        .map(|arg| arg.to_json_value())
+        .collect::<Vec<_>>();
+    assert_eq!(json!("1000000"), values[0]);
+    assert_eq!(json!([1, 2, 3]), values[1]);
+    assert_eq!(json!("Test Name"), values[2]);
+    assert_eq!(json!(7), values[3]);
+    assert_eq!(untyped_object_arg, values[4]);
+}
+
+#[test]
+fn test_multisig_transaction_signature() {
+    let keys: Vec<SuiKeyPair> = vec![
+        SuiKeyPair::Ed25519(get_key_pair().1),
+        SuiKeyPair::Secp256k1(get_key_pair().1),
+        SuiKeyPair::Secp256r1(get_key_pair().1),
+    ];
+    let multisig_pk = MultiSigPublicKey::new(
+        keys.iter().map(|key| key.public()).collect(),
+        vec![1, 1, 2],
+        2,
+    )
+    .unwrap();
+    let msg = IntentMessage::new(
+        Intent::default(),
+        PersonalMessage {
+            message: "Hello".as_bytes().to_vec(),
+        },
+    );
+    let multisig = GenericSignature::MultiSig(
+        MultiSig::combine(
+            vec![
+                Signature::new_secure(&msg, &keys[0]),
+                Signature::new_secure(&msg, &keys[1]),
+            ],
+            multisig_pk,
+        )
+        .unwrap(),
+    );
+
+    let sui_multisig = match SuiTransactionSignature::from(multisig.clone()) {
+        SuiTransactionSignature::MultiSig(sui_multisig) => sui_multisig,
+        _ => panic!("Expected a multisig signature"),
+    };
+    assert_eq!(2, sui_multisig.threshold);
+    assert_eq!(multisig, sui_multisig.signature);
+    let public_keys = sui_multisig
+        .public_keys
+        .iter()
+        .map(|pk| (pk.public_key.clone(), pk.weight, pk.signed))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (keys[0].public(), 1, true),
+            (keys[1].public(), 1, true),
+            (keys[2].public(), 2, false),
+        ],
+        public_keys
+    );
+
+    let single = GenericSignature::Signature(Signature::new_secure(&msg, &keys[0]));
+    assert_eq!(
+        SuiTransactionSignature::Signature(single.clone()),
+        SuiTransactionSignature::from(single)
+    );
+}
@@ -0,0 +1,66 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+extern crate criterion;
+
+use std::time::Duration;
+
+use criterion::{Criterion, Throughput};
+use tokio::runtime::Builder;
+
+use sui_json_rpc::api::{EventReadApiClient, ReadApiClient};
+use sui_types::query::EventQuery;
+use test_utils::network::TestClusterBuilder;
+use test_utils::transaction::transfer_coin;
+
+/// Read the effects and events of transfers over RPC, in one call and in two separate calls.
+fn bench_get_transaction_effects_and_events(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Effects and events of transfers");
+
+    let runtime = Builder::new_multi_thread().enable_all().build().unwrap();
+    let mut cluster = runtime
+        .block_on(TestClusterBuilder::new().enable_fullnode_events().build())
+        .expect("Could not start test cluster");
+    let digests: Vec<_> = (0..10)
+        .map(|_| {
+            let (_, _, _, digest, _, _) = runtime
+                .block_on(transfer_coin(&mut cluster.wallet))
+                .expect("Could not transfer a coin");
+            digest
+        })
+        .collect();
+    // Let the fullnode store the events of the last transfers.
+    runtime.block_on(tokio::time::sleep(Duration::from_millis(100)));
+    let client = cluster.rpc_client();
+
+    group.throughput(Throughput::Elements(digests.len() as u64));
+
+    group.bench_function("getTransactionEffectsAndEvents", |b| {
+        b.to_async(&runtime).iter(|| async {
+            for digest in &digests {
+                client
+                    .get_transaction_effects_and_events(*digest)
+                    .await
+                    .expect("Reading effects and events should not fail");
+            }
+        })
+    });
+    group.bench_function("getTransactionEffects + getEvents", |b| {
+        b.to_async(&runtime).iter(|| async {
+            for digest in &digests {
+                client
+                    .get_transaction_effects(*digest)
+                    .await
+                    .expect("Reading effects should not fail");
+                client
+                    .get_events(EventQuery::Transaction(*digest), None, None, None)
+                    .await
+                    .expect("Reading events should not fail");
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_get_transaction_effects_and_events);
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use tokio::runtime::Builder;
+
+use sui_json_rpc::api::{ReadApiClient, ReadApiServer};
+use sui_json_rpc::read_api::ReadApi;
+use test_utils::network::TestClusterBuilder;
+
+/// Read the same-typed gas coins of an account over and over, with layouts served from the
+/// layout cache and with layouts built on every read.
+fn bench_get_object_layout_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ReadApi.get_object() of same-typed objects");
+
+    let runtime = Builder::new_multi_thread().enable_all().build().unwrap();
+    let cluster = runtime
+        .block_on(TestClusterBuilder::new().build())
+        .expect("Could not start test cluster");
+    let address = cluster.accounts[0];
+    let coins: Vec<_> = runtime
+        .block_on(cluster.rpc_client().get_objects_owned_by_address(address))
+        .expect("Could not read gas coins")
+        .into_iter()
+        .map(|info| info.object_id)
+        .collect();
+    let state = cluster.fullnode_handle.sui_node.state();
+
+    group.throughput(Throughput::Elements(coins.len() as u64));
+
+    for (name, max_layout_cache_size) in [("cached", 1_000), ("uncached", 0)] {
+        let mut read_api = ReadApi::new(state.clone());
+        read_api.max_layout_cache_size = max_layout_cache_size;
+        group.bench_with_input(BenchmarkId::new("layouts", name), &read_api, |b, read_api| {
+            b.to_async(&runtime).iter(|| async {
+                for coin in &coins {
+                    ReadApiServer::get_object(read_api, *coin)
+                        .await
+                        .expect("Reading a gas coin should not fail");
+                }
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_get_object_layout_cache);
+criterion_main!(benches);
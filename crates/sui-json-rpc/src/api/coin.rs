@@ -3,7 +3,7 @@
 
 use jsonrpsee::core::RpcResult;
 use jsonrpsee_proc_macros::rpc;
-use sui_json_rpc_types::{Balance, CoinPage, SuiCoinMetadata};
+use sui_json_rpc_types::{Balance, Coin, CoinPage, CoinSortOrder, SuiCoinMetadata};
 use sui_open_rpc_macros::open_rpc;
 use sui_types::balance::Supply;
 use sui_types::base_types::{ObjectID, SuiAddress};
@@ -23,6 +23,9 @@ pub trait CoinReadApi {
         cursor: Option<ObjectID>,
         /// maximum number of items per page
         limit: Option<usize>,
+        /// order of the coins within the page, default to by object ID. Pages are always cut by
+        /// object ID, so sorting by balance only applies within a page.
+        sort: Option<CoinSortOrder>,
     ) -> RpcResult<CoinPage>;
 
     /// Return all Coin objects owned by an address.
@@ -37,6 +40,21 @@ pub trait CoinReadApi {
         limit: Option<usize>,
     ) -> RpcResult<CoinPage>;
 
+    /// Return unlocked Coin<`coin_type`> objects owned by an address whose balances add up to at
+    /// least `amount`, picking the largest coins first.
+    #[method(name = "selectCoins")]
+    async fn select_coins(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+        /// optional type name for the coin (e.g., 0x168da5bf1f48dafc111b0a488fa454aca95e0b5e::usdc::USDC), default to 0x2::sui::SUI if not specified.
+        coin_type: Option<String>,
+        /// the total balance to cover
+        amount: u128,
+        /// optional coins that must not be selected, e.g. the gas coin
+        exclude: Option<Vec<ObjectID>>,
+    ) -> RpcResult<Vec<Coin>>;
+
     /// Return the total coin balance for one coin type, owned by the address owner.
     #[method(name = "getBalance")]
     async fn get_balance(
@@ -63,6 +81,15 @@ pub trait CoinReadApi {
         coin_type: String,
     ) -> RpcResult<SuiCoinMetadata>;
 
+    /// Return metadata for each of the given coins, in the order requested, or null for a coin
+    /// without published metadata
+    #[method(name = "multiGetCoinMetadata")]
+    async fn multi_get_coin_metadata(
+        &self,
+        /// type names for the coins, at most [QUERY_MAX_RESULT_LIMIT]
+        coin_types: Vec<String>,
+    ) -> RpcResult<Vec<Option<SuiCoinMetadata>>>;
+
     /// Return total supply for a coin
     #[method(name = "getTotalSupply")]
     async fn get_total_supply(
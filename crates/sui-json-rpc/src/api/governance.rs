@@ -39,4 +39,14 @@ pub trait GovernanceReadApi {
     /// Return the reference gas price for the network
     #[method(name = "getReferenceGasPrice")]
     async fn get_reference_gas_price(&self) -> RpcResult<u64>;
+
+    /// Return the reference gas price of the last `epochs` epochs, the current one included,
+    /// ordered by epoch. Fewer epochs are returned if the network is younger, or if this node no
+    /// longer stores the state of the older epochs.
+    #[method(name = "getReferenceGasPriceHistory")]
+    async fn get_reference_gas_price_history(
+        &self,
+        /// the number of epochs, at most [MAX_GAS_PRICE_HISTORY_EPOCHS]
+        epochs: usize,
+    ) -> RpcResult<Vec<(EpochId, u64)>>;
 }
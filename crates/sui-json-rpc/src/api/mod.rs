@@ -1,6 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::fmt::Debug;
+
+use anyhow::anyhow;
+use jsonrpsee::core::{Error as RpcError, RpcResult};
+use jsonrpsee::types::error::CallError;
+
 mod bls;
 mod coin;
 mod event;
@@ -43,6 +49,19 @@ pub use transaction_builder::TransactionBuilderServer;
 /// for document purposes.
 pub const QUERY_MAX_RESULT_LIMIT: usize = 1000;
 
+/// Maximum number of checkpoints exported by a single `exportCheckpointsBcs` request.
+pub const MAX_CHECKPOINT_EXPORT_RANGE: u64 = 100;
+
+/// Maximum size in bytes of the BCS encoded checkpoints of a single `exportCheckpointsBcs`
+/// request.
+pub const MAX_CHECKPOINT_EXPORT_BYTES: usize = 8 * 1024 * 1024;
+
+/// Maximum number of epochs returned by `getReferenceGasPriceHistory`.
+pub const MAX_GAS_PRICE_HISTORY_EPOCHS: usize = 100;
+
+/// Maximum number of addresses of a `TransactionQuery::FromAnyOf` query.
+pub const MAX_TRANSACTION_QUERY_ADDRESSES: usize = 50;
+
 pub fn cap_page_limit(limit: Option<usize>) -> usize {
     let limit = limit.unwrap_or_default();
     if limit > QUERY_MAX_RESULT_LIMIT || limit == 0 {
@@ -51,3 +70,22 @@ pub fn cap_page_limit(limit: Option<usize>) -> usize {
         limit
     }
 }
+
+/// Error returned by paginated methods for a cursor that does not correspond to any item of the
+/// query, rather than silently restarting the traversal from the beginning.
+pub fn cursor_not_found_error(cursor: &impl Debug) -> RpcError {
+    RpcError::Call(CallError::InvalidParams(anyhow!(
+        "Cursor [{cursor:?}] was not found"
+    )))
+}
+
+/// Cursors are inclusive, the item a valid cursor points to is always the first of its page.
+pub fn check_page_cursor<T: PartialEq + Debug>(
+    cursor: Option<&T>,
+    first: Option<&T>,
+) -> RpcResult<()> {
+    match cursor {
+        Some(cursor) if first != Some(cursor) => Err(cursor_not_found_error(cursor)),
+        _ => Ok(()),
+    }
+}
@@ -1,21 +1,31 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use fastcrypto::encoding::Base64;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee_proc_macros::rpc;
 use std::collections::BTreeMap;
+use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    Checkpoint, CheckpointId, DynamicFieldPage, GetObjectDataResponse, GetPastObjectDataResponse,
-    GetRawObjectDataResponse, MoveFunctionArgType, SuiMoveNormalizedFunction,
-    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo, SuiTransactionResponse,
-    TransactionsPage,
+    AddressSummary, Checkpoint, CheckpointDigestsPage, CheckpointId, CheckpointSignatureInfo,
+    CheckpointTips, CommandEffect, DynamicFieldPage, EffectsAndEvents, GetObjectDataResponse,
+    GetPastObjectDataResponse, GetRawObjectDataResponse, IntentInfo, ModuleAbi,
+    MoveFunctionArgType, ObjectOwnershipChange, ObjectWithFields, ObjectsPage, OwnedObjectRef,
+    OwnershipProof, PackagePreloadResult, SharedObjectInfo, StorageHistoryPage, SuiExecutionStatus,
+    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
+    SuiMoveNormalizedStructResult, SuiObjectDataOptions, SuiObjectInfo, SuiObjectRef,
+    SuiObjectResponse, SuiTransactionEffects, SuiTransactionResponse,
+    SuiTransactionResponseOptions, SuiTransactionSignature, SuiTransactionStatus,
+    TimestampedTransactionsPage, TransactionTimestampCursor, TransactionsInRangePage,
+    TransactionsPage, TypeCompatibility,
 };
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::{
-    ObjectID, SequenceNumber, SuiAddress, TransactionDigest, TxSequenceNumber,
+    ObjectDigest, ObjectID, SequenceNumber, SuiAddress, TransactionDigest,
+    TransactionEffectsDigest, TxSequenceNumber,
 };
 use sui_types::digests::{CheckpointContentsDigest, CheckpointDigest};
-use sui_types::dynamic_field::DynamicFieldName;
+use sui_types::dynamic_field::{DynamicFieldName, DynamicFieldType};
 use sui_types::messages_checkpoint::{
     CheckpointContents, CheckpointSequenceNumber, CheckpointSummary,
 };
@@ -32,6 +42,38 @@ pub trait ReadApi {
         address: SuiAddress,
     ) -> RpcResult<Vec<SuiObjectInfo>>;
 
+    /// Return the objects owned by an address that were last changed in or after the given
+    /// checkpoint, including those changed by transactions not yet included in a checkpoint.
+    #[method(name = "getObjectsOwnedByAddressUpdatedSince")]
+    async fn get_objects_owned_by_address_updated_since(
+        &self,
+        /// the owner's Sui address
+        address: SuiAddress,
+        /// the sequence number of the earliest checkpoint to return changes from
+        updated_since_checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<SuiObjectInfo>>;
+
+    /// Return the total storage rebate of the objects owned by an address, that is the amount of
+    /// SUI refunded if all of them were deleted. Covers the objects returned by
+    /// `getObjectsOwnedByAddress`, and is 0 for an address that owns no objects.
+    #[method(name = "getOwnedStorageRebate")]
+    async fn get_owned_storage_rebate(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+    ) -> RpcResult<u64>;
+
+    /// Return an overview of an address: its SUI balance, number of coins, staked SUI and number
+    /// of objects of each type, gathered from a single scan of its objects. The scan is bounded
+    /// like `getObjectsOwnedByAddress`, so for very large wallets the summary covers only part of
+    /// the objects, which is reported by `truncated`.
+    #[method(name = "getAddressSummary")]
+    async fn get_address_summary(
+        &self,
+        /// the owner's Sui address
+        address: SuiAddress,
+    ) -> RpcResult<AddressSummary>;
+
     /// Return the list of dynamic field objects owned by an object.
     #[method(name = "getDynamicFields")]
     async fn get_dynamic_fields(
@@ -42,12 +84,47 @@ pub trait ReadApi {
         cursor: Option<ObjectID>,
         /// Maximum item returned per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
         limit: Option<usize>,
+        /// Optional filter to only return dynamic fields or only dynamic object fields
+        field_type: Option<DynamicFieldType>,
     ) -> RpcResult<DynamicFieldPage>;
 
+    /// Return the live address owned objects of the given struct type, ordered by object ID.
+    /// Shared, immutable and object owned objects are not included.
+    #[method(name = "getObjectsByType")]
+    async fn get_objects_by_type(
+        &self,
+        /// the struct type of the objects, e.g. `0x2::coin::Coin<0x2::sui::SUI>`
+        struct_type: String,
+        /// Optional paging cursor
+        cursor: Option<ObjectID>,
+        /// Maximum item returned per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage>;
+
+    /// Return the object information for a specified object, together with the first page of
+    /// its dynamic fields.
+    #[method(name = "getObjectWithFields")]
+    async fn get_object_with_fields(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+        /// Maximum number of dynamic fields returned, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        fields_limit: Option<usize>,
+    ) -> RpcResult<ObjectWithFields>;
+
     /// Return the total number of transactions known to the server.
     #[method(name = "getTotalTransactionNumber")]
     async fn get_total_transaction_number(&self) -> RpcResult<u64>;
 
+    /// Return the total number of transactions included in checkpoints up to and including the
+    /// given checkpoint.
+    #[method(name = "getTotalTransactionNumberAtCheckpoint")]
+    async fn get_total_transaction_number_at_checkpoint(
+        &self,
+        /// the sequence number of the checkpoint, default to the latest checkpoint if not specified.
+        sequence_number: Option<CheckpointSequenceNumber>,
+    ) -> RpcResult<u64>;
+
     /// Return list of transaction digests within the queried range.
     #[method(name = "getTransactionsInRange")]
     async fn get_transactions_in_range(
@@ -58,6 +135,22 @@ pub trait ReadApi {
         end: TxSequenceNumber,
     ) -> RpcResult<Vec<TransactionDigest>>;
 
+    /// Return a page of transaction digests within the queried range, ordered by sequence number.
+    #[method(name = "getTransactionsInRangePaged")]
+    async fn get_transactions_in_range_paged(
+        &self,
+        /// the matching transactions' sequence number will be greater than or equals to the starting sequence number
+        start: TxSequenceNumber,
+        /// the matching transactions' sequence number will be less than the ending sequence number
+        end: TxSequenceNumber,
+        /// optional paging cursor, the sequence number to resume from (inclusive)
+        cursor: Option<TxSequenceNumber>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+        /// query result ordering, default to false (ascending order), oldest record first.
+        descending_order: Option<bool>,
+    ) -> RpcResult<TransactionsInRangePage>;
+
     /// Return the transaction response object.
     #[method(name = "getTransaction")]
     async fn get_transaction(
@@ -66,6 +159,98 @@ pub trait ReadApi {
         digest: TransactionDigest,
     ) -> RpcResult<SuiTransactionResponse>;
 
+    /// Return the transaction response object, with the requested extra data
+    #[method(name = "getTransactionWithOptions")]
+    async fn get_transaction_with_options(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+        /// options for specifying the extra data to be returned
+        options: Option<SuiTransactionResponseOptions>,
+    ) -> RpcResult<SuiTransactionResponse>;
+
+    /// Return whether the node has executed the transaction with the given BCS serialized
+    /// transaction data, without the caller computing its digest.
+    #[method(name = "getTransactionStatusByBytes")]
+    async fn get_transaction_status_by_bytes(
+        &self,
+        /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string
+        tx_bytes: Base64,
+    ) -> RpcResult<SuiTransactionStatus>;
+
+    /// Return the intent the given BCS serialized transaction data is signed with, along with the
+    /// resulting transaction digest.
+    #[method(name = "getTransactionIntentInfo")]
+    async fn get_transaction_intent_info(
+        &self,
+        /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string
+        tx_bytes: Base64,
+    ) -> RpcResult<IntentInfo>;
+
+    /// Return the transaction response object of the transaction that produced the given effects.
+    #[method(name = "getTransactionByEffectsDigest")]
+    async fn get_transaction_by_effects_digest(
+        &self,
+        /// the digest of the effects of the queried transaction
+        effects_digest: TransactionEffectsDigest,
+    ) -> RpcResult<SuiTransactionResponse>;
+
+    /// Return the most recent transaction sent from or to the address, or None if the address
+    /// has no transactions.
+    #[method(name = "getLatestTransactionByAddress")]
+    async fn get_latest_transaction_by_address(
+        &self,
+        /// the queried Sui address
+        address: SuiAddress,
+    ) -> RpcResult<Option<SuiTransactionResponse>>;
+
+    /// Return the signatures of a transaction. Multisig signatures are returned with the public
+    /// keys, weights and threshold of the multisig.
+    #[method(name = "getTransactionSignatures")]
+    async fn get_transaction_signatures(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<SuiTransactionSignature>>;
+
+    /// Return the effects of a transaction, including the events it emitted, without the
+    /// transaction data or checkpoint information.
+    #[method(name = "getTransactionEffects")]
+    async fn get_transaction_effects(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionEffects>;
+
+    /// Return the effects of a transaction along with its events, as needed to index it, without
+    /// the transaction data or checkpoint information. Only served by nodes that store events.
+    #[method(name = "getTransactionEffectsAndEvents")]
+    async fn get_transaction_effects_and_events(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<EffectsAndEvents>;
+
+    /// Return the object changes of each command of a programmable transaction. This needs the
+    /// execution trace of the transaction, which nodes do not retain yet, so the call fails with
+    /// an unsupported error (code -32004) for any executed transaction.
+    #[method(name = "getTransactionCommandEffects")]
+    async fn get_transaction_command_effects(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<CommandEffect>>;
+
+    /// Return only the execution status of a transaction, or None if the transaction has not
+    /// been executed by this node (yet). This is the cheapest way to poll for the outcome of a
+    /// transaction.
+    #[method(name = "getTransactionExecutionStatus")]
+    async fn get_transaction_execution_status(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<Option<SuiExecutionStatus>>;
+
     /// Return the object information for a specified object
     #[method(name = "getObject")]
     async fn get_object(
@@ -74,6 +259,48 @@ pub trait ReadApi {
         object_id: ObjectID,
     ) -> RpcResult<GetObjectDataResponse>;
 
+    /// Return the object information for a specified object once its live version is at least
+    /// `min_version`, e.g. to read the writes of a transaction that was just executed elsewhere.
+    /// Fails if the object does not reach the version within the timeout.
+    #[method(name = "getObjectAtLeastVersion")]
+    async fn get_object_at_least_version(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+        /// the lowest version of the object to return
+        min_version: SequenceNumber,
+        /// how long to wait for the version in milliseconds, default to 5000, at most 30000
+        timeout_ms: Option<u64>,
+    ) -> RpcResult<GetObjectDataResponse>;
+
+    /// Return only the digest of the latest version of an object, the cheapest read to check
+    /// whether a known object reference is still current.
+    #[method(name = "getObjectDigest")]
+    async fn get_object_digest(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+    ) -> RpcResult<ObjectDigest>;
+
+    /// Return the object information for a list of objects, in the same order as requested.
+    /// Duplicated IDs are only read once.
+    #[method(name = "multiGetObjects")]
+    async fn multi_get_objects(
+        &self,
+        /// the IDs of the queried objects, at most [QUERY_MAX_RESULT_LIMIT]
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>>;
+
+    /// Return the object information for a specified object, with the requested extra data
+    #[method(name = "getObjectWithOptions")]
+    async fn get_object_with_options(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+        /// options for specifying the content to be returned
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<SuiObjectResponse>;
+
     /// Return the dynamic field object information for a specified object
     #[method(name = "getDynamicFieldObject")]
     async fn get_dynamic_field_object(
@@ -84,6 +311,20 @@ pub trait ReadApi {
         name: DynamicFieldName,
     ) -> RpcResult<GetObjectDataResponse>;
 
+    /// Return the dynamic field object of a parent object from the value of its name alone,
+    /// trying every primitive name type the value can be read as: integers, booleans, addresses,
+    /// IDs, strings and vectors of these. Numbers and numeric strings are treated as equal, so a
+    /// `u64` key can be given as a plain JSON number. Fails if no dynamic field matches, or if
+    /// fields of several name types match.
+    #[method(name = "getDynamicFieldObjectByValue")]
+    async fn get_dynamic_field_object_by_value(
+        &self,
+        /// The ID of the queried parent object
+        parent_object_id: ObjectID,
+        /// The value of the name of the dynamic field
+        value: SuiJsonValue,
+    ) -> RpcResult<GetObjectDataResponse>;
+
     /// Return the argument types of a Move function,
     /// based on normalized Type.
     #[method(name = "getMoveFunctionArgTypes")]
@@ -109,6 +350,11 @@ pub trait ReadApi {
         module_name: String,
     ) -> RpcResult<SuiMoveNormalizedModule>;
 
+    /// Return a compact description of the interface of a Move module for code generation, with
+    /// types written as type strings
+    #[method(name = "getModuleAbi")]
+    async fn get_module_abi(&self, package: ObjectID, module_name: String) -> RpcResult<ModuleAbi>;
+
     /// Return a structured representation of Move struct
     #[method(name = "getNormalizedMoveStruct")]
     async fn get_normalized_move_struct(
@@ -118,6 +364,16 @@ pub trait ReadApi {
         struct_name: String,
     ) -> RpcResult<SuiMoveNormalizedStruct>;
 
+    /// Return the structured representations of the Move structs of several type strings, e.g.
+    /// `0x2::coin::Coin<0x2::sui::SUI>`, with a result per type so that a type that cannot be
+    /// resolved does not fail the others. Type arguments are ignored.
+    #[method(name = "multiGetNormalizedMoveStructsByType")]
+    async fn multi_get_normalized_move_structs_by_type(
+        &self,
+        /// the struct types, at most [QUERY_MAX_RESULT_LIMIT]
+        types: Vec<String>,
+    ) -> RpcResult<Vec<SuiMoveNormalizedStructResult>>;
+
     /// Return a structured representation of Move function
     #[method(name = "getNormalizedMoveFunction")]
     async fn get_normalized_move_function(
@@ -125,8 +381,42 @@ pub trait ReadApi {
         package: ObjectID,
         module_name: String,
         function_name: String,
+        /// Whether to include the functions called directly from the function's body, default to false.
+        include_calls: Option<bool>,
     ) -> RpcResult<SuiMoveNormalizedFunction>;
 
+    /// Return true if the module exposes a function with the given name, false if it does not.
+    /// Return an error if the package or the module does not exist.
+    #[method(name = "moveFunctionExists")]
+    async fn move_function_exists(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        function_name: String,
+    ) -> RpcResult<bool>;
+
+    /// Normalize and cache every module of the given packages ahead of time, so that the first
+    /// normalized-module requests for them do not pay the normalization cost. Packages that are
+    /// already cached are skipped, and packages that fail to load do not fail the others.
+    /// Return what was done for each package, in the order of the request.
+    #[method(name = "preloadPackages")]
+    async fn preload_packages(
+        &self,
+        /// the IDs of the packages to cache, at most [QUERY_MAX_RESULT_LIMIT]
+        packages: Vec<ObjectID>,
+    ) -> RpcResult<Vec<PackagePreloadResult>>;
+
+    /// Evict the cached normalized modules of the given package, or of every package if not
+    /// specified. Published packages are immutable, so this is only useful for diagnostics and
+    /// testing. Return the number of modules evicted. This is an admin method, only served by
+    /// nodes configured with `enable-rpc-admin-methods`.
+    #[method(name = "clearModuleCache")]
+    async fn clear_module_cache(
+        &self,
+        /// the ID of the package to evict, default to all packages if not specified.
+        package: Option<ObjectID>,
+    ) -> RpcResult<usize>;
+
     /// Return list of transactions for a specified query criteria.
     #[method(name = "getTransactions")]
     async fn get_transactions(
@@ -141,6 +431,54 @@ pub trait ReadApi {
         descending_order: Option<bool>,
     ) -> RpcResult<TransactionsPage>;
 
+    /// Return the transactions sent by an address with a timestamp in [start_time, end_time),
+    /// ordered by timestamp and, for transactions sharing a timestamp, by digest. Paging with a
+    /// timestamp and digest cursor goes through transactions sharing a timestamp without
+    /// skipping or repeating any of them.
+    #[method(name = "getTransactionsFromAddressByTimestamp")]
+    async fn get_transactions_from_address_by_timestamp(
+        &self,
+        /// the sender address
+        address: SuiAddress,
+        /// left endpoint of time interval, milliseconds since epoch, inclusive
+        start_time: u64,
+        /// right endpoint of time interval, milliseconds since epoch, exclusive
+        end_time: u64,
+        /// Optional paging cursor
+        cursor: Option<TransactionTimestampCursor>,
+        /// Maximum item returned per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+        /// query result ordering, default to false (ascending order), oldest record first.
+        descending_order: Option<bool>,
+    ) -> RpcResult<TimestampedTransactionsPage>;
+
+    /// Stream all transactions matching the query criteria in ascending order, in batches sized
+    /// by the server. The subscription is closed once the query is exhausted.
+    #[subscription(
+        name = "streamTransactions",
+        unsubscribe = "unsubscribeStreamTransactions",
+        item = Vec<SuiTransactionResponse>
+    )]
+    fn stream_transactions(
+        &self,
+        /// the transaction query criteria.
+        query: TransactionQuery,
+        /// Optional cursor of the first transaction to stream
+        start_cursor: Option<TransactionDigest>,
+    );
+
+    /// Subscribe to the objects gained and lost by an address. The objects the address owns when
+    /// subscribing are pushed first as gained, followed by a snapshot complete marker, then live
+    /// changes in transaction order. As the subscription starts before the snapshot is read, a
+    /// change committed meanwhile can be pushed both in the snapshot and as a live change: an
+    /// object may be reported gained twice, or gained in the snapshot and lost right after.
+    #[subscription(name = "subscribeAddressObjects", item = ObjectOwnershipChange)]
+    fn subscribe_address_objects(
+        &self,
+        /// the owner's Sui address
+        address: SuiAddress,
+    );
+
     /// Note there is no software-level guarantee/SLA that objects with past versions
     /// can be retrieved by this API, even if the object and version exists/existed.
     /// The result may vary across nodes depending on their pruning policies.
@@ -154,10 +492,62 @@ pub trait ReadApi {
         version: SequenceNumber,
     ) -> RpcResult<GetPastObjectDataResponse>;
 
+    /// Return the state of each of the given objects as of the given checkpoint, in the same
+    /// order as requested. Objects created after the checkpoint are reported as not created.
+    /// As with `tryGetPastObject`, older states may have been pruned by this node. Fails for an
+    /// object that was written more than a thousand times since the checkpoint.
+    #[method(name = "multiGetObjectsAtCheckpoint")]
+    async fn multi_get_objects_at_checkpoint(
+        &self,
+        /// the IDs of the queried objects, at most [QUERY_MAX_RESULT_LIMIT]
+        object_ids: Vec<ObjectID>,
+        /// the sequence number of the checkpoint to read the objects at
+        checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<GetPastObjectDataResponse>>;
+
+    /// Return the references of the given objects owned by `owner`, in the same order as
+    /// requested. All references are read as of the latest executed checkpoint, so that they are
+    /// consistent with each other. Fails if any of the objects was not owned by `owner` then.
+    #[method(name = "getOwnedObjectRefsConsistent")]
+    async fn get_owned_object_refs_consistent(
+        &self,
+        /// the owner of the objects
+        owner: SuiAddress,
+        /// the IDs of the queried objects, at most [QUERY_MAX_RESULT_LIMIT]
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<SuiObjectRef>>;
+
+    /// Return the initial shared version, the current version and the type of a shared object,
+    /// as needed to use it as a shared object input of a transaction.
+    #[method(name = "getSharedObjectInfo")]
+    async fn get_shared_object_info(
+        &self,
+        /// the ID of the queried shared object
+        object_id: ObjectID,
+    ) -> RpcResult<SharedObjectInfo>;
+
+    /// Return the stored versions of an object, newest first, with the storage rebate and
+    /// size of each version. Versions that have been pruned from this node are not returned.
+    #[method(name = "getObjectStorageHistory")]
+    async fn get_object_storage_history(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+        /// optional paging cursor, the version to start from (inclusive)
+        cursor: Option<SequenceNumber>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+    ) -> RpcResult<StorageHistoryPage>;
+
     /// Return the sequence number of the latest checkpoint that has been executed
     #[method(name = "getLatestCheckpointSequenceNumber")]
     async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<CheckpointSequenceNumber>;
 
+    /// Return the sequence numbers of the latest checkpoint the node has synced and of the latest
+    /// one it has executed. Objects are only readable as of the executed checkpoint.
+    #[method(name = "getCheckpointTips")]
+    async fn get_checkpoint_tips(&self) -> RpcResult<CheckpointTips>;
+
     /// Return a checkpoint
     #[method(name = "getCheckpoint")]
     async fn get_checkpoint(
@@ -166,6 +556,55 @@ pub trait ReadApi {
         id: CheckpointId,
     ) -> RpcResult<Checkpoint>;
 
+    /// Return the checkpoint that includes the given transaction
+    #[method(name = "getCheckpointByTransaction")]
+    async fn get_checkpoint_by_transaction(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<Checkpoint>;
+
+    /// Return the transaction at the given position in a checkpoint, transactions are ordered
+    /// as they appear in the checkpoint contents.
+    #[method(name = "getTransactionByCheckpointIndex")]
+    async fn get_transaction_by_checkpoint_index(
+        &self,
+        /// the sequence number of the checkpoint
+        checkpoint: CheckpointSequenceNumber,
+        /// the zero-based position of the transaction in the checkpoint
+        index: usize,
+    ) -> RpcResult<SuiTransactionResponse>;
+
+    /// Return the transactions of a checkpoint sent from or to the given address, in the order
+    /// they appear in the checkpoint contents. Empty if the address had no activity in it.
+    #[method(name = "getCheckpointTransactionsForAddress")]
+    async fn get_checkpoint_transactions_for_address(
+        &self,
+        /// Checkpoint identifier, can use either checkpoint digest, or checkpoint sequence number as input.
+        id: CheckpointId,
+        /// the address whose transactions are returned
+        address: SuiAddress,
+    ) -> RpcResult<Vec<SuiTransactionResponse>>;
+
+    /// Return the data proving who owns an object as of the checkpoint that includes the
+    /// transaction that last changed it, to be verified against a trusted summary of that
+    /// checkpoint as described in `OwnershipProof`. Fails if the transaction is not yet included
+    /// in a checkpoint.
+    #[method(name = "getObjectOwnershipProof")]
+    async fn get_object_ownership_proof(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+    ) -> RpcResult<OwnershipProof>;
+
+    /// Return the genesis checkpoint
+    #[method(name = "getGenesisCheckpoint")]
+    async fn get_genesis_checkpoint(&self) -> RpcResult<Checkpoint>;
+
+    /// Return the references of the objects created by the genesis transaction
+    #[method(name = "getGenesisObjects")]
+    async fn get_genesis_objects(&self) -> RpcResult<Vec<OwnedObjectRef>>;
+
     /// Return a checkpoint summary based on a checkpoint sequence number
     #[method(name = "getCheckpointSummary")]
     async fn get_checkpoint_summary(
@@ -180,6 +619,28 @@ pub trait ReadApi {
         digest: CheckpointDigest,
     ) -> RpcResult<CheckpointSummary>;
 
+    /// Return the validators that signed the certificate of a checkpoint with their voting power,
+    /// and the signed voting power against the quorum threshold of the epoch's committee
+    #[method(name = "getCheckpointSignatures")]
+    async fn get_checkpoint_signatures(
+        &self,
+        /// Checkpoint identifier, can use either checkpoint digest, or checkpoint sequence number as input.
+        id: CheckpointId,
+    ) -> RpcResult<CheckpointSignatureInfo>;
+
+    /// Return the checkpoints with sequence numbers in [start, end) as a Base64 encoded BCS
+    /// vector of `(CheckpointSummary, CheckpointContents)` pairs, for bulk ingestion. The range
+    /// is limited to [MAX_CHECKPOINT_EXPORT_RANGE] checkpoints and the encoded export to
+    /// [MAX_CHECKPOINT_EXPORT_BYTES] bytes.
+    #[method(name = "exportCheckpointsBcs")]
+    async fn export_checkpoints_bcs(
+        &self,
+        /// the first checkpoint to export
+        start: CheckpointSequenceNumber,
+        /// the checkpoint after the last one to export
+        end: CheckpointSequenceNumber,
+    ) -> RpcResult<Base64>;
+
     /// Return contents of a checkpoint, namely a list of execution digests
     #[method(name = "getCheckpointContents")]
     async fn get_checkpoint_contents(
@@ -194,6 +655,28 @@ pub trait ReadApi {
         digest: CheckpointContentsDigest,
     ) -> RpcResult<CheckpointContents>;
 
+    /// Return true if the digest of the given checkpoint contents matches the content digest of
+    /// the given checkpoint summary
+    #[method(name = "verifyCheckpointContents")]
+    async fn verify_checkpoint_contents(
+        &self,
+        summary: CheckpointSummary,
+        contents: CheckpointContents,
+    ) -> RpcResult<bool>;
+
+    /// Return a page of the transaction and effects digests of a checkpoint, in checkpoint order,
+    /// without fetching the transactions themselves.
+    #[method(name = "getCheckpointDigestsPaged")]
+    async fn get_checkpoint_digests_paged(
+        &self,
+        /// Checkpoint identifier, can use either checkpoint digest, or checkpoint sequence number as input.
+        id: CheckpointId,
+        /// optional paging cursor, the position in the checkpoint to start from (inclusive)
+        cursor: Option<usize>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+    ) -> RpcResult<CheckpointDigestsPage>;
+
     /// Return the raw BCS serialized move object bytes for a specified object.
     #[method(name = "getRawObject")]
     async fn get_raw_object(
@@ -201,4 +684,34 @@ pub trait ReadApi {
         /// the id of the object
         object_id: ObjectID,
     ) -> RpcResult<GetRawObjectDataResponse>;
+
+    /// Return the BCS serialized Move value of a Move object, without the object envelope.
+    /// Packages have no Move value and are rejected.
+    #[method(name = "getObjectMoveBcs")]
+    async fn get_object_move_bcs(
+        &self,
+        /// the id of the object
+        object_id: ObjectID,
+    ) -> RpcResult<Base64>;
+
+    /// Check whether two fully instantiated struct types have the same layout, i.e. fields laid
+    /// out alike in the same order, and list the differences. Nested structs are compared by
+    /// layout, so they may be declared in different packages. Fields may be named differently.
+    #[method(name = "checkTypeCompatibility")]
+    async fn check_type_compatibility(
+        &self,
+        /// the original struct type, e.g. `0x2::coin::Coin<0x2::sui::SUI>`
+        old: String,
+        /// the struct type to compare against the original
+        new: String,
+    ) -> RpcResult<TypeCompatibility>;
+
+    /// Return the digests of the transactions the given transaction depends on,
+    /// as recorded in its effects.
+    #[method(name = "getTransactionDependencies")]
+    async fn get_transaction_dependencies(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<TransactionDigest>>;
 }
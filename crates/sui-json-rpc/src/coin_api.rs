@@ -13,7 +13,7 @@ use tracing::debug;
 
 use sui_core::authority::AuthorityState;
 use sui_json_rpc_types::{Balance, Coin as SuiCoin};
-use sui_json_rpc_types::{CoinPage, SuiCoinMetadata};
+use sui_json_rpc_types::{CoinPage, CoinSortOrder, SuiCoinMetadata};
 use sui_open_rpc::Module;
 use sui_types::balance::Supply;
 use sui_types::base_types::{ObjectID, ObjectType, SuiAddress};
@@ -21,10 +21,10 @@ use sui_types::coin::{Coin, CoinMetadata, LockedCoin, TreasuryCap};
 use sui_types::error::SuiError;
 use sui_types::event::Event;
 use sui_types::gas_coin::GAS;
-use sui_types::object::Object;
+use sui_types::object::{Object, ObjectRead};
 use sui_types::parse_sui_struct_tag;
 
-use crate::api::{cap_page_limit, CoinReadApiServer};
+use crate::api::{cap_page_limit, check_page_cursor, CoinReadApiServer, QUERY_MAX_RESULT_LIMIT};
 use crate::error::Error;
 use crate::SuiRpcModule;
 
@@ -81,14 +81,15 @@ impl CoinReadApi {
         coin_type: Option<StructTag>,
         cursor: Option<ObjectID>,
         limit: Option<usize>,
-    ) -> Result<CoinPage, Error> {
+    ) -> RpcResult<CoinPage> {
         // TODO: Add index to improve performance?
         let limit = cap_page_limit(limit);
         let mut coins = self
-            .get_owner_coin_iterator(owner, &coin_type)?
-            .skip_while(|o| matches!(&cursor, Some(cursor) if cursor != o))
+            .get_owner_coin_iterator(owner, &coin_type)
+            .skip_while(|o| matches!((&cursor, o), (Some(cursor), Ok(o)) if cursor != o))
             .take(limit + 1)
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
+        check_page_cursor(cursor.as_ref(), coins.first())?;
 
         let next_cursor = coins.get(limit).cloned();
         coins.truncate(limit);
@@ -100,16 +101,59 @@ impl CoinReadApi {
         Ok(CoinPage { data, next_cursor })
     }
 
+    /// All coins of the owner, the owner index is read one page at a time so that owners of many
+    /// objects are not cut off.
     fn get_owner_coin_iterator<'a>(
         &'a self,
         owner: SuiAddress,
         coin_type: &'a Option<StructTag>,
-    ) -> Result<impl Iterator<Item = ObjectID> + '_, Error> {
-        Ok(self
-            .state
-            .get_owner_objects_iterator(owner)?
-            .filter(move |o| matches!(&o.type_, ObjectType::Struct(type_) if is_coin_type(type_, coin_type)))
-            .map(|info|info.object_id))
+    ) -> impl Iterator<Item = Result<ObjectID, Error>> + 'a {
+        self.state
+            .get_all_owner_objects_iterator(owner)
+            .filter_map(move |info| match info {
+                Ok(info) => matches!(&info.type_, ObjectType::Struct(type_) if is_coin_type(type_, coin_type))
+                    .then_some(Ok(info.object_id)),
+                Err(e) => Some(Err(e.into())),
+            })
+    }
+
+    /// The metadata published with the coin type, or hardcoded for SUI. None if the package of the
+    /// type does not exist or did not publish metadata for it.
+    async fn get_coin_metadata_internal(
+        &self,
+        coin_struct: StructTag,
+    ) -> Result<Option<SuiCoinMetadata>, Error> {
+        if GAS::is_gas(&coin_struct) {
+            // TODO: We need to special case for `CoinMetadata<0x2::sui::SUI> because `get_transaction`
+            // will fail for genesis transaction. However, instead of hardcoding the values here, We
+            // can store the object id for `CoinMetadata<0x2::sui::SUI>` in the Sui System object
+            return Ok(Some(SuiCoinMetadata {
+                id: None,
+                decimals: 9,
+                symbol: "SUI".to_string(),
+                name: "Sui".to_string(),
+                description: "".to_string(),
+                icon_url: None,
+            }));
+        }
+
+        let metadata_object = self
+            .try_find_package_object(
+                &coin_struct.address.into(),
+                &CoinMetadata::type_(coin_struct),
+            )
+            .await?;
+        let Some(metadata_object) = metadata_object else {
+            return Ok(None);
+        };
+        let metadata_object_id = metadata_object.id();
+        metadata_object.try_into().map(Some).map_err(|e: SuiError| {
+            debug!(
+                ?metadata_object_id,
+                "Failed to convert object to CoinMetadata: {:?}", e
+            );
+            Error::from(e)
+        })
     }
 
     async fn find_package_object(
@@ -117,32 +161,46 @@ impl CoinReadApi {
         package_id: &ObjectID,
         object_struct_tag: StructTag,
     ) -> Result<Object, Error> {
-        let publish_txn_digest = self.get_object(package_id).await?.previous_transaction;
-        let (_, effects) = self
-            .state
-            .get_executed_transaction_and_effects(publish_txn_digest)
-            .await?;
-
-        let object_id = effects
-            .events
-            .into_iter()
-            .find_map(|e| {
-                if let Event::NewObject { object_type, .. } = &e {
-                    if matches!(parse_sui_struct_tag(object_type), Ok(tag) if tag == object_struct_tag) {
-                        return e.object_id();
-                    }
-                }
-                None
-            })
+        self.try_find_package_object(package_id, &object_struct_tag)
+            .await?
             .ok_or_else(|| {
                 anyhow!(
                     "Cannot find object [{}] from [{}] package event.",
                     object_struct_tag,
                     package_id
                 )
-            })?;
+                .into()
+            })
+    }
+
+    /// The object of type `object_struct_tag` created when the package was published, or None if
+    /// the package does not exist or created no such object.
+    async fn try_find_package_object(
+        &self,
+        package_id: &ObjectID,
+        object_struct_tag: &StructTag,
+    ) -> Result<Option<Object>, Error> {
+        let ObjectRead::Exists(_, package, _) = self.state.get_object_read(package_id).await? else {
+            return Ok(None);
+        };
+        let (_, effects) = self
+            .state
+            .get_executed_transaction_and_effects(package.previous_transaction)
+            .await?;
 
-        self.get_object(&object_id).await
+        let object_id = effects.events.into_iter().find_map(|e| {
+            if let Event::NewObject { object_type, .. } = &e {
+                if matches!(parse_sui_struct_tag(object_type), Ok(tag) if &tag == object_struct_tag)
+                {
+                    return e.object_id();
+                }
+            }
+            None
+        });
+        match object_id {
+            Some(object_id) => Ok(Some(self.get_object(&object_id).await?)),
+            None => Ok(None),
+        }
     }
 }
 
@@ -164,14 +222,21 @@ impl CoinReadApiServer for CoinReadApi {
         coin_type: Option<String>,
         cursor: Option<ObjectID>,
         limit: Option<usize>,
+        sort: Option<CoinSortOrder>,
     ) -> RpcResult<CoinPage> {
         let coin_type = Some(match coin_type {
             Some(c) => parse_sui_struct_tag(&c)?,
             None => GAS::type_(),
         });
-        Ok(self
+        let mut page = self
             .get_coins_internal(owner, coin_type, cursor, limit)
-            .await?)
+            .await?;
+        match sort {
+            Some(CoinSortOrder::BalanceDesc) => page.data.sort_by(|a, b| b.balance.cmp(&a.balance)),
+            Some(CoinSortOrder::BalanceAsc) => page.data.sort_by_key(|coin| coin.balance),
+            Some(CoinSortOrder::ObjectId) | None => {}
+        }
+        Ok(page)
     }
 
     async fn get_all_coins(
@@ -183,6 +248,52 @@ impl CoinReadApiServer for CoinReadApi {
         Ok(self.get_coins_internal(owner, None, cursor, limit).await?)
     }
 
+    async fn select_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        amount: u128,
+        exclude: Option<Vec<ObjectID>>,
+    ) -> RpcResult<Vec<SuiCoin>> {
+        let coin_type = Some(match coin_type {
+            Some(c) => parse_sui_struct_tag(&c)?,
+            None => GAS::type_(),
+        });
+        let exclude = exclude.unwrap_or_default();
+
+        let mut coins = vec![];
+        for coin in self.get_owner_coin_iterator(owner, &coin_type) {
+            let coin = coin?;
+            if exclude.contains(&coin) {
+                continue;
+            }
+            let coin = self.get_coin(&coin).await?;
+            // Locked coins cannot be spent
+            if coin.locked_until_epoch.is_none() {
+                coins.push(coin);
+            }
+        }
+        coins.sort_by(|a, b| b.balance.cmp(&a.balance));
+
+        let mut total = 0u128;
+        let mut selected = vec![];
+        for coin in coins {
+            if total >= amount {
+                break;
+            }
+            total += coin.balance as u128;
+            selected.push(coin);
+        }
+        if total < amount {
+            return Err(Error::InsufficientFund {
+                address: owner,
+                amount,
+            }
+            .into());
+        }
+        Ok(selected)
+    }
+
     async fn get_balance(
         &self,
         owner: SuiAddress,
@@ -194,13 +305,13 @@ impl CoinReadApiServer for CoinReadApi {
         });
 
         // TODO: Add index to improve performance?
-        let coins = self.get_owner_coin_iterator(owner, &coin_type)?;
+        let coins = self.get_owner_coin_iterator(owner, &coin_type);
         let mut total_balance = 0u128;
         let mut locked_balance = HashMap::new();
         let mut coin_object_count = 0;
 
         for coin in coins {
-            let coin = self.get_coin(&coin).await?;
+            let coin = self.get_coin(&coin?).await?;
             if let Some(lock) = coin.locked_until_epoch {
                 *locked_balance.entry(lock).or_default() += coin.balance as u128
             } else {
@@ -219,11 +330,11 @@ impl CoinReadApiServer for CoinReadApi {
 
     async fn get_all_balances(&self, owner: SuiAddress) -> RpcResult<Vec<Balance>> {
         // TODO: Add index to improve performance?
-        let coins = self.get_owner_coin_iterator(owner, &None)?;
+        let coins = self.get_owner_coin_iterator(owner, &None);
         let mut balances: HashMap<String, Balance> = HashMap::new();
 
         for coin in coins {
-            let coin = self.get_coin(&coin).await?;
+            let coin = self.get_coin(&coin?).await?;
             let balance = balances.entry(coin.coin_type.clone()).or_insert(Balance {
                 coin_type: coin.coin_type,
                 coin_object_count: 0,
@@ -243,34 +354,36 @@ impl CoinReadApiServer for CoinReadApi {
 
     async fn get_coin_metadata(&self, coin_type: String) -> RpcResult<SuiCoinMetadata> {
         let coin_struct = parse_sui_struct_tag(&coin_type)?;
-        if GAS::is_gas(&coin_struct) {
-            // TODO: We need to special case for `CoinMetadata<0x2::sui::SUI> because `get_transaction`
-            // will fail for genesis transaction. However, instead of hardcoding the values here, We
-            // can store the object id for `CoinMetadata<0x2::sui::SUI>` in the Sui System object
-            return Ok(SuiCoinMetadata {
-                id: None,
-                decimals: 9,
-                symbol: "SUI".to_string(),
-                name: "Sui".to_string(),
-                description: "".to_string(),
-                icon_url: None,
-            });
-        }
+        Ok(self
+            .get_coin_metadata_internal(coin_struct)
+            .await?
+            .ok_or_else(|| anyhow!("Cannot find metadata for coin type [{coin_type}]."))?)
+    }
 
-        let metadata_object = self
-            .find_package_object(
-                &coin_struct.address.into(),
-                CoinMetadata::type_(coin_struct),
+    async fn multi_get_coin_metadata(
+        &self,
+        coin_types: Vec<String>,
+    ) -> RpcResult<Vec<Option<SuiCoinMetadata>>> {
+        if coin_types.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(
+                "Requested {} coin types, which exceeds the max limit of {QUERY_MAX_RESULT_LIMIT}",
+                coin_types.len()
             )
-            .await?;
-        let metadata_object_id = metadata_object.id();
-        Ok(metadata_object.try_into().map_err(|e: SuiError| {
-            debug!(
-                ?metadata_object_id,
-                "Failed to convert object to CoinMetadata: {:?}", e
-            );
-            Error::from(e)
-        })?)
+            .into());
+        }
+        let coin_structs = coin_types
+            .iter()
+            .map(|coin_type| parse_sui_struct_tag(coin_type))
+            .collect::<Result<Vec<_>, _>>()?;
+        let results = futures::future::join_all(
+            coin_structs
+                .into_iter()
+                .map(|coin_struct| self.get_coin_metadata_internal(coin_struct)),
+        )
+        .await;
+        // Types whose package does not exist or did not publish metadata have no metadata, any
+        // other failure fails the request
+        Ok(results.into_iter().collect::<Result<Vec<_>, _>>()?)
     }
 
     async fn get_total_supply(&self, coin_type: String) -> RpcResult<Supply> {
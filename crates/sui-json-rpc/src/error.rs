@@ -4,6 +4,9 @@
 use hyper::header::InvalidHeaderValue;
 use jsonrpsee::core::Error as RpcError;
 use jsonrpsee::types::error::CallError;
+use jsonrpsee::types::ErrorObject;
+use std::time::Duration;
+use sui_types::base_types::SuiAddress;
 use sui_types::error::SuiError;
 use thiserror::Error;
 
@@ -26,10 +29,44 @@ pub enum Error {
 
     #[error(transparent)]
     InvalidHeaderValue(#[from] InvalidHeaderValue),
+
+    #[error("Insufficient fund for address [{address}], requested amount: {amount}")]
+    InsufficientFund { address: SuiAddress, amount: u128 },
+
+    #[error("Method {method} is rate limited, retry after {}ms", retry_after.as_millis())]
+    RateLimited {
+        method: String,
+        retry_after: Duration,
+    },
+
+    #[error("Method {method} is not supported by this node: {reason}")]
+    Unsupported { method: String, reason: String },
 }
 
+/// JSON-RPC error code returned when a call is rejected by the rate limiter.
+pub const RATE_LIMITED_CODE: i32 = -32005;
+
+/// JSON-RPC error code returned for methods this node cannot serve.
+pub const UNSUPPORTED_CODE: i32 = -32004;
+
 impl From<Error> for RpcError {
     fn from(e: Error) -> Self {
-        RpcError::Call(CallError::Failed(e.into()))
+        match e {
+            // Surface the retry-after hint (in milliseconds) as the error data.
+            Error::RateLimited { retry_after, .. } => {
+                let retry_after_ms = retry_after.as_millis() as u64;
+                RpcError::Call(CallError::Custom(ErrorObject::owned(
+                    RATE_LIMITED_CODE,
+                    e.to_string(),
+                    Some(retry_after_ms),
+                )))
+            }
+            Error::Unsupported { .. } => RpcError::Call(CallError::Custom(ErrorObject::owned(
+                UNSUPPORTED_CODE,
+                e.to_string(),
+                None::<()>,
+            ))),
+            e => RpcError::Call(CallError::Failed(e.into())),
+        }
     }
 }
@@ -22,11 +22,12 @@ use sui_open_rpc::Module;
 use sui_types::event::{EventEnvelope, EventID};
 use sui_types::query::EventQuery;
 
-use crate::api::cap_page_limit;
 use crate::api::EventReadApiServer;
+use crate::api::{cap_page_limit, check_page_cursor, cursor_not_found_error};
+use crate::error::Error;
 use crate::SuiRpcModule;
 
-fn spawn_subscription<S, T, E>(mut sink: SubscriptionSink, rx: S)
+pub(crate) fn spawn_subscription<S, T, E>(mut sink: SubscriptionSink, rx: S)
 where
     S: TryStream<Ok = T, Error = E> + Unpin + Send + 'static,
     T: Serialize,
@@ -39,7 +40,7 @@ where
             }
             SubscriptionClosed::RemotePeerAborted => (),
             SubscriptionClosed::Failed(err) => {
-                warn!(error = ?err, "Event subscription closed.");
+                warn!(error = ?err, "Subscription closed.");
                 sink.close(err);
             }
         };
@@ -78,11 +79,22 @@ impl EventReadApiServer for EventReadApi {
         );
         let descending = descending_order.unwrap_or_default();
         let limit = cap_page_limit(limit);
+        if let Some(cursor) = &cursor {
+            if !self
+                .state
+                .database
+                .is_tx_already_executed(&cursor.tx_digest)
+                .map_err(Error::from)?
+            {
+                return Err(cursor_not_found_error(cursor));
+            }
+        }
         // Retrieve 1 extra item for next cursor
         let mut data = self
             .state
-            .get_events(query, cursor, limit + 1, descending)
+            .get_events(query, cursor.clone(), limit + 1, descending)
             .await?;
+        check_page_cursor(cursor.as_ref(), data.first().map(|(id, _)| id))?;
         let next_cursor = data.get(limit).map(|(id, _)| id.clone());
         data.truncate(limit);
         let data = data.into_iter().map(|(_, event)| event).collect();
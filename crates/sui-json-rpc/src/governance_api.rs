@@ -1,11 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::anyhow;
 use jsonrpsee::core::RpcResult;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::api::GovernanceReadApiServer;
+use crate::api::{GovernanceReadApiServer, MAX_GAS_PRICE_HISTORY_EPOCHS};
 use crate::error::Error;
 use crate::SuiRpcModule;
 use async_trait::async_trait;
@@ -97,6 +98,31 @@ impl GovernanceReadApiServer for GovernanceReadApi {
     async fn get_reference_gas_price(&self) -> RpcResult<u64> {
         Ok(self.get_sui_system_state().await?.reference_gas_price)
     }
+
+    async fn get_reference_gas_price_history(
+        &self,
+        epochs: usize,
+    ) -> RpcResult<Vec<(EpochId, u64)>> {
+        let epochs = epochs.min(MAX_GAS_PRICE_HISTORY_EPOCHS) as u64;
+        if epochs == 0 {
+            return Ok(vec![]);
+        }
+        let system_state = self.get_sui_system_state().await?;
+        let mut history = vec![(system_state.epoch, system_state.reference_gas_price)];
+        for epoch in (system_state.epoch.saturating_sub(epochs - 1)..system_state.epoch).rev() {
+            // Stop at the first epoch whose state is gone, the history has no gaps
+            match self
+                .state
+                .get_epoch_reference_gas_price(epoch)
+                .map_err(|e| anyhow!("{e}"))?
+            {
+                Some(price) => history.push((epoch, price)),
+                None => break,
+            }
+        }
+        history.reverse();
+        Ok(history)
+    }
 }
 
 impl SuiRpcModule for GovernanceReadApi {
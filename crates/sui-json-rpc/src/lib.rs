@@ -28,6 +28,7 @@ pub mod error;
 pub mod event_api;
 pub mod governance_api;
 mod metrics;
+pub mod rate_limiter;
 pub mod read_api;
 mod routing_layer;
 pub mod threshold_bls_api;
@@ -0,0 +1,23 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+/// Decides whether a JSON-RPC call may proceed. It is consulted at the start of every handler
+/// with the full method name (e.g. `sui_getObject`), so implementations can apply different
+/// limits to cheap and expensive methods.
+pub trait RateLimiter: Send + Sync {
+    /// Return `Ok(())` if a call to `method` may proceed, otherwise return how long the caller
+    /// should wait before retrying.
+    fn check(&self, method: &str) -> Result<(), Duration>;
+}
+
+/// A rate limiter that never limits any method. This is the default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopRateLimiter;
+
+impl RateLimiter for NoopRateLimiter {
+    fn check(&self, _method: &str) -> Result<(), Duration> {
+        Ok(())
+    }
+}
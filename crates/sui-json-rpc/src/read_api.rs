@@ -3,57 +3,548 @@
 
 use anyhow::anyhow;
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult;
-use move_binary_format::normalized::{Module as NormalizedModule, Type};
-use move_core_types::identifier::Identifier;
+use futures::StreamExt;
+use jsonrpsee::core::{Error as RpcError, RpcResult};
+use jsonrpsee::types::error::CallError;
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::file_format::{AbilitySet, Bytecode, CompiledModule};
+use move_binary_format::normalized::{
+    Module as NormalizedModule, Struct as NormalizedStruct, Type,
+};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::{IdentStr, Identifier};
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue};
+use serde_json::Value;
 use std::collections::BTreeMap;
-use std::sync::Arc;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use sui_types::intent::{AppId, Intent, IntentMessage, IntentScope, IntentVersion};
 use tap::TapFallible;
+use tokio::time::{sleep, timeout};
+use tokio_util::sync::{CancellationToken, DropGuard};
 
 use crate::api::ReadApiServer;
-use fastcrypto::encoding::Base64;
-use jsonrpsee::RpcModule;
+use fastcrypto::encoding::{Base64, Encoding};
+use jsonrpsee::types::SubscriptionResult;
+use jsonrpsee::{RpcModule, SubscriptionSink};
 use sui_core::authority::AuthorityState;
+use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    Checkpoint, CheckpointId, DynamicFieldPage, GetObjectDataResponse, GetPastObjectDataResponse,
-    GetRawObjectDataResponse, MoveFunctionArgType, ObjectValueKind, Page,
-    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo,
-    SuiTransactionEffects, SuiTransactionResponse, TransactionsPage,
+    AddressSummary, Checkpoint, CheckpointDigestsPage, CheckpointId, CheckpointSignatureInfo,
+    CheckpointTips, CommandEffect, DynamicFieldPage, EffectsAndEvents, GetObjectDataResponse,
+    GetPastObjectDataResponse, GetRawObjectDataResponse, IntentInfo, ModuleAbi,
+    MoveFunctionArgType, ObjectOwnershipChange, ObjectValueKind, ObjectWithFields, ObjectsPage,
+    OwnedObjectRef, OwnershipProof, PackagePreloadResult, Page, SharedObjectInfo,
+    StorageHistoryEntry, StorageHistoryPage, SuiExecutionStatus, SuiGasObjectState,
+    SuiMoveCallObjectArgument, SuiMoveFunctionComplexity, SuiMoveFunctionRef,
+    SuiMoveNormalizedConstant, SuiMoveNormalizedField, SuiMoveNormalizedFunction,
+    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiMoveNormalizedStructResult,
+    SuiMoveNormalizedType, SuiMoveValue, SuiNestedObjectRef, SuiNestedObjectResolution, SuiObject,
+    SuiObjectDataOptions, SuiObjectInfo, SuiObjectRead, SuiObjectRef, SuiObjectResponse,
+    SuiObjectTypeAbilities, SuiParsedData, SuiParsedObject, SuiPastObjectRead, SuiRawData,
+    SuiTransaction, SuiTransactionData, SuiTransactionEffects, SuiTransactionKind,
+    SuiTransactionResponse, SuiTransactionResponseOptions, SuiTransactionSignature,
+    SuiTransactionStatus, SuiTransactionSummary, TimestampedTransactionsPage,
+    TransactionTimestampCursor, TransactionsInRangePage, TransactionsPage, TypeCompatibility,
 };
 use sui_open_rpc::Module;
 use sui_types::base_types::SequenceNumber;
-use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest, TxSequenceNumber};
+use sui_types::base_types::{
+    ExecutionDigests, ObjectDigest, ObjectID, ObjectType, SuiAddress, TransactionDigest,
+    TransactionEffectsDigest, TxSequenceNumber, STD_ASCII_MODULE_NAME, STD_ASCII_STRUCT_NAME,
+    STD_UTF8_MODULE_NAME, STD_UTF8_STRUCT_NAME,
+};
+use sui_types::coin::{Coin, LockedCoin};
 use sui_types::crypto::sha3_hash;
-use sui_types::messages::TransactionData;
+use sui_types::gas_coin::GasCoin;
+use sui_types::governance::StakedSui;
+use sui_types::id::{ID, UID};
+use sui_types::message_envelope::Message;
+use sui_types::messages::{
+    CallArg, ObjectArg, SingleTransactionKind, TransactionData, TransactionEffects, TransactionKind,
+};
 use sui_types::messages_checkpoint::{
     CheckpointContents, CheckpointContentsDigest, CheckpointDigest, CheckpointSequenceNumber,
     CheckpointSummary,
 };
 use sui_types::move_package::normalize_modules;
-use sui_types::object::{Data, ObjectRead};
-use sui_types::query::TransactionQuery;
+use sui_types::object::{Data, MoveObject, Object, ObjectFormatOptions, ObjectRead, Owner};
+use sui_types::query::{EventQuery, TransactionQuery};
+use sui_types::{parse_sui_struct_tag, MOVE_STDLIB_ADDRESS};
 
-use sui_types::dynamic_field::DynamicFieldName;
-use tracing::debug;
+use sui_types::dynamic_field::{DynamicFieldName, DynamicFieldType};
+use sui_types::error::SuiError;
+use tracing::{debug, warn};
 
-use crate::api::cap_page_limit;
+use crate::api::{
+    cap_page_limit, check_page_cursor, cursor_not_found_error, MAX_CHECKPOINT_EXPORT_BYTES,
+    MAX_CHECKPOINT_EXPORT_RANGE, MAX_TRANSACTION_QUERY_ADDRESSES, QUERY_MAX_RESULT_LIMIT,
+};
 use crate::error::Error;
+use crate::event_api::spawn_subscription;
+use crate::rate_limiter::{NoopRateLimiter, RateLimiter};
 use crate::SuiRpcModule;
 
+/// Nested struct depth up to which `fixed_size` of a normalized struct is computed.
+const MAX_FIXED_SIZE_DEPTH: usize = 16;
+
+/// Instruction counts up to which a function body is considered cheap, and moderately expensive,
+/// by `function_complexity_hint`.
+const LOW_COMPLEXITY_MAX_INSTRUCTIONS: usize = 32;
+const MEDIUM_COMPLEXITY_MAX_INSTRUCTIONS: usize = 256;
+
+/// Number of transactions pushed per item of a `streamTransactions` subscription.
+const STREAM_TRANSACTIONS_BATCH_SIZE: usize = 50;
+
+/// How long `getObjectAtLeastVersion` waits by default, and at most, for an object version.
+const DEFAULT_OBJECT_VERSION_TIMEOUT_MS: u64 = 5_000;
+const MAX_OBJECT_VERSION_TIMEOUT_MS: u64 = 30_000;
+
+/// Interval between reads of an object while waiting for it to reach a version.
+const OBJECT_VERSION_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Number of distinct objects looked up to resolve the nested references of a single object.
+const MAX_NESTED_OBJECT_REF_LOOKUPS: usize = 16;
+
+/// Number of objects read to resolve the types of the object arguments of a single transaction.
+const MAX_OBJECT_ARGUMENT_LOOKUPS: usize = 64;
+
+/// Number of versions of an object walked back to find its state as of a checkpoint.
+const MAX_PAST_OBJECT_VERSIONS_WALKED: usize = 1_000;
+
 // An implementation of the read portion of the JSON-RPC interface intended for use in
 // Fullnodes.
+#[derive(Clone)]
 pub struct ReadApi {
     pub state: Arc<AuthorityState>,
+    /// The maximum number of transactions in checkpoint contents returned by a single request.
+    pub max_checkpoint_contents_size: usize,
+    /// Normalized modules keyed by package ID and module name. Published packages are immutable,
+    /// so entries never go stale.
+    normalized_module_cache: Arc<RwLock<BTreeMap<(ObjectID, String), NormalizedModule>>>,
+    /// The maximum number of immutable objects kept in the immutable object cache, further
+    /// immutable objects are read from the store every time.
+    pub max_immutable_object_cache_size: usize,
+    /// Responses for objects owned by `Owner::Immutable`, filled on first read. Immutable objects
+    /// never change, so entries never go stale.
+    immutable_object_cache: Arc<RwLock<BTreeMap<ObjectID, GetObjectDataResponse>>>,
+    /// The maximum number of struct layouts kept in the layout cache, further layouts are built
+    /// every time they are needed.
+    pub max_layout_cache_size: usize,
+    /// Layouts of the object and event types read so far, keyed by their fully instantiated
+    /// type. Such a layout never changes, so entries never go stale.
+    layout_cache: Arc<RwLock<BTreeMap<StructTag, MoveStructLayout>>>,
+    /// Number of units of work done by long read loops, see `next_read_unit`.
+    completed_read_units: Arc<AtomicUsize>,
+    /// Consulted at the start of every handler, defaults to a limiter that never limits.
+    pub rate_limiter: Arc<dyn RateLimiter>,
+    /// Whether the methods meant for node operators are served, off by default as they let a
+    /// caller affect every other caller of the node.
+    pub enable_admin_methods: bool,
 }
 
+pub const DEFAULT_MAX_CHECKPOINT_CONTENTS_SIZE: usize = 10_000;
+pub const DEFAULT_MAX_IMMUTABLE_OBJECT_CACHE_SIZE: usize = 1_000;
+pub const DEFAULT_MAX_LAYOUT_CACHE_SIZE: usize = 10_000;
+
 impl ReadApi {
     pub fn new(state: Arc<AuthorityState>) -> Self {
-        Self { state }
+        Self {
+            state,
+            max_checkpoint_contents_size: DEFAULT_MAX_CHECKPOINT_CONTENTS_SIZE,
+            normalized_module_cache: Arc::new(RwLock::new(BTreeMap::new())),
+            max_immutable_object_cache_size: DEFAULT_MAX_IMMUTABLE_OBJECT_CACHE_SIZE,
+            immutable_object_cache: Arc::new(RwLock::new(BTreeMap::new())),
+            max_layout_cache_size: DEFAULT_MAX_LAYOUT_CACHE_SIZE,
+            layout_cache: Arc::new(RwLock::new(BTreeMap::new())),
+            completed_read_units: Arc::new(AtomicUsize::new(0)),
+            rate_limiter: Arc::new(NoopRateLimiter),
+            enable_admin_methods: false,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn cached_module_count(&self) -> usize {
+        self.normalized_module_cache.read().unwrap().len()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn cached_immutable_object_count(&self) -> usize {
+        self.immutable_object_cache.read().unwrap().len()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn cached_layout_count(&self) -> usize {
+        self.layout_cache.read().unwrap().len()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn completed_read_units(&self) -> usize {
+        self.completed_read_units.load(Ordering::Relaxed)
+    }
+
+    /// Called after each unit of work of a long read loop. Store reads do not yield on their own,
+    /// so this yields, which lets the runtime drop the request future between units once its
+    /// client went away.
+    async fn next_read_unit(&self) {
+        self.completed_read_units.fetch_add(1, Ordering::Relaxed);
+        tokio::task::yield_now().await;
+    }
+
+    fn check_rate_limit(&self, method: &str) -> Result<(), Error> {
+        self.rate_limiter
+            .check(method)
+            .map_err(|retry_after| Error::RateLimited {
+                method: method.to_string(),
+                retry_after,
+            })
+    }
+
+    /// Convert `module`, filling in what the normalized module lacks: the fixed sizes of its
+    /// structs and its constant pool.
+    fn to_sui_module(&self, module: NormalizedModule) -> Result<SuiMoveNormalizedModule, SuiError> {
+        let compiled = self.get_compiled_module(ObjectID::from(module.address), &module.name)?;
+        let constants = module_constants(&compiled);
+        let sizes = module
+            .structs
+            .iter()
+            .map(|(name, struct_)| {
+                let size = self.struct_fixed_size(struct_, &[], MAX_FIXED_SIZE_DEPTH);
+                (name.to_string(), size)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut sui_module = SuiMoveNormalizedModule::from(module);
+        for (name, struct_) in sui_module.structs.iter_mut() {
+            struct_.fixed_size = sizes.get(name).copied().flatten();
+        }
+        sui_module.constants = constants;
+        Ok(sui_module)
+    }
+
+    fn get_compiled_module(
+        &self,
+        package: ObjectID,
+        module: &Identifier,
+    ) -> Result<CompiledModule, SuiError> {
+        let object = self
+            .state
+            .database
+            .get_object(&package)?
+            .ok_or(SuiError::ObjectNotFound {
+                object_id: package,
+                version: None,
+            })?;
+        match &object.data {
+            Data::Package(p) => p.deserialize_module(module),
+            _ => Err(SuiError::BadObjectType {
+                error: format!("Object {package} is not a package"),
+            }),
+        }
+    }
+
+    /// Return the BCS serialized size of `struct_` if it is the same for all of its values.
+    /// `type_arg_sizes` holds the fixed sizes of the struct's type arguments, where known. Nested
+    /// structs are resolved through their defining modules, at most `depth` levels deep.
+    fn struct_fixed_size(
+        &self,
+        struct_: &NormalizedStruct,
+        type_arg_sizes: &[Option<usize>],
+        depth: usize,
+    ) -> Option<usize> {
+        struct_.fields.iter().try_fold(0, |size, field| {
+            Some(size + self.type_fixed_size(&field.type_, type_arg_sizes, depth)?)
+        })
+    }
+
+    fn type_fixed_size(
+        &self,
+        type_: &Type,
+        type_arg_sizes: &[Option<usize>],
+        depth: usize,
+    ) -> Option<usize> {
+        match type_ {
+            Type::Bool | Type::U8 => Some(1),
+            Type::U16 => Some(2),
+            Type::U32 => Some(4),
+            Type::U64 => Some(8),
+            Type::U128 => Some(16),
+            Type::U256 => Some(32),
+            Type::Address => Some(AccountAddress::LENGTH),
+            Type::TypeParameter(index) => type_arg_sizes.get(*index as usize).copied().flatten(),
+            Type::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                let depth = depth.checked_sub(1)?;
+                let arg_sizes = type_arguments
+                    .iter()
+                    .map(|arg| self.type_fixed_size(arg, type_arg_sizes, depth))
+                    .collect::<Vec<_>>();
+                let struct_ = self.get_cached_struct(ObjectID::from(*address), module, name)?;
+                self.struct_fixed_size(&struct_, &arg_sizes, depth)
+            }
+            Type::Vector(_) | Type::Signer | Type::Reference(_) | Type::MutableReference(_) => None,
+        }
+    }
+
+    /// Return the abilities of `type_` with its type arguments applied.
+    fn type_abilities(&self, type_: &TypeTag) -> Option<AbilitySet> {
+        match type_ {
+            TypeTag::Bool
+            | TypeTag::U8
+            | TypeTag::U16
+            | TypeTag::U32
+            | TypeTag::U64
+            | TypeTag::U128
+            | TypeTag::U256
+            | TypeTag::Address => Some(AbilitySet::PRIMITIVES),
+            TypeTag::Signer => Some(AbilitySet::SIGNER),
+            TypeTag::Vector(type_) => AbilitySet::polymorphic_abilities(
+                AbilitySet::VECTOR,
+                vec![false],
+                vec![self.type_abilities(type_)?],
+            )
+            .ok(),
+            TypeTag::Struct(tag) => self.struct_abilities(tag),
+        }
+    }
+
+    /// Return the abilities of the struct type `tag`, which are its declared abilities restricted
+    /// by the abilities of its non-phantom type arguments.
+    fn struct_abilities(&self, tag: &StructTag) -> Option<AbilitySet> {
+        let struct_ =
+            self.get_cached_struct(ObjectID::from(tag.address), &tag.module, &tag.name)?;
+        let type_arguments = tag
+            .type_params
+            .iter()
+            .map(|type_| self.type_abilities(type_))
+            .collect::<Option<Vec<_>>>()?;
+        AbilitySet::polymorphic_abilities(
+            struct_.abilities,
+            struct_
+                .type_parameters
+                .iter()
+                .map(|parameter| parameter.is_phantom)
+                .collect::<Vec<_>>(),
+            type_arguments,
+        )
+        .ok()
+    }
+
+    /// Look up a struct definition through the normalized module cache, loading and caching its
+    /// module on a miss.
+    fn get_cached_struct(
+        &self,
+        package: ObjectID,
+        module: &Identifier,
+        name: &Identifier,
+    ) -> Option<NormalizedStruct> {
+        let key = (package, module.to_string());
+        if let Some(module) = self.normalized_module_cache.read().unwrap().get(&key) {
+            return module.structs.get(name).cloned();
+        }
+        let object = self.state.database.get_object(&package).ok()??;
+        let bytes = match &object.data {
+            Data::Package(p) => p.serialized_module_map().get(&key.1)?,
+            _ => return None,
+        };
+        let normalized = normalize_modules(std::iter::once(bytes))
+            .ok()?
+            .remove(&key.1)?;
+        let struct_ = normalized.structs.get(name).cloned();
+        self.normalized_module_cache
+            .write()
+            .unwrap()
+            .insert(key, normalized);
+        struct_
+    }
+
+    /// Build the layout of `struct_tag` through the layout cache, caching it on a miss while the
+    /// cache has room.
+    fn get_struct_layout(&self, struct_tag: &StructTag) -> Result<MoveStructLayout, SuiError> {
+        if let Some(layout) = self.layout_cache.read().unwrap().get(struct_tag) {
+            return Ok(layout.clone());
+        }
+        let layout = MoveObject::get_layout_from_struct_tag(
+            struct_tag.clone(),
+            ObjectFormatOptions::default(),
+            self.state.module_cache.as_ref(),
+        )?;
+        let mut cache = self.layout_cache.write().unwrap();
+        if cache.len() < self.max_layout_cache_size {
+            cache.insert(struct_tag.clone(), layout.clone());
+        }
+        Ok(layout)
+    }
+
+    fn get_object_layout(&self, object: &Object) -> Result<Option<MoveStructLayout>, SuiError> {
+        match &object.data {
+            Data::Move(move_object) => Ok(Some(self.get_struct_layout(&move_object.type_)?)),
+            Data::Package(_) => Ok(None),
+        }
+    }
+
+    async fn get_object_read(&self, object_id: &ObjectID) -> Result<ObjectRead, SuiError> {
+        self.state
+            .get_object_read_with_layout(object_id, |object| self.get_object_layout(object))
+            .await
+    }
+
+    fn to_sui_effects(
+        &self,
+        effects: TransactionEffects,
+    ) -> Result<SuiTransactionEffects, anyhow::Error> {
+        SuiTransactionEffects::try_from_with_layouts(effects, |type_| self.get_struct_layout(type_))
+    }
+
+    /// Return the reference and raw content of an object whose type could not be resolved,
+    /// along with the reason, rather than failing the whole request.
+    fn get_object_with_unresolved_type(
+        &self,
+        object_id: ObjectID,
+        error: String,
+    ) -> RpcResult<GetObjectDataResponse> {
+        let object = self
+            .state
+            .database
+            .get_object(&object_id)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::ObjectNotFound {
+                object_id,
+                version: None,
+            }))?;
+        Ok(SuiObjectRead::Exists(
+            SuiParsedObject::with_unresolved_type(object, error)?,
+        ))
+    }
+
+    async fn get_object_internal(&self, object_id: ObjectID) -> RpcResult<GetObjectDataResponse> {
+        if let Some(response) = self.immutable_object_cache.read().unwrap().get(&object_id) {
+            return Ok(response.clone());
+        }
+        let response: GetObjectDataResponse = match self.get_object_read(&object_id).await {
+            Ok(object_read) => object_read.try_into()?,
+            // The layout of the object's type could not be built
+            Err(SuiError::ObjectSerializationError { error }) => {
+                debug!(?object_id, "Failed to resolve object type: {error}");
+                self.get_object_with_unresolved_type(object_id, error)?
+            }
+            Err(e) => {
+                debug!(?object_id, "Failed to get object: {:?}", e);
+                return Err(anyhow!("{e}").into());
+            }
+        };
+        if matches!(&response, SuiObjectRead::Exists(object)
+            if object.owner == Owner::Immutable && object.type_resolution_error.is_none())
+        {
+            let mut cache = self.immutable_object_cache.write().unwrap();
+            if cache.len() < self.max_immutable_object_cache_size {
+                cache.insert(object_id, response.clone());
+            }
+        }
+        Ok(response)
+    }
+
+    async fn get_normalized_move_struct_internal(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        struct_name: String,
+    ) -> RpcResult<SuiMoveNormalizedStruct> {
+        let module = get_move_module(self, package, module_name).await?;
+        let structs = module.structs;
+        let identifier = Identifier::new(struct_name.as_str()).map_err(|e| anyhow!("{e}"))?;
+        Ok(match structs.get(&identifier) {
+            Some(struct_) => Ok(SuiMoveNormalizedStruct {
+                fixed_size: self.struct_fixed_size(struct_, &[], MAX_FIXED_SIZE_DEPTH),
+                ..struct_.clone().into()
+            }),
+            None => Err(anyhow!(
+                "No struct was found with struct name {}",
+                struct_name
+            )),
+        }?)
+    }
+
+    /// Resolve the normalized struct of a struct type string, ignoring its type arguments.
+    async fn get_normalized_move_struct_by_type(
+        &self,
+        type_: &str,
+    ) -> RpcResult<SuiMoveNormalizedStruct> {
+        let tag = parse_sui_struct_tag(type_)?;
+        self.get_normalized_move_struct_internal(
+            tag.address.into(),
+            tag.module.to_string(),
+            tag.name.to_string(),
+        )
+        .await
+    }
+
+    /// Wait until the live version of an object is at least `min_version`, then read it.
+    async fn wait_for_object_version(
+        &self,
+        object_id: ObjectID,
+        min_version: SequenceNumber,
+    ) -> RpcResult<GetObjectDataResponse> {
+        loop {
+            let object = self
+                .state
+                .database
+                .get_object(&object_id)
+                .map_err(Error::from)?;
+            if matches!(object, Some(object) if object.version() >= min_version) {
+                return self.get_object_internal(object_id).await;
+            }
+            sleep(OBJECT_VERSION_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Only live Move objects can hold dynamic fields, check the parent first so that a bad
+    /// parent is not reported as a missing field.
+    fn check_dynamic_field_parent(&self, parent_object_id: ObjectID) -> RpcResult<()> {
+        match self
+            .state
+            .database
+            .get_object(&parent_object_id)
+            .map_err(|e| anyhow!("{e}"))?
+        {
+            None => Err(anyhow!(
+                "Parent object [{parent_object_id}] does not exist or has been deleted."
+            )
+            .into()),
+            Some(parent) if parent.is_package() => Err(anyhow!(
+                "Parent object [{parent_object_id}] is a package and cannot hold dynamic fields."
+            )
+            .into()),
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn get_dynamic_fields_internal(
+        &self,
+        parent_object_id: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+        field_type: Option<DynamicFieldType>,
+    ) -> RpcResult<DynamicFieldPage> {
+        let limit = cap_page_limit(limit);
+        let mut data = self
+            .state
+            .get_dynamic_fields(parent_object_id, cursor, limit + 1, field_type)
+            .map_err(|e| anyhow!("{e}"))?;
+        check_page_cursor(cursor.as_ref(), data.first().map(|info| &info.object_id))?;
+        let next_cursor = data.get(limit).map(|info| info.object_id);
+        data.truncate(limit);
+        Ok(DynamicFieldPage { data, next_cursor })
     }
 
     fn get_checkpoint_internal(&self, id: CheckpointId) -> Result<Checkpoint, Error> {
-        Ok(match id {
+        let mut checkpoint: Checkpoint = match id {
             CheckpointId::SequenceNumber(seq) => {
                 let summary = self.state.get_checkpoint_summary_by_sequence_number(seq)?;
                 let content = self.state.get_checkpoint_contents(summary.content_digest)?;
@@ -64,8 +555,437 @@ impl ReadApi {
                 let content = self.state.get_checkpoint_contents(summary.content_digest)?;
                 (summary, content).into()
             }
+        };
+        self.add_epoch_context(&mut checkpoint)?;
+        Ok(checkpoint)
+    }
+
+    /// Fill in the position of `checkpoint` within its epoch. The size of the epoch is only
+    /// filled in once the epoch has ended.
+    fn add_epoch_context(&self, checkpoint: &mut Checkpoint) -> Result<(), Error> {
+        let first = self
+            .state
+            .get_epoch_first_checkpoint_sequence_number(checkpoint.epoch)?;
+        let last = self
+            .state
+            .get_epoch_last_checkpoint_sequence_number(checkpoint.epoch)?;
+        if let Some(first) = first {
+            checkpoint.epoch_first_checkpoint = Some(first);
+            checkpoint.epoch_checkpoint_index = Some(checkpoint.sequence_number - first);
+            checkpoint.epoch_checkpoint_count = last.map(|last| last - first + 1);
+        }
+        Ok(())
+    }
+
+    /// The index store fails opaquely on cursors of unknown transactions, reject them upfront.
+    fn check_transaction_cursor(&self, cursor: Option<TransactionDigest>) -> RpcResult<()> {
+        match cursor {
+            Some(cursor)
+                if !self
+                    .state
+                    .database
+                    .is_tx_already_executed(&cursor)
+                    .map_err(Error::from)? =>
+            {
+                Err(cursor_not_found_error(&cursor))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_transaction_query(query: &TransactionQuery) -> RpcResult<()> {
+        match query {
+            TransactionQuery::FromAnyOf(addresses) if addresses.is_empty() => Err(RpcError::Call(
+                CallError::InvalidParams(anyhow!("FromAnyOf requires at least one address")),
+            )),
+            TransactionQuery::FromAnyOf(addresses)
+                if addresses.len() > MAX_TRANSACTION_QUERY_ADDRESSES =>
+            {
+                Err(RpcError::Call(CallError::InvalidParams(anyhow!(
+                    "FromAnyOf accepts at most {MAX_TRANSACTION_QUERY_ADDRESSES} addresses, {} given",
+                    addresses.len()
+                ))))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Fetch the transactions of `query` starting at `cursor`, along with the cursor of the batch
+    /// that follows, if any.
+    async fn get_transactions_batch(
+        &self,
+        query: TransactionQuery,
+        cursor: Option<TransactionDigest>,
+    ) -> RpcResult<(Vec<SuiTransactionResponse>, Option<TransactionDigest>)> {
+        self.check_transaction_cursor(cursor)?;
+        let mut digests = self.state.get_transactions(
+            query,
+            cursor,
+            Some(STREAM_TRANSACTIONS_BATCH_SIZE + 1),
+            false,
+        )?;
+        check_page_cursor(cursor.as_ref(), digests.first())?;
+        let next_cursor = digests.get(STREAM_TRANSACTIONS_BATCH_SIZE).cloned();
+        digests.truncate(STREAM_TRANSACTIONS_BATCH_SIZE);
+
+        let mut batch = Vec::with_capacity(digests.len());
+        for digest in digests {
+            batch.push(self.get_transaction_internal(digest).await?);
+            self.next_read_unit().await;
+        }
+        Ok((batch, next_cursor))
+    }
+
+    /// Read the state of `object_id` as of `checkpoint`, walking back through the parent entries
+    /// of the object until one written by a transaction included in or before that checkpoint.
+    /// Fails if more than `MAX_PAST_OBJECT_VERSIONS_WALKED` versions were written since.
+    async fn get_past_object_at_checkpoint(
+        &self,
+        object_id: ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<GetPastObjectDataResponse> {
+        let database = &self.state.database;
+        let mut version = SequenceNumber::MAX;
+        let mut versions_walked = 0;
+        while let Some((obj_ref, tx_digest)) = database
+            .find_parent_entry_lt_or_eq_version(object_id, version)
+            .map_err(Error::from)?
+        {
+            if versions_walked == MAX_PAST_OBJECT_VERSIONS_WALKED {
+                return Err(anyhow!(
+                    "Object {object_id} has more than {MAX_PAST_OBJECT_VERSIONS_WALKED} versions written after checkpoint {checkpoint}, its state at that checkpoint cannot be read"
+                )
+                .into());
+            }
+            versions_walked += 1;
+            let tx_checkpoint = database
+                .get_transaction_checkpoint(&tx_digest)
+                .map_err(Error::from)?;
+            if matches!(tx_checkpoint, Some((_, seq)) if seq <= checkpoint) {
+                if !obj_ref.2.is_alive() {
+                    return Ok(SuiPastObjectRead::ObjectDeleted(obj_ref.into()));
+                }
+                let object = database
+                    .get_object_by_key(&object_id, obj_ref.1)
+                    .map_err(Error::from)?
+                    .ok_or_else(|| {
+                        anyhow!("State of object {object_id} at checkpoint {checkpoint} has been pruned")
+                    })?;
+                let layout = self.get_object_layout(&object).map_err(Error::from)?;
+                return Ok(SuiPastObjectRead::VersionFound(SuiObject::try_from(
+                    object, layout,
+                )?));
+            }
+            if obj_ref.1 == SequenceNumber::MIN {
+                break;
+            }
+            version = SequenceNumber::from_u64(obj_ref.1.value() - 1);
+            self.next_read_unit().await;
+        }
+        Ok(if versions_walked > 0 {
+            SuiPastObjectRead::NotCreatedAtCheckpoint {
+                object_id,
+                checkpoint,
+            }
+        } else {
+            SuiPastObjectRead::ObjectNotExists(object_id)
         })
     }
+
+    async fn get_transaction_internal(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionResponse> {
+        let (transaction, effects) = self
+            .state
+            .get_executed_transaction_and_effects(digest)
+            .await
+            .tap_err(|err| debug!(tx_digest=?digest, "Failed to get transaction: {:?}", err))?;
+        let checkpoint = self
+            .state
+            .database
+            .get_transaction_checkpoint(&digest)
+            .map_err(|e| anyhow!("{e}"))?;
+        let transaction = transaction.into_message();
+        let mut sui_transaction: SuiTransaction = transaction.clone().try_into()?;
+        self.decode_move_call_arguments(
+            &transaction.intent_message.value,
+            &mut sui_transaction.data,
+        )
+        .await;
+        Ok(SuiTransactionResponse {
+            transaction: sui_transaction,
+            effects: self.to_sui_effects(effects)?,
+            timestamp_ms: self.state.get_timestamp_ms(&digest).await?,
+            confirmed_local_execution: None,
+            checkpoint: checkpoint.map(|(_epoch, checkpoint)| checkpoint),
+            gas_object: None,
+            execution_digests: None,
+        })
+    }
+
+    /// Read the gas coin of a transaction at the version the transaction left it in, so the
+    /// balance is unaffected by later transactions.
+    fn get_gas_object_state(
+        &self,
+        effects: &SuiTransactionEffects,
+    ) -> RpcResult<SuiGasObjectState> {
+        let reference = effects.gas_object.reference.clone();
+        let object = self
+            .state
+            .database
+            .get_object_by_key(&reference.object_id, reference.version)
+            .map_err(Error::from)?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Gas object {} at version {} has been pruned",
+                    reference.object_id,
+                    reference.version
+                )
+            })?;
+        let balance = GasCoin::try_from(&object)
+            .map_err(|e| anyhow!("{e}"))?
+            .value();
+        Ok(SuiGasObjectState { reference, balance })
+    }
+
+    /// Resolve the `ID` and `UID` values nested in the fields of `object`, other than its own
+    /// `id`. At most `MAX_NESTED_OBJECT_REF_LOOKUPS` distinct objects are looked up, further
+    /// references are returned without a lookup.
+    async fn get_nested_object_refs(
+        &self,
+        object: &Object,
+    ) -> RpcResult<Option<Vec<SuiNestedObjectRef>>> {
+        let (move_object, layout) = match (
+            &object.data,
+            self.get_object_layout(object).map_err(Error::from)?,
+        ) {
+            (Data::Move(move_object), Some(layout)) => (move_object, layout),
+            _ => return Ok(None),
+        };
+        let move_struct = move_object.to_move_struct(&layout).map_err(Error::from)?;
+        let mut refs = vec![];
+        if let MoveStruct::WithTypes { fields, .. } = &move_struct {
+            for (name, value) in fields {
+                if name.as_str() != "id" {
+                    collect_nested_object_ids(value, name.to_string(), &mut refs);
+                }
+            }
+        }
+
+        let mut resolutions = BTreeMap::new();
+        let mut nested_refs = Vec::with_capacity(refs.len());
+        for (path, object_id) in refs {
+            let resolution = match resolutions.get(&object_id) {
+                Some(resolution) => resolution.clone(),
+                None if resolutions.len() >= MAX_NESTED_OBJECT_REF_LOOKUPS => {
+                    SuiNestedObjectResolution::NotLookedUp
+                }
+                None => {
+                    let resolution = match self
+                        .get_object_read(&object_id)
+                        .await
+                        .map_err(Error::from)?
+                    {
+                        ObjectRead::Exists(obj_ref, object, _) => {
+                            nested_object_resolution(obj_ref.1, &object)
+                        }
+                        _ => SuiNestedObjectResolution::Unresolved,
+                    };
+                    resolutions.insert(object_id, resolution.clone());
+                    resolution
+                }
+            };
+            nested_refs.push(SuiNestedObjectRef {
+                path,
+                object_id,
+                resolution,
+            });
+        }
+        Ok(Some(nested_refs))
+    }
+
+    /// Summarize a transaction, or return None for the genesis transaction, which has no
+    /// meaningful sender or status.
+    async fn get_transaction_summary(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Option<SuiTransactionSummary>> {
+        if digest == TransactionDigest::genesis() {
+            return Ok(None);
+        }
+        let (transaction, effects) = self
+            .state
+            .get_executed_transaction_and_effects(digest)
+            .await?;
+        let data = &transaction.data().intent_message.value;
+        if data.kind.is_genesis_tx() {
+            return Ok(None);
+        }
+        Ok(Some(SuiTransactionSummary {
+            digest,
+            status: effects.status.into(),
+            sender: data.sender(),
+            timestamp_ms: self.state.get_timestamp_ms(&digest).await?,
+        }))
+    }
+
+    /// Decode the pure arguments of the Move calls in the transaction using the parameter types
+    /// of the called functions, so that they are returned as typed values rather than raw bytes.
+    async fn decode_move_call_arguments(
+        &self,
+        data: &TransactionData,
+        sui_data: &mut SuiTransactionData,
+    ) {
+        let transactions = match &data.kind {
+            TransactionKind::Single(tx) => vec![tx],
+            TransactionKind::Batch(txs) => txs.iter().collect(),
+        };
+        for (tx, sui_tx) in transactions
+            .into_iter()
+            .zip(sui_data.transactions.iter_mut())
+        {
+            if let (SingleTransactionKind::Call(call), SuiTransactionKind::Call(sui_call)) =
+                (tx, sui_tx)
+            {
+                let module =
+                    match get_move_module(self, call.package, call.module.to_string()).await {
+                        Ok(module) => module,
+                        Err(e) => {
+                            debug!(package = ?call.package, "Failed to get module: {:?}", e);
+                            continue;
+                        }
+                    };
+                if let Some(function) = module.exposed_functions.get(&call.function) {
+                    let function = SuiMoveNormalizedFunction::from(function.clone());
+                    let type_arguments = call
+                        .type_arguments
+                        .iter()
+                        .cloned()
+                        .map(SuiMoveNormalizedType::from)
+                        .collect::<Vec<_>>();
+                    sui_call.decode_pure_arguments(
+                        &call.arguments,
+                        &function.parameters,
+                        &type_arguments,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Resolve the types of the objects passed to the Move calls of a transaction, reading each
+    /// object at the version the transaction used. At most `MAX_OBJECT_ARGUMENT_LOOKUPS` objects
+    /// are read, the types of the others are left unresolved, as are those of pruned versions.
+    fn resolve_object_argument_types(
+        &self,
+        data: &TransactionData,
+        response: &mut SuiTransactionResponse,
+    ) -> RpcResult<()> {
+        let shared_versions = response
+            .effects
+            .shared_objects
+            .iter()
+            .map(|reference| (reference.object_id, reference.version))
+            .collect::<BTreeMap<_, _>>();
+        let transactions = match &data.kind {
+            TransactionKind::Single(tx) => vec![tx],
+            TransactionKind::Batch(txs) => txs.iter().collect(),
+        };
+        let mut lookups = 0;
+        for (tx, sui_tx) in transactions
+            .into_iter()
+            .zip(response.transaction.data.transactions.iter_mut())
+        {
+            let (SingleTransactionKind::Call(call), SuiTransactionKind::Call(sui_call)) =
+                (tx, sui_tx)
+            else {
+                continue;
+            };
+            let mut object_arguments = vec![];
+            for (index, argument) in call.arguments.iter().enumerate() {
+                let objects = match argument {
+                    CallArg::Pure(_) => continue,
+                    CallArg::Object(object) => vec![*object],
+                    CallArg::ObjVec(objects) => objects.clone(),
+                };
+                for object in objects {
+                    let (object_id, version) = match object {
+                        ObjectArg::ImmOrOwnedObject((id, version, _)) => (id, version),
+                        ObjectArg::SharedObject {
+                            id,
+                            initial_shared_version,
+                            ..
+                        } => (
+                            id,
+                            shared_versions
+                                .get(&id)
+                                .copied()
+                                .unwrap_or(initial_shared_version),
+                        ),
+                    };
+                    let type_ = if lookups < MAX_OBJECT_ARGUMENT_LOOKUPS {
+                        lookups += 1;
+                        self.state
+                            .database
+                            .get_object_by_key(&object_id, version)
+                            .map_err(Error::from)?
+                            .and_then(|object| object.type_().map(|type_| type_.to_string()))
+                    } else {
+                        None
+                    };
+                    object_arguments.push(SuiMoveCallObjectArgument {
+                        index,
+                        object_id,
+                        version,
+                        type_,
+                    });
+                }
+            }
+            sui_call.object_arguments = Some(object_arguments);
+        }
+        Ok(())
+    }
+
+    /// Resolve the fields of a struct type, with the type arguments of the given type
+    /// substituted in for the struct's type parameters.
+    async fn get_instantiated_struct_fields(
+        &self,
+        struct_type: &str,
+    ) -> RpcResult<Vec<SuiMoveNormalizedField>> {
+        let tag = parse_sui_struct_tag(struct_type)?;
+        let module = get_move_module(self, tag.address.into(), tag.module.to_string()).await?;
+        let struct_ = module
+            .structs
+            .get(&tag.name)
+            .ok_or_else(|| anyhow!("No struct was found with struct name {}", tag.name))?;
+        let type_args = tag
+            .type_params
+            .into_iter()
+            .map(SuiMoveNormalizedType::from)
+            .collect::<Vec<_>>();
+        Ok(SuiMoveNormalizedStruct::from(struct_.clone()).instantiate_fields(&type_args)?)
+    }
+
+    /// Whether values of two fully instantiated types are laid out alike, comparing the layouts
+    /// of structs rather than their identities.
+    fn same_layout(
+        &self,
+        old: &SuiMoveNormalizedType,
+        new: &SuiMoveNormalizedType,
+    ) -> Result<bool, anyhow::Error> {
+        match (old, new) {
+            (SuiMoveNormalizedType::Vector(old), SuiMoveNormalizedType::Vector(new)) => {
+                self.same_layout(old, new)
+            }
+            (SuiMoveNormalizedType::Struct { .. }, SuiMoveNormalizedType::Struct { .. }) => {
+                let old = self.get_struct_layout(&parse_sui_struct_tag(&old.to_string())?)?;
+                let new = self.get_struct_layout(&parse_sui_struct_tag(&new.to_string())?)?;
+                Ok(same_struct_layout(&old, &new))
+            }
+            _ => Ok(old == new),
+        }
+    }
 }
 
 #[async_trait]
@@ -74,6 +994,7 @@ impl ReadApiServer for ReadApi {
         &self,
         address: SuiAddress,
     ) -> RpcResult<Vec<SuiObjectInfo>> {
+        self.check_rate_limit("sui_getObjectsOwnedByAddress")?;
         Ok(self
             .state
             .get_owner_objects(address)
@@ -83,32 +1004,284 @@ impl ReadApiServer for ReadApi {
             .collect())
     }
 
+    async fn get_objects_owned_by_address_updated_since(
+        &self,
+        address: SuiAddress,
+        updated_since_checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<SuiObjectInfo>> {
+        self.check_rate_limit("sui_getObjectsOwnedByAddressUpdatedSince")?;
+        let mut updated = vec![];
+        // Read the owner index to its end one page at a time, so no update is missed on addresses
+        // owning many objects
+        for object in self.state.get_all_owner_objects_iterator(address) {
+            let object = object.map_err(|e| anyhow!("{e}"))?;
+            let checkpoint = self
+                .state
+                .database
+                .get_transaction_checkpoint(&object.previous_transaction)
+                .map_err(Error::from)?;
+            // Transactions not yet in a checkpoint are more recent than any checkpoint.
+            if checkpoint.map_or(true, |(_, seq)| seq >= updated_since_checkpoint) {
+                updated.push(SuiObjectInfo::from(object));
+            }
+        }
+        Ok(updated)
+    }
+
+    async fn get_owned_storage_rebate(&self, owner: SuiAddress) -> RpcResult<u64> {
+        self.check_rate_limit("sui_getOwnedStorageRebate")?;
+        // Every object of the owner counts, so the owner index is read to its end one page at a time
+        let object_ids = self
+            .state
+            .get_all_owner_objects_iterator(owner)
+            .map(|info| info.map(|info| info.object_id))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("{e}"))?;
+        // Objects deleted since the index was read no longer hold a rebate.
+        let mut total: u64 = 0;
+        for object in self
+            .state
+            .get_objects(&object_ids)
+            .await
+            .map_err(Error::from)?
+            .into_iter()
+            .flatten()
+        {
+            total = total
+                .checked_add(object.storage_rebate)
+                .ok_or_else(|| anyhow!("Storage rebate of objects owned by {owner} overflows"))?;
+        }
+        Ok(total)
+    }
+
+    async fn get_address_summary(&self, address: SuiAddress) -> RpcResult<AddressSummary> {
+        self.check_rate_limit("sui_getAddressSummary")?;
+        let (objects, truncated) = self
+            .state
+            .get_owner_objects_bounded(address)
+            .map_err(|e| anyhow!("{e}"))?;
+
+        let sui_coin_type = GasCoin::type_();
+        let staked_sui_type = StakedSui::type_();
+        let mut object_count_by_type = BTreeMap::new();
+        let mut coin_object_count = 0;
+        // Only SUI coins and staked SUI need to be read, for their amounts
+        let mut amount_object_ids = vec![];
+        for info in &objects {
+            *object_count_by_type
+                .entry(info.type_.to_string())
+                .or_default() += 1;
+            if let ObjectType::Struct(type_) = &info.type_ {
+                if Coin::is_coin(type_) || LockedCoin::is_locked_coin(type_) {
+                    coin_object_count += 1;
+                }
+                if type_ == &sui_coin_type || type_ == &staked_sui_type {
+                    amount_object_ids.push(info.object_id);
+                }
+            }
+        }
+
+        let mut sui_balance = 0u128;
+        let mut staked_sui = 0u128;
+        // Objects deleted since the index was read no longer count.
+        for object in self
+            .state
+            .get_objects(&amount_object_ids)
+            .await
+            .map_err(Error::from)?
+            .into_iter()
+            .flatten()
+        {
+            let Some(move_object) = object.data.try_as_move() else {
+                continue;
+            };
+            if move_object.type_ == staked_sui_type {
+                let staked: StakedSui =
+                    bcs::from_bytes(move_object.contents()).map_err(Error::from)?;
+                staked_sui += staked.principal() as u128;
+            } else {
+                let coin: Coin = bcs::from_bytes(move_object.contents()).map_err(Error::from)?;
+                sui_balance += coin.balance.value() as u128;
+            }
+        }
+
+        Ok(AddressSummary {
+            sui_balance,
+            coin_object_count,
+            staked_sui,
+            object_count_by_type,
+            truncated,
+        })
+    }
+
     async fn get_dynamic_fields(
         &self,
         parent_object_id: ObjectID,
         cursor: Option<ObjectID>,
         limit: Option<usize>,
+        field_type: Option<DynamicFieldType>,
     ) -> RpcResult<DynamicFieldPage> {
+        self.check_rate_limit("sui_getDynamicFields")?;
+        self.get_dynamic_fields_internal(parent_object_id, cursor, limit, field_type)
+    }
+
+    async fn get_objects_by_type(
+        &self,
+        struct_type: String,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        self.check_rate_limit("sui_getObjectsByType")?;
+        let struct_type = parse_sui_struct_tag(&struct_type)?;
         let limit = cap_page_limit(limit);
         let mut data = self
             .state
-            .get_dynamic_fields(parent_object_id, cursor, limit + 1)
+            .get_objects_by_type(&struct_type, cursor, limit + 1)
             .map_err(|e| anyhow!("{e}"))?;
+        check_page_cursor(cursor.as_ref(), data.first().map(|info| &info.object_id))?;
         let next_cursor = data.get(limit).map(|info| info.object_id);
         data.truncate(limit);
-        Ok(DynamicFieldPage { data, next_cursor })
+        let data = data.into_iter().map(SuiObjectInfo::from).collect();
+        Ok(ObjectsPage { data, next_cursor })
+    }
+
+    async fn get_object_with_fields(
+        &self,
+        object_id: ObjectID,
+        fields_limit: Option<usize>,
+    ) -> RpcResult<ObjectWithFields> {
+        self.check_rate_limit("sui_getObjectWithFields")?;
+        Ok(ObjectWithFields {
+            object: self.get_object_internal(object_id).await?,
+            dynamic_fields: self.get_dynamic_fields_internal(
+                object_id,
+                None,
+                fields_limit,
+                None,
+            )?,
+        })
     }
 
     async fn get_object(&self, object_id: ObjectID) -> RpcResult<GetObjectDataResponse> {
-        Ok(self
+        self.check_rate_limit("sui_getObject")?;
+        self.get_object_internal(object_id).await
+    }
+
+    async fn get_object_at_least_version(
+        &self,
+        object_id: ObjectID,
+        min_version: SequenceNumber,
+        timeout_ms: Option<u64>,
+    ) -> RpcResult<GetObjectDataResponse> {
+        self.check_rate_limit("sui_getObjectAtLeastVersion")?;
+        let timeout_ms = timeout_ms
+            .unwrap_or(DEFAULT_OBJECT_VERSION_TIMEOUT_MS)
+            .min(MAX_OBJECT_VERSION_TIMEOUT_MS);
+        timeout(
+            Duration::from_millis(timeout_ms),
+            self.wait_for_object_version(object_id, min_version),
+        )
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "Object [{object_id}] did not reach version {min_version} within {timeout_ms}ms"
+            )
+        })?
+    }
+
+    async fn get_object_digest(&self, object_id: ObjectID) -> RpcResult<ObjectDigest> {
+        self.check_rate_limit("sui_getObjectDigest")?;
+        let object = self
             .state
-            .get_object_read(&object_id)
-            .await
-            .map_err(|e| {
-                debug!(?object_id, "Failed to get object: {:?}", e);
-                anyhow!("{e}")
-            })?
-            .try_into()?)
+            .database
+            .get_object(&object_id)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::ObjectNotFound {
+                object_id,
+                version: None,
+            }))?;
+        Ok(object.digest())
+    }
+
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>> {
+        self.check_rate_limit("sui_multiGetObjects")?;
+        if object_ids.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(
+                "Requested {} objects, which exceeds the max limit of {QUERY_MAX_RESULT_LIMIT}",
+                object_ids.len()
+            )
+            .into());
+        }
+        // Read each distinct object once, then fan the result out to every requested position.
+        let mut objects = BTreeMap::new();
+        for object_id in &object_ids {
+            if !objects.contains_key(object_id) {
+                objects.insert(*object_id, self.get_object_internal(*object_id).await?);
+                self.next_read_unit().await;
+            }
+        }
+        Ok(object_ids
+            .iter()
+            .map(|object_id| objects[object_id].clone())
+            .collect())
+    }
+
+    async fn get_object_with_options(
+        &self,
+        object_id: ObjectID,
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<SuiObjectResponse> {
+        self.check_rate_limit("sui_getObjectWithOptions")?;
+        let options = options.unwrap_or_default();
+        let object_read = self.get_object_read(&object_id).await.map_err(|e| {
+            debug!(?object_id, "Failed to get object: {:?}", e);
+            anyhow!("{e}")
+        })?;
+        let bcs = match &object_read {
+            ObjectRead::Exists(_, object, _) if options.show_bcs => Some(match &object.data {
+                Data::Move(o) => SuiRawData::MoveObject(o.clone().into()),
+                Data::Package(p) => SuiRawData::Package(p.clone().into()),
+            }),
+            _ => None,
+        };
+        let previous_transaction_summary = match &object_read {
+            ObjectRead::Exists(_, object, _) if options.show_previous_transaction_summary => {
+                self.get_transaction_summary(object.previous_transaction)
+                    .await?
+            }
+            _ => None,
+        };
+        let type_abilities = match &object_read {
+            ObjectRead::Exists(_, object, _) if options.show_type_abilities => object
+                .type_()
+                .and_then(|type_| self.struct_abilities(type_))
+                .map(SuiObjectTypeAbilities::from),
+            _ => None,
+        };
+        let nested_refs = match &object_read {
+            ObjectRead::Exists(_, object, _) if options.resolve_nested_refs => {
+                self.get_nested_object_refs(object).await?
+            }
+            _ => None,
+        };
+        let mut details: GetObjectDataResponse = object_read.try_into()?;
+        if let (Some(max_depth), SuiObjectRead::Exists(object)) =
+            (options.content_max_depth, &mut details)
+        {
+            if let SuiParsedData::MoveObject(move_object) = &mut object.data {
+                move_object.fields.truncate(max_depth);
+            }
+        }
+        Ok(SuiObjectResponse {
+            details,
+            bcs,
+            previous_transaction_summary,
+            type_abilities,
+            nested_refs,
+        })
     }
 
     async fn get_dynamic_field_object(
@@ -116,6 +1289,8 @@ impl ReadApiServer for ReadApi {
         parent_object_id: ObjectID,
         name: DynamicFieldName,
     ) -> RpcResult<GetObjectDataResponse> {
+        self.check_rate_limit("sui_getDynamicFieldObject")?;
+        self.check_dynamic_field_parent(parent_object_id)?;
         let id = self
             .state
             .get_dynamic_field_object_id(parent_object_id, &name)
@@ -123,18 +1298,80 @@ impl ReadApiServer for ReadApi {
             .ok_or_else(|| {
                 anyhow!("Cannot find dynamic field [{name:?}] for object [{parent_object_id}].")
             })?;
-        self.get_object(id).await
+        self.get_object_internal(id).await
+    }
+
+    async fn get_dynamic_field_object_by_value(
+        &self,
+        parent_object_id: ObjectID,
+        value: SuiJsonValue,
+    ) -> RpcResult<GetObjectDataResponse> {
+        self.check_rate_limit("sui_getDynamicFieldObjectByValue")?;
+        self.check_dynamic_field_parent(parent_object_id)?;
+        let value = value.to_json_value();
+        // Look up every name the value can stand for, rather than reading all fields of the parent
+        let candidates = dynamic_field_name_candidates(&value);
+        let mut matching = vec![];
+        for name in &candidates {
+            if let Some(id) = self
+                .state
+                .get_dynamic_field_object_id(parent_object_id, name)
+                .map_err(|e| anyhow!("{e}"))?
+            {
+                matching.push((name, id));
+            }
+        }
+        let type_names = |names: Vec<&DynamicFieldName>| {
+            names
+                .iter()
+                .map(|name| name.type_.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match &matching[..] {
+            [(_, id)] => self.get_object_internal(*id).await,
+            [] => Err(anyhow!(
+                "Cannot find dynamic field with name value [{value}] for object \
+                [{parent_object_id}], tried name types [{}].",
+                type_names(candidates.iter().collect())
+            )
+            .into()),
+            _ => Err(anyhow!(
+                "Name value [{value}] matches dynamic fields of several name types [{}] for \
+                object [{parent_object_id}], use getDynamicFieldObject with an explicit type.",
+                type_names(matching.iter().map(|(name, _)| *name).collect())
+            )
+            .into()),
+        }
     }
 
     async fn get_total_transaction_number(&self) -> RpcResult<u64> {
+        self.check_rate_limit("sui_getTotalTransactionNumber")?;
         Ok(self.state.get_total_transaction_number()?)
     }
 
+    async fn get_total_transaction_number_at_checkpoint(
+        &self,
+        sequence_number: Option<CheckpointSequenceNumber>,
+    ) -> RpcResult<u64> {
+        self.check_rate_limit("sui_getTotalTransactionNumberAtCheckpoint")?;
+        let sequence_number = match sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => self.state.get_latest_checkpoint_sequence_number()?,
+        };
+        let summary = self
+            .state
+            .get_checkpoint_summary_by_sequence_number(sequence_number)
+            .map_err(|e| anyhow!("Checkpoint summary based on sequence number: {sequence_number} was not found with error :{e}"))?;
+        Ok(summary.network_total_transactions)
+    }
+
     async fn get_transactions_in_range(
         &self,
         start: TxSequenceNumber,
         end: TxSequenceNumber,
     ) -> RpcResult<Vec<TransactionDigest>> {
+        self.check_rate_limit("sui_getTransactionsInRange")?;
         Ok(self
             .state
             .get_transactions_in_range(start, end)?
@@ -143,38 +1380,255 @@ impl ReadApiServer for ReadApi {
             .collect())
     }
 
+    async fn get_transactions_in_range_paged(
+        &self,
+        start: TxSequenceNumber,
+        end: TxSequenceNumber,
+        cursor: Option<TxSequenceNumber>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<TransactionsInRangePage> {
+        self.check_rate_limit("sui_getTransactionsInRangePaged")?;
+        let limit = cap_page_limit(limit);
+        // the index enforces the range bounds and the maximum range width.
+        let mut txs = self.state.get_transactions_in_range(start, end)?;
+        let descending = descending_order.unwrap_or_default();
+        if descending {
+            txs.reverse();
+        }
+        let mut data = txs
+            .into_iter()
+            .filter(|(seq, _)| match cursor {
+                Some(cursor) if descending => *seq <= cursor,
+                Some(cursor) => *seq >= cursor,
+                None => true,
+            })
+            .take(limit + 1)
+            .collect::<Vec<_>>();
+        check_page_cursor(cursor.as_ref(), data.first().map(|(seq, _)| seq))?;
+        let next_cursor = data.get(limit).map(|(seq, _)| *seq);
+        data.truncate(limit);
+        Ok(TransactionsInRangePage {
+            data: data.into_iter().map(|(_, digest)| digest).collect(),
+            next_cursor,
+        })
+    }
+
     async fn get_transaction(
         &self,
         digest: TransactionDigest,
     ) -> RpcResult<SuiTransactionResponse> {
-        let (transaction, effects) = self
+        self.check_rate_limit("sui_getTransaction")?;
+        self.get_transaction_internal(digest).await
+    }
+
+    async fn get_transaction_with_options(
+        &self,
+        digest: TransactionDigest,
+        options: Option<SuiTransactionResponseOptions>,
+    ) -> RpcResult<SuiTransactionResponse> {
+        self.check_rate_limit("sui_getTransactionWithOptions")?;
+        let options = options.unwrap_or_default();
+        let mut response = self.get_transaction_internal(digest).await?;
+        if options.show_gas_object {
+            response.gas_object = Some(self.get_gas_object_state(&response.effects)?);
+        }
+        if options.show_object_argument_types {
+            let (transaction, _) = self
+                .state
+                .get_executed_transaction_and_effects(digest)
+                .await?;
+            self.resolve_object_argument_types(
+                &transaction.data().intent_message.value,
+                &mut response,
+            )?;
+        }
+        if options.show_execution_digests {
+            let effects = self
+                .state
+                .database
+                .get_executed_effects(&digest)
+                .map_err(Error::from)?
+                .ok_or(Error::SuiError(SuiError::TransactionNotFound { digest }))?;
+            response.execution_digests = Some(ExecutionDigests::new(digest, effects.digest()));
+        }
+        Ok(response)
+    }
+
+    async fn get_transaction_status_by_bytes(
+        &self,
+        tx_bytes: Base64,
+    ) -> RpcResult<SuiTransactionStatus> {
+        self.check_rate_limit("sui_getTransactionStatusByBytes")?;
+        let (_, digest) = get_transaction_data_and_digest(tx_bytes)
+            .map_err(|e| RpcError::Call(CallError::InvalidParams(anyhow!("{e}"))))?;
+        if !self
             .state
-            .get_executed_transaction_and_effects(digest)
-            .await
-            .tap_err(|err| debug!(tx_digest=?digest, "Failed to get transaction: {:?}", err))?;
+            .database
+            .is_tx_already_executed(&digest)
+            .map_err(Error::from)?
+        {
+            return Ok(SuiTransactionStatus::NotExecuted { digest });
+        }
         let checkpoint = self
             .state
             .database
             .get_transaction_checkpoint(&digest)
-            .map_err(|e| anyhow!("{e}"))?;
-        Ok(SuiTransactionResponse {
-            transaction: transaction.into_message().try_into()?,
-            effects: SuiTransactionEffects::try_from(effects, self.state.module_cache.as_ref())?,
-            timestamp_ms: self.state.get_timestamp_ms(&digest).await?,
-            confirmed_local_execution: None,
+            .map_err(Error::from)?;
+        Ok(SuiTransactionStatus::Executed {
+            digest,
             checkpoint: checkpoint.map(|(_epoch, checkpoint)| checkpoint),
         })
     }
 
+    async fn get_transaction_intent_info(&self, tx_bytes: Base64) -> RpcResult<IntentInfo> {
+        self.check_rate_limit("sui_getTransactionIntentInfo")?;
+        let intent_msg = get_transaction_intent_message(tx_bytes)
+            .map_err(|e| RpcError::Call(CallError::InvalidParams(anyhow!("{e}"))))?;
+        let intent = &intent_msg.intent;
+        Ok(IntentInfo {
+            scope: format!("{:?}", intent.scope),
+            version: intent.version as u8,
+            app_id: format!("{:?}", intent.app_id),
+            intent_bytes: Base64::from_bytes(&bcs::to_bytes(intent).map_err(Error::from)?),
+            digest: TransactionDigest::new(sha3_hash(&intent_msg.value)),
+        })
+    }
+
+    async fn get_transaction_by_effects_digest(
+        &self,
+        effects_digest: TransactionEffectsDigest,
+    ) -> RpcResult<SuiTransactionResponse> {
+        self.check_rate_limit("sui_getTransactionByEffectsDigest")?;
+        let digest = self
+            .state
+            .get_transaction_digest_by_effects_digest(&effects_digest)
+            .map_err(Error::from)?;
+        self.get_transaction_internal(digest).await
+    }
+
+    async fn get_latest_transaction_by_address(
+        &self,
+        address: SuiAddress,
+    ) -> RpcResult<Option<SuiTransactionResponse>> {
+        self.check_rate_limit("sui_getLatestTransactionByAddress")?;
+        match self.state.get_latest_transaction_by_address(address)? {
+            Some(digest) => Ok(Some(self.get_transaction_internal(digest).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_transaction_signatures(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<SuiTransactionSignature>> {
+        self.check_rate_limit("sui_getTransactionSignatures")?;
+        let transaction = self
+            .state
+            .database
+            .get_transaction(&digest)
+            .map_err(|e| anyhow!("{e}"))?
+            .ok_or(Error::SuiError(SuiError::TransactionNotFound { digest }))?;
+        Ok(transaction
+            .into_message()
+            .tx_signatures
+            .into_iter()
+            .map(SuiTransactionSignature::from)
+            .collect())
+    }
+
+    async fn get_transaction_effects(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionEffects> {
+        self.check_rate_limit("sui_getTransactionEffects")?;
+        let effects = self
+            .state
+            .database
+            .get_executed_effects(&digest)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::TransactionNotFound { digest }))?;
+        Ok(self.to_sui_effects(effects)?)
+    }
+
+    async fn get_transaction_effects_and_events(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<EffectsAndEvents> {
+        self.check_rate_limit("sui_getTransactionEffectsAndEvents")?;
+        let effects = self
+            .state
+            .database
+            .get_executed_effects(&digest)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::TransactionNotFound { digest }))?;
+        let events = if effects.events.is_empty() {
+            vec![]
+        } else {
+            self.state
+                .get_events(
+                    EventQuery::Transaction(digest),
+                    None,
+                    effects.events.len(),
+                    false,
+                )
+                .await?
+                .into_iter()
+                .map(|(_, event)| event)
+                .collect()
+        };
+        Ok(EffectsAndEvents {
+            effects: self.to_sui_effects(effects)?,
+            events,
+        })
+    }
+
+    async fn get_transaction_command_effects(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<CommandEffect>> {
+        self.check_rate_limit("sui_getTransactionCommandEffects")?;
+        if !self
+            .state
+            .database
+            .is_tx_already_executed(&digest)
+            .map_err(Error::from)?
+        {
+            return Err(Error::SuiError(SuiError::TransactionNotFound { digest }).into());
+        }
+        // Only the effects of the whole transaction are stored, the results of each command are
+        // discarded once it is executed.
+        Err(Error::Unsupported {
+            method: "sui_getTransactionCommandEffects".to_string(),
+            reason: "per-command execution traces are not retained".to_string(),
+        }
+        .into())
+    }
+
+    async fn get_transaction_execution_status(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Option<SuiExecutionStatus>> {
+        self.check_rate_limit("sui_getTransactionExecutionStatus")?;
+        Ok(self
+            .state
+            .database
+            .get_executed_effects(&digest)
+            .map_err(Error::from)?
+            .map(|effects| effects.status.into()))
+    }
+
     async fn get_normalized_move_modules_by_package(
         &self,
         package: ObjectID,
     ) -> RpcResult<BTreeMap<String, SuiMoveNormalizedModule>> {
+        self.check_rate_limit("sui_getNormalizedMoveModulesByPackage")?;
         let modules = get_move_modules_by_package(self, package).await?;
         Ok(modules
             .into_iter()
-            .map(|(name, module)| (name, module.into()))
-            .collect::<BTreeMap<String, SuiMoveNormalizedModule>>())
+            .map(|(name, module)| Ok((name, self.to_sui_module(module)?)))
+            .collect::<Result<BTreeMap<String, SuiMoveNormalizedModule>, SuiError>>()
+            .map_err(Error::from)?)
     }
 
     async fn get_normalized_move_module(
@@ -182,8 +1636,15 @@ impl ReadApiServer for ReadApi {
         package: ObjectID,
         module_name: String,
     ) -> RpcResult<SuiMoveNormalizedModule> {
+        self.check_rate_limit("sui_getNormalizedMoveModule")?;
+        let module = get_move_module(self, package, module_name).await?;
+        Ok(self.to_sui_module(module).map_err(Error::from)?)
+    }
+
+    async fn get_module_abi(&self, package: ObjectID, module_name: String) -> RpcResult<ModuleAbi> {
+        self.check_rate_limit("sui_getModuleAbi")?;
         let module = get_move_module(self, package, module_name).await?;
-        Ok(module.into())
+        Ok(SuiMoveNormalizedModule::from(module).into())
     }
 
     async fn get_normalized_move_struct(
@@ -192,16 +1653,38 @@ impl ReadApiServer for ReadApi {
         module_name: String,
         struct_name: String,
     ) -> RpcResult<SuiMoveNormalizedStruct> {
-        let module = get_move_module(self, package, module_name).await?;
-        let structs = module.structs;
-        let identifier = Identifier::new(struct_name.as_str()).map_err(|e| anyhow!("{e}"))?;
-        Ok(match structs.get(&identifier) {
-            Some(struct_) => Ok(struct_.clone().into()),
-            None => Err(anyhow!(
-                "No struct was found with struct name {}",
-                struct_name
-            )),
-        }?)
+        self.check_rate_limit("sui_getNormalizedMoveStruct")?;
+        self.get_normalized_move_struct_internal(package, module_name, struct_name)
+            .await
+    }
+
+    async fn multi_get_normalized_move_structs_by_type(
+        &self,
+        types: Vec<String>,
+    ) -> RpcResult<Vec<SuiMoveNormalizedStructResult>> {
+        self.check_rate_limit("sui_multiGetNormalizedMoveStructsByType")?;
+        if types.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(
+                "Requested {} types, which exceeds the max limit of {QUERY_MAX_RESULT_LIMIT}",
+                types.len()
+            )
+            .into());
+        }
+        let results = futures::future::join_all(
+            types
+                .iter()
+                .map(|type_| self.get_normalized_move_struct_by_type(type_)),
+        )
+        .await;
+        Ok(results
+            .into_iter()
+            .map(|result| match result {
+                Ok(struct_) => SuiMoveNormalizedStructResult::Resolved(struct_),
+                Err(e) => SuiMoveNormalizedStructResult::Error {
+                    error: e.to_string(),
+                },
+            })
+            .collect())
     }
 
     async fn get_normalized_move_function(
@@ -209,17 +1692,100 @@ impl ReadApiServer for ReadApi {
         package: ObjectID,
         module_name: String,
         function_name: String,
+        include_calls: Option<bool>,
     ) -> RpcResult<SuiMoveNormalizedFunction> {
-        let module = get_move_module(self, package, module_name).await?;
+        self.check_rate_limit("sui_getNormalizedMoveFunction")?;
+        let module = get_move_module(self, package, module_name.clone()).await?;
         let functions = module.exposed_functions;
         let identifier = Identifier::new(function_name.as_str()).map_err(|e| anyhow!("{e}"))?;
-        Ok(match functions.get(&identifier) {
-            Some(function) => Ok(function.clone().into()),
+        let mut function = match functions.get(&identifier) {
+            Some(function) => Ok(SuiMoveNormalizedFunction::from_named(
+                identifier.as_str(),
+                function.clone(),
+            )),
             None => Err(anyhow!(
                 "No function was found with function name {}",
                 function_name
             )),
-        }?)
+        }?;
+        let module_name = Identifier::new(module_name).map_err(|e| anyhow!("{e}"))?;
+        let compiled_module = match self
+            .get_object_read(&package)
+            .await
+            .map_err(|e| anyhow!("{e}"))?
+        {
+            ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
+                Data::Package(p) => p
+                    .deserialize_module(&module_name)
+                    .map_err(|e| anyhow!("{e}"))?,
+                _ => return Err(anyhow!("Object is not a package with ID {}", package).into()),
+            },
+            _ => return Err(anyhow!("Package object does not exist with ID {}", package).into()),
+        };
+        if include_calls.unwrap_or_default() {
+            function.calls = function_callees(&compiled_module, &identifier);
+        }
+        function.complexity_hint = function_complexity_hint(&compiled_module, &identifier);
+        Ok(function)
+    }
+
+    async fn move_function_exists(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        function_name: String,
+    ) -> RpcResult<bool> {
+        self.check_rate_limit("sui_moveFunctionExists")?;
+        let module = get_move_module(self, package, module_name).await?;
+        let identifier = Identifier::new(function_name.as_str()).map_err(|e| anyhow!("{e}"))?;
+        Ok(module.exposed_functions.contains_key(&identifier))
+    }
+
+    async fn preload_packages(
+        &self,
+        packages: Vec<ObjectID>,
+    ) -> RpcResult<Vec<PackagePreloadResult>> {
+        self.check_rate_limit("sui_preloadPackages")?;
+        if packages.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(
+                "Requested {} packages, which exceeds the max limit of {QUERY_MAX_RESULT_LIMIT}",
+                packages.len()
+            )
+            .into());
+        }
+        let (cancel, _cancel_on_drop) = request_cancellation();
+        let mut results = Vec::with_capacity(packages.len());
+        for package in packages {
+            results.push(match preload_package(self, package, &cancel).await {
+                Ok(true) => PackagePreloadResult::Cached,
+                Ok(false) => PackagePreloadResult::AlreadyCached,
+                Err(e) => {
+                    warn!(?package, "Failed to preload package: {:?}", e);
+                    PackagePreloadResult::Error {
+                        error: e.to_string(),
+                    }
+                }
+            });
+            self.next_read_unit().await;
+        }
+        Ok(results)
+    }
+
+    async fn clear_module_cache(&self, package: Option<ObjectID>) -> RpcResult<usize> {
+        self.check_rate_limit("sui_clearModuleCache")?;
+        if !self.enable_admin_methods {
+            return Err(anyhow!(
+                "sui_clearModuleCache is an admin method, which is not enabled on this node"
+            )
+            .into());
+        }
+        let mut cache = self.normalized_module_cache.write().unwrap();
+        let before = cache.len();
+        match package {
+            Some(package) => cache.retain(|(id, _), _| *id != package),
+            None => cache.clear(),
+        }
+        Ok(before - cache.len())
     }
 
     async fn get_move_function_arg_types(
@@ -228,8 +1794,8 @@ impl ReadApiServer for ReadApi {
         module: String,
         function: String,
     ) -> RpcResult<Vec<MoveFunctionArgType>> {
+        self.check_rate_limit("sui_getMoveFunctionArgTypes")?;
         let object_read = self
-            .state
             .get_object_read(&package)
             .await
             .map_err(|e| anyhow!("{e}"))?;
@@ -280,13 +1846,17 @@ impl ReadApiServer for ReadApi {
         limit: Option<usize>,
         descending_order: Option<bool>,
     ) -> RpcResult<TransactionsPage> {
+        self.check_rate_limit("sui_getTransactions")?;
         let limit = cap_page_limit(limit);
         let descending = descending_order.unwrap_or_default();
+        Self::check_transaction_query(&query)?;
+        self.check_transaction_cursor(cursor)?;
 
         // Retrieve 1 extra item for next cursor
         let mut data = self
             .state
             .get_transactions(query, cursor, Some(limit + 1), descending)?;
+        check_page_cursor(cursor.as_ref(), data.first())?;
 
         // extract next cursor
         let next_cursor = data.get(limit).cloned();
@@ -294,11 +1864,133 @@ impl ReadApiServer for ReadApi {
         Ok(Page { data, next_cursor })
     }
 
+    async fn get_transactions_from_address_by_timestamp(
+        &self,
+        address: SuiAddress,
+        start_time: u64,
+        end_time: u64,
+        cursor: Option<TransactionTimestampCursor>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<TimestampedTransactionsPage> {
+        self.check_rate_limit("sui_getTransactionsFromAddressByTimestamp")?;
+        let limit = cap_page_limit(limit);
+        let mut data = self
+            .state
+            .get_transactions_from_addr_by_timestamp(
+                address,
+                start_time,
+                end_time,
+                cursor.map(|cursor| (cursor.timestamp_ms, cursor.digest)),
+                limit + 1,
+                descending_order.unwrap_or_default(),
+            )?
+            .into_iter()
+            .map(|(timestamp_ms, digest)| TransactionTimestampCursor {
+                timestamp_ms,
+                digest,
+            })
+            .collect::<Vec<_>>();
+        check_page_cursor(cursor.as_ref(), data.first())?;
+        let next_cursor = data.get(limit).copied();
+        data.truncate(limit);
+        Ok(Page {
+            data: data.into_iter().map(|entry| entry.digest).collect(),
+            next_cursor,
+        })
+    }
+
+    fn stream_transactions(
+        &self,
+        mut sink: SubscriptionSink,
+        query: TransactionQuery,
+        start_cursor: Option<TransactionDigest>,
+    ) -> SubscriptionResult {
+        if let Err(e) = self.check_rate_limit("sui_streamTransactions") {
+            return Ok(sink.reject(RpcError::from(e))?);
+        }
+        if let Err(e) = Self::check_transaction_query(&query) {
+            return Ok(sink.reject(e)?);
+        }
+
+        // Each batch is only read once the previous one has been handed to the sink, and the
+        // stream is dropped when the client goes away, which stops the scan.
+        let api = self.clone();
+        let stream = futures::stream::unfold(Some(start_cursor), move |cursor| {
+            let api = api.clone();
+            let query = query.clone();
+            async move {
+                let cursor = cursor?;
+                match api.get_transactions_batch(query, cursor).await {
+                    Ok((batch, _)) if batch.is_empty() => None,
+                    Ok((batch, next_cursor)) => Some((Ok(batch), next_cursor.map(Some))),
+                    Err(e) => Some((Err(e), None)),
+                }
+            }
+        });
+        spawn_subscription(sink, Box::pin(stream));
+        Ok(())
+    }
+
+    fn subscribe_address_objects(
+        &self,
+        mut sink: SubscriptionSink,
+        address: SuiAddress,
+    ) -> SubscriptionResult {
+        if let Err(e) = self.check_rate_limit("sui_subscribeAddressObjects") {
+            return Ok(sink.reject(RpcError::from(e))?);
+        }
+        let handler = match &self.state.ownership_handler {
+            Some(handler) => handler,
+            None => {
+                let e = Error::from(SuiError::IndexStoreNotAvailable);
+                return Ok(sink.reject(RpcError::from(e))?);
+            }
+        };
+
+        // Subscribe before reading the snapshot, so that no change committed in between is missed.
+        let changes = handler.subscribe(address).map(Ok::<_, Error>);
+        // The snapshot covers all owned objects, reading the owner index one page at a time as the
+        // client consumes it. It is only complete once the last page has been read.
+        let state = self.state.clone();
+        let snapshot = futures::stream::unfold(Some(ObjectID::ZERO), move |start| {
+            let state = state.clone();
+            async move {
+                let start = start?;
+                Some(match state.get_owner_objects_page(address, start) {
+                    Ok((objects, next)) => {
+                        let page = objects
+                            .into_iter()
+                            .map(|info| {
+                                Ok(ObjectOwnershipChange::Gained {
+                                    object_id: info.object_id,
+                                    type_: info.type_.to_string(),
+                                    tx_digest: None,
+                                })
+                            })
+                            .collect::<Vec<_>>();
+                        (page, next)
+                    }
+                    Err(e) => (vec![Err(Error::from(e))], None),
+                })
+            }
+        })
+        .flat_map(futures::stream::iter);
+        let stream = snapshot
+            .chain(futures::stream::once(async {
+                Ok(ObjectOwnershipChange::SnapshotComplete)
+            }))
+            .chain(changes);
+        spawn_subscription(sink, Box::pin(stream));
+        Ok(())
+    }
+
     async fn try_get_past_object(
         &self,
         object_id: ObjectID,
         version: SequenceNumber,
     ) -> RpcResult<GetPastObjectDataResponse> {
+        self.check_rate_limit("sui_tryGetPastObject")?;
         Ok(self
             .state
             .get_past_object_read(&object_id, version)
@@ -307,7 +1999,138 @@ impl ReadApiServer for ReadApi {
             .try_into()?)
     }
 
+    async fn multi_get_objects_at_checkpoint(
+        &self,
+        object_ids: Vec<ObjectID>,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<GetPastObjectDataResponse>> {
+        self.check_rate_limit("sui_multiGetObjectsAtCheckpoint")?;
+        if object_ids.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(
+                "Requested {} objects, which exceeds the max limit of {QUERY_MAX_RESULT_LIMIT}",
+                object_ids.len()
+            )
+            .into());
+        }
+        let latest = self
+            .state
+            .get_latest_checkpoint_sequence_number()
+            .map_err(|e| anyhow!("{e}"))?;
+        if checkpoint > latest {
+            return Err(anyhow!(
+                "Checkpoint {checkpoint} has not been executed yet, latest checkpoint is {latest}"
+            )
+            .into());
+        }
+        futures::future::try_join_all(
+            object_ids
+                .into_iter()
+                .map(|object_id| self.get_past_object_at_checkpoint(object_id, checkpoint)),
+        )
+        .await
+    }
+
+    async fn get_owned_object_refs_consistent(
+        &self,
+        owner: SuiAddress,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<SuiObjectRef>> {
+        self.check_rate_limit("sui_getOwnedObjectRefsConsistent")?;
+        if object_ids.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(
+                "Requested {} objects, which exceeds the max limit of {QUERY_MAX_RESULT_LIMIT}",
+                object_ids.len()
+            )
+            .into());
+        }
+        let checkpoint = self
+            .state
+            .get_latest_checkpoint_sequence_number()
+            .map_err(|e| anyhow!("{e}"))?;
+        let reads = futures::future::try_join_all(
+            object_ids
+                .into_iter()
+                .map(|object_id| self.get_past_object_at_checkpoint(object_id, checkpoint)),
+        )
+        .await?;
+        reads
+            .into_iter()
+            .map(|read| {
+                let object = read.into_object().map_err(Error::from)?;
+                if object.owner != Owner::AddressOwner(owner) {
+                    return Err(RpcError::Call(CallError::InvalidParams(anyhow!(
+                        "Object {} is not owned by {owner} as of checkpoint {checkpoint}",
+                        object.id()
+                    ))));
+                }
+                Ok(object.reference)
+            })
+            .collect()
+    }
+
+    async fn get_shared_object_info(&self, object_id: ObjectID) -> RpcResult<SharedObjectInfo> {
+        self.check_rate_limit("sui_getSharedObjectInfo")?;
+        let object = self
+            .state
+            .database
+            .get_object(&object_id)
+            .map_err(Error::from)?
+            .ok_or_else(|| anyhow!("Object {object_id} does not exist"))?;
+        let Owner::Shared { initial_shared_version } = object.owner else {
+            return Err(RpcError::Call(CallError::InvalidParams(anyhow!(
+                "Object {object_id} is not shared"
+            ))));
+        };
+        let type_ = object
+            .type_()
+            .ok_or_else(|| anyhow!("Object {object_id} is a package"))?;
+        Ok(SharedObjectInfo {
+            object_id,
+            initial_shared_version,
+            version: object.version(),
+            type_: type_.to_string(),
+        })
+    }
+
+    async fn get_object_storage_history(
+        &self,
+        object_id: ObjectID,
+        cursor: Option<SequenceNumber>,
+        limit: Option<usize>,
+    ) -> RpcResult<StorageHistoryPage> {
+        self.check_rate_limit("sui_getObjectStorageHistory")?;
+        let limit = cap_page_limit(limit);
+        let mut data = vec![];
+        let mut version = cursor.unwrap_or(SequenceNumber::MAX);
+        while data.len() <= limit {
+            let object = match self
+                .state
+                .database
+                .find_object_lt_or_eq_version(object_id, version)
+            {
+                Some(object) if object.id() == object_id => object,
+                _ => break,
+            };
+            data.push(StorageHistoryEntry {
+                version: object.version(),
+                previous_transaction: object.previous_transaction,
+                storage_rebate: object.storage_rebate,
+                object_size: object.object_size_for_gas_metering(),
+            });
+            if object.version() == SequenceNumber::MIN {
+                break;
+            }
+            version = SequenceNumber::from_u64(object.version().value() - 1);
+            self.next_read_unit().await;
+        }
+        check_page_cursor(cursor.as_ref(), data.first().map(|entry| &entry.version))?;
+        let next_cursor = data.get(limit).map(|entry| entry.version);
+        data.truncate(limit);
+        Ok(StorageHistoryPage { data, next_cursor })
+    }
+
     async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<CheckpointSequenceNumber> {
+        self.check_rate_limit("sui_getLatestCheckpointSequenceNumber")?;
         Ok(self
             .state
             .get_latest_checkpoint_sequence_number()
@@ -316,14 +2139,186 @@ impl ReadApiServer for ReadApi {
             })?)
     }
 
+    async fn get_checkpoint_tips(&self) -> RpcResult<CheckpointTips> {
+        self.check_rate_limit("sui_getCheckpointTips")?;
+        // Read the executed tip first, the synced tip is never behind it.
+        let highest_executed = self
+            .state
+            .get_latest_checkpoint_sequence_number()
+            .map_err(|e| {
+                anyhow!("Latest checkpoint sequence number was not found with error :{e}")
+            })?;
+        let highest_synced = self
+            .state
+            .get_latest_synced_checkpoint_sequence_number()
+            .map_err(|e| {
+                anyhow!("Latest synced checkpoint sequence number was not found with error :{e}")
+            })?;
+        Ok(CheckpointTips {
+            highest_synced,
+            highest_executed,
+        })
+    }
+
     async fn get_checkpoint(&self, id: CheckpointId) -> RpcResult<Checkpoint> {
+        self.check_rate_limit("sui_getCheckpoint")?;
         Ok(self.get_checkpoint_internal(id)?)
     }
 
+    async fn get_checkpoint_by_transaction(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Checkpoint> {
+        self.check_rate_limit("sui_getCheckpointByTransaction")?;
+        if !self
+            .state
+            .database
+            .is_tx_already_executed(&digest)
+            .map_err(Error::from)?
+        {
+            return Err(Error::SuiError(SuiError::TransactionNotFound { digest }).into());
+        }
+        let (_epoch, sequence_number) = self
+            .state
+            .database
+            .get_transaction_checkpoint(&digest)
+            .map_err(Error::from)?
+            .ok_or_else(|| {
+                Error::UnexpectedError(format!(
+                    "Transaction {digest:?} has not been included in a checkpoint yet"
+                ))
+            })?;
+        Ok(self.get_checkpoint_internal(CheckpointId::SequenceNumber(sequence_number))?)
+    }
+
+    async fn get_transaction_by_checkpoint_index(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        index: usize,
+    ) -> RpcResult<SuiTransactionResponse> {
+        self.check_rate_limit("sui_getTransactionByCheckpointIndex")?;
+        let contents = self
+            .state
+            .get_checkpoint_contents_by_sequence_number(checkpoint)
+            .map_err(|e| anyhow!("Checkpoint contents based on seq number: {checkpoint} were not found with error: {e}"))?;
+        let digest = contents
+            .iter()
+            .nth(index)
+            .ok_or_else(|| {
+                RpcError::Call(CallError::InvalidParams(anyhow!(
+                    "Index {index} is out of range for checkpoint {checkpoint} with {} transactions",
+                    contents.size()
+                )))
+            })?
+            .transaction;
+        self.get_transaction_internal(digest).await
+    }
+
+    async fn get_checkpoint_transactions_for_address(
+        &self,
+        id: CheckpointId,
+        address: SuiAddress,
+    ) -> RpcResult<Vec<SuiTransactionResponse>> {
+        self.check_rate_limit("sui_getCheckpointTransactionsForAddress")?;
+        let sequence_number = match id {
+            CheckpointId::SequenceNumber(seq) => seq,
+            CheckpointId::Digest(digest) => {
+                self.state
+                    .get_checkpoint_summary_by_digest(digest)?
+                    .sequence_number
+            }
+        };
+        let digests = self
+            .state
+            .get_checkpoint_transactions_for_address(sequence_number, address)?;
+        let mut responses = Vec::with_capacity(digests.len());
+        for digest in digests {
+            responses.push(self.get_transaction_internal(digest).await?);
+        }
+        Ok(responses)
+    }
+
+    async fn get_object_ownership_proof(&self, object_id: ObjectID) -> RpcResult<OwnershipProof> {
+        self.check_rate_limit("sui_getObjectOwnershipProof")?;
+        let object = self
+            .state
+            .database
+            .get_object(&object_id)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::ObjectNotFound {
+                object_id,
+                version: None,
+            }))?;
+        let digest = object.previous_transaction;
+        let effects = self
+            .state
+            .database
+            .get_executed_effects(&digest)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::TransactionNotFound { digest }))?;
+        let (_epoch, sequence_number) = self
+            .state
+            .database
+            .get_transaction_checkpoint(&digest)
+            .map_err(Error::from)?
+            .ok_or_else(|| {
+                Error::UnexpectedError(format!(
+                    "Transaction {digest:?} has not been included in a checkpoint yet"
+                ))
+            })?;
+        let checkpoint_summary = self
+            .state
+            .get_checkpoint_summary_by_sequence_number(sequence_number)?;
+        let checkpoint_contents = self
+            .state
+            .get_checkpoint_contents(checkpoint_summary.content_digest)?;
+        Ok(OwnershipProof {
+            object_ref: object.compute_object_reference().into(),
+            owner: object.owner,
+            transaction_digest: digest,
+            effects: bcs::to_bytes(&effects).map_err(Error::from)?,
+            checkpoint_summary,
+            checkpoint_contents,
+        })
+    }
+
+    async fn get_genesis_checkpoint(&self) -> RpcResult<Checkpoint> {
+        self.check_rate_limit("sui_getGenesisCheckpoint")?;
+        Ok(self.get_checkpoint_internal(CheckpointId::SequenceNumber(0))?)
+    }
+
+    async fn get_genesis_objects(&self) -> RpcResult<Vec<OwnedObjectRef>> {
+        self.check_rate_limit("sui_getGenesisObjects")?;
+        let contents = self
+            .state
+            .get_checkpoint_contents_by_sequence_number(0)
+            .map_err(|e| anyhow!("Genesis checkpoint was not found with error: {e}"))?;
+        let digest = contents
+            .iter()
+            .next()
+            .ok_or_else(|| anyhow!("Genesis checkpoint does not contain the genesis transaction"))?
+            .transaction;
+        let effects = self
+            .state
+            .database
+            .get_executed_effects(&digest)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::TransactionNotFound { digest }))?;
+        Ok(effects
+            .created
+            .into_iter()
+            .map(|(reference, owner)| OwnedObjectRef {
+                owner,
+                reference: reference.into(),
+            })
+            .collect())
+    }
+
     async fn get_checkpoint_summary_by_digest(
         &self,
         digest: CheckpointDigest,
     ) -> RpcResult<CheckpointSummary> {
+        self.check_rate_limit("sui_getCheckpointSummaryByDigest")?;
         Ok(self
             .state
             .get_checkpoint_summary_by_digest(digest)
@@ -334,10 +2329,52 @@ impl ReadApiServer for ReadApi {
             })?)
     }
 
+    async fn get_checkpoint_signatures(
+        &self,
+        id: CheckpointId,
+    ) -> RpcResult<CheckpointSignatureInfo> {
+        self.check_rate_limit("sui_getCheckpointSignatures")?;
+        let certified = match id {
+            CheckpointId::SequenceNumber(seq) => self
+                .state
+                .get_certified_checkpoint_by_sequence_number(seq)?,
+            CheckpointId::Digest(digest) => {
+                self.state.get_certified_checkpoint_by_digest(digest)?
+            }
+        };
+        let epoch = certified.auth_signature.epoch;
+        let committee = self
+            .state
+            .committee_store()
+            .get_committee(&epoch)
+            .map_err(Error::from)?
+            .ok_or_else(|| anyhow!("Committee of epoch {epoch} not found"))?;
+        let signers = certified
+            .auth_signature
+            .signers_map
+            .iter()
+            .map(|index| {
+                let name = committee.authority_by_index(index).ok_or_else(|| {
+                    anyhow!("Signer {index} is not in the committee of epoch {epoch}")
+                })?;
+                Ok((*name, committee.weight(name)))
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        Ok(CheckpointSignatureInfo {
+            epoch,
+            sequence_number: certified.summary.sequence_number,
+            signed_stake: signers.iter().map(|(_, stake)| stake).sum(),
+            signers,
+            quorum_threshold: committee.quorum_threshold(),
+            total_stake: committee.total_votes,
+        })
+    }
+
     async fn get_checkpoint_summary(
         &self,
         sequence_number: CheckpointSequenceNumber,
     ) -> RpcResult<CheckpointSummary> {
+        self.check_rate_limit("sui_getCheckpointSummary")?;
         Ok(self.state.get_checkpoint_summary_by_sequence_number(sequence_number)
             .map_err(|e| anyhow!("Checkpoint summary based on sequence number: {sequence_number} was not found with error :{e}"))?)
     }
@@ -346,31 +2383,189 @@ impl ReadApiServer for ReadApi {
         &self,
         digest: CheckpointContentsDigest,
     ) -> RpcResult<CheckpointContents> {
-        Ok(self.state.get_checkpoint_contents(digest).map_err(|e| {
+        self.check_rate_limit("sui_getCheckpointContentsByDigest")?;
+        let contents = self.state.get_checkpoint_contents(digest).map_err(|e| {
             anyhow!(
                 "Checkpoint contents based on digest: {digest:?} were not found with error: {e}"
             )
-        })?)
+        })?;
+        Ok(check_checkpoint_contents_size(
+            contents,
+            self.max_checkpoint_contents_size,
+        )?)
+    }
+
+    async fn verify_checkpoint_contents(
+        &self,
+        summary: CheckpointSummary,
+        contents: CheckpointContents,
+    ) -> RpcResult<bool> {
+        self.check_rate_limit("sui_verifyCheckpointContents")?;
+        Ok(contents.digest() == summary.content_digest)
+    }
+
+    async fn get_checkpoint_digests_paged(
+        &self,
+        id: CheckpointId,
+        cursor: Option<usize>,
+        limit: Option<usize>,
+    ) -> RpcResult<CheckpointDigestsPage> {
+        self.check_rate_limit("sui_getCheckpointDigestsPaged")?;
+        let limit = cap_page_limit(limit);
+        let contents = match id {
+            CheckpointId::SequenceNumber(seq) => {
+                self.state.get_checkpoint_contents_by_sequence_number(seq)?
+            }
+            CheckpointId::Digest(digest) => {
+                let summary = self.state.get_checkpoint_summary_by_digest(digest)?;
+                self.state.get_checkpoint_contents(summary.content_digest)?
+            }
+        };
+        if let Some(cursor) = cursor.filter(|cursor| *cursor >= contents.size()) {
+            return Err(cursor_not_found_error(&cursor));
+        }
+        let start = cursor.unwrap_or(0);
+        let mut data = contents
+            .iter()
+            .skip(start)
+            .take(limit + 1)
+            .cloned()
+            .collect::<Vec<_>>();
+        let next_cursor = if data.len() > limit {
+            Some(start + limit)
+        } else {
+            None
+        };
+        data.truncate(limit);
+        Ok(CheckpointDigestsPage { data, next_cursor })
+    }
+
+    async fn export_checkpoints_bcs(
+        &self,
+        start: CheckpointSequenceNumber,
+        end: CheckpointSequenceNumber,
+    ) -> RpcResult<Base64> {
+        self.check_rate_limit("sui_exportCheckpointsBcs")?;
+        if start >= end || end - start > MAX_CHECKPOINT_EXPORT_RANGE {
+            return Err(RpcError::Call(CallError::InvalidParams(anyhow!(
+                "Invalid checkpoint range [{start}, {end}), the range must be non-empty and span \
+                at most {MAX_CHECKPOINT_EXPORT_RANGE} checkpoints"
+            ))));
+        }
+        let mut checkpoints = Vec::new();
+        let mut size = 0;
+        for sequence_number in start..end {
+            let summary = self
+                .state
+                .get_checkpoint_summary_by_sequence_number(sequence_number)
+                .map_err(|e| anyhow!("Checkpoint summary based on sequence number: {sequence_number} was not found with error :{e}"))?;
+            let contents = self
+                .state
+                .get_checkpoint_contents_by_sequence_number(sequence_number)
+                .map_err(|e| anyhow!("Checkpoint contents based on seq number: {sequence_number} were not found with error: {e}"))?;
+            let checkpoint = (summary, contents);
+            size += bcs::serialized_size(&checkpoint).map_err(Error::from)?;
+            if size > MAX_CHECKPOINT_EXPORT_BYTES {
+                return Err(anyhow!(
+                    "Exported checkpoints exceed the max size of {MAX_CHECKPOINT_EXPORT_BYTES} \
+                    bytes at checkpoint {sequence_number}, request a smaller range"
+                )
+                .into());
+            }
+            checkpoints.push(checkpoint);
+            self.next_read_unit().await;
+        }
+        Ok(Base64::from_bytes(
+            &bcs::to_bytes(&checkpoints).map_err(Error::from)?,
+        ))
     }
 
     async fn get_checkpoint_contents(
         &self,
         sequence_number: CheckpointSequenceNumber,
     ) -> RpcResult<CheckpointContents> {
-        Ok(self
+        self.check_rate_limit("sui_getCheckpointContents")?;
+        let contents = self
             .state
             .get_checkpoint_contents_by_sequence_number(sequence_number)
-            .map_err(|e| anyhow!("Checkpoint contents based on seq number: {sequence_number} were not found with error: {e}"))?)
+            .map_err(|e| anyhow!("Checkpoint contents based on seq number: {sequence_number} were not found with error: {e}"))?;
+        Ok(check_checkpoint_contents_size(
+            contents,
+            self.max_checkpoint_contents_size,
+        )?)
     }
 
     async fn get_raw_object(&self, object_id: ObjectID) -> RpcResult<GetRawObjectDataResponse> {
+        self.check_rate_limit("sui_getRawObject")?;
         Ok(self
-            .state
             .get_object_read(&object_id)
             .await
             .map_err(|e| anyhow!("{e}"))?
             .try_into()?)
     }
+
+    async fn get_object_move_bcs(&self, object_id: ObjectID) -> RpcResult<Base64> {
+        self.check_rate_limit("sui_getObjectMoveBcs")?;
+        let object = self
+            .state
+            .database
+            .get_object(&object_id)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::ObjectNotFound {
+                object_id,
+                version: None,
+            }))?;
+        match &object.data {
+            Data::Move(move_object) => Ok(Base64::from_bytes(move_object.contents())),
+            Data::Package(_) => {
+                Err(anyhow!("Object [{object_id}] is a package, which has no Move value").into())
+            }
+        }
+    }
+
+    async fn check_type_compatibility(
+        &self,
+        old: String,
+        new: String,
+    ) -> RpcResult<TypeCompatibility> {
+        self.check_rate_limit("sui_checkTypeCompatibility")?;
+        let old_fields = self.get_instantiated_struct_fields(&old).await?;
+        let new_fields = self.get_instantiated_struct_fields(&new).await?;
+        Ok(TypeCompatibility::check(
+            &old_fields,
+            &new_fields,
+            |old, new| self.same_layout(old, new),
+        )?)
+    }
+
+    async fn get_transaction_dependencies(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Vec<TransactionDigest>> {
+        self.check_rate_limit("sui_getTransactionDependencies")?;
+        let effects = self
+            .state
+            .database
+            .get_executed_effects(&digest)
+            .map_err(Error::from)?
+            .ok_or(Error::SuiError(SuiError::TransactionNotFound { digest }))?;
+        Ok(effects.dependencies)
+    }
+}
+
+/// Reject checkpoint contents that are too large to be returned in a single response.
+pub(crate) fn check_checkpoint_contents_size(
+    contents: CheckpointContents,
+    max_size: usize,
+) -> Result<CheckpointContents, Error> {
+    if contents.size() > max_size {
+        return Err(Error::UnexpectedError(format!(
+            "Checkpoint contents contain {} transactions, which exceeds the max of {max_size}, \
+            query the transactions of the checkpoint with a paginated request instead",
+            contents.size()
+        )));
+    }
+    Ok(contents)
 }
 
 impl SuiRpcModule for ReadApi {
@@ -388,11 +2583,361 @@ pub async fn get_move_module(
     package: ObjectID,
     module_name: String,
 ) -> RpcResult<NormalizedModule> {
-    let normalized = get_move_modules_by_package(fullnode_api, package).await?;
-    Ok(match normalized.get(&module_name) {
-        Some(module) => Ok(module.clone()),
-        None => Err(anyhow!("No module found with module name {}", module_name)),
-    }?)
+    if let Some(module) = fullnode_api
+        .normalized_module_cache
+        .read()
+        .unwrap()
+        .get(&(package, module_name.clone()))
+    {
+        return Ok(module.clone());
+    }
+
+    let object_read = fullnode_api
+        .state
+        .get_object_read(&package)
+        .await
+        .map_err(|e| anyhow!("{e}"))?;
+
+    // Only normalize the requested module rather than the whole package.
+    let module = match object_read {
+        ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
+            Data::Package(p) => match p.serialized_module_map().get(&module_name) {
+                Some(bytes) => normalize_modules(std::iter::once(bytes))
+                    .map_err(|e| anyhow!("{e}"))?
+                    .remove(&module_name)
+                    .ok_or_else(|| anyhow!("No module found with module name {}", module_name)),
+                None => Err(anyhow!("No module found with module name {}", module_name)),
+            },
+            _ => Err(anyhow!("Object is not a package with ID {}", package)),
+        },
+        _ => Err(anyhow!("Package object does not exist with ID {}", package)),
+    }?;
+
+    fullnode_api
+        .normalized_module_cache
+        .write()
+        .unwrap()
+        .insert((package, module_name), module.clone());
+    Ok(module)
+}
+
+/// The functions called directly from the body of `function_name` in `module`, in the order of
+/// their first call. Callees in other packages are resolved through the module's handle tables,
+/// so no other package needs to be loaded.
+fn function_callees(
+    module: &CompiledModule,
+    function_name: &IdentStr,
+) -> Option<Vec<SuiMoveFunctionRef>> {
+    let definition = module.function_defs().iter().find(|definition| {
+        module.identifier_at(module.function_handle_at(definition.function).name) == function_name
+    })?;
+    let mut callees: Vec<SuiMoveFunctionRef> = vec![];
+    for instruction in definition.code.iter().flat_map(|code| code.code.iter()) {
+        let handle_index = match instruction {
+            Bytecode::Call(index) => *index,
+            Bytecode::CallGeneric(index) => module.function_instantiation_at(*index).handle,
+            _ => continue,
+        };
+        let handle = module.function_handle_at(handle_index);
+        let module_id = module.module_id_for_handle(module.module_handle_at(handle.module));
+        let callee = SuiMoveFunctionRef {
+            address: module_id.address().to_hex_literal(),
+            module: module_id.name().to_string(),
+            function: module.identifier_at(handle.name).to_string(),
+        };
+        if !callees.contains(&callee) {
+            callees.push(callee);
+        }
+    }
+    Some(callees)
+}
+
+/// Estimate the cost of calling the function named `function_name` from its body, or return
+/// None for a native function, which has no body to inspect. A backward branch is taken to be a
+/// loop.
+fn function_complexity_hint(
+    module: &CompiledModule,
+    function_name: &IdentStr,
+) -> Option<SuiMoveFunctionComplexity> {
+    let definition = module.function_defs().iter().find(|definition| {
+        module.identifier_at(module.function_handle_at(definition.function).name) == function_name
+    })?;
+    let code = &definition.code.as_ref()?.code;
+    let has_loop = code
+        .iter()
+        .enumerate()
+        .any(|(offset, instruction)| match instruction {
+            Bytecode::Branch(target) | Bytecode::BrTrue(target) | Bytecode::BrFalse(target) => {
+                *target as usize <= offset
+            }
+            _ => false,
+        });
+    let has_calls = code
+        .iter()
+        .any(|instruction| matches!(instruction, Bytecode::Call(_) | Bytecode::CallGeneric(_)));
+    let instructions = code.len();
+    Some(
+        if instructions > MEDIUM_COMPLEXITY_MAX_INSTRUCTIONS
+            || (has_loop && (has_calls || instructions > LOW_COMPLEXITY_MAX_INSTRUCTIONS))
+        {
+            SuiMoveFunctionComplexity::High
+        } else if has_loop || instructions > LOW_COMPLEXITY_MAX_INSTRUCTIONS {
+            SuiMoveFunctionComplexity::Medium
+        } else {
+            SuiMoveFunctionComplexity::Low
+        },
+    )
+}
+
+/// The constant pool of `module`, in pool order.
+/// Whether values of both struct layouts have the same BCS encoding, regardless of the names of
+/// their fields and of the structs they are laid out from.
+fn same_struct_layout(old: &MoveStructLayout, new: &MoveStructLayout) -> bool {
+    let old = old.fields();
+    let new = new.fields();
+    old.len() == new.len()
+        && old
+            .iter()
+            .zip(new.iter())
+            .all(|(old, new)| same_type_layout(old, new))
+}
+
+fn same_type_layout(old: &MoveTypeLayout, new: &MoveTypeLayout) -> bool {
+    match (old, new) {
+        (MoveTypeLayout::Struct(old), MoveTypeLayout::Struct(new)) => same_struct_layout(old, new),
+        (MoveTypeLayout::Vector(old), MoveTypeLayout::Vector(new)) => same_type_layout(old, new),
+        _ => old == new,
+    }
+}
+
+fn module_constants(module: &CompiledModule) -> Vec<SuiMoveNormalizedConstant> {
+    module
+        .constant_pool()
+        .iter()
+        .map(|constant| SuiMoveNormalizedConstant {
+            type_: Type::new(module, &constant.type_).into(),
+            value: constant.deserialize_constant().map(SuiMoveValue::from),
+            bytes: constant.data.clone(),
+        })
+        .collect()
+}
+
+/// Collect the object IDs held in `value` as an `ID`, or as the `UID` of a wrapped object,
+/// along with the path of the field holding them.
+fn collect_nested_object_ids(value: &MoveValue, path: String, refs: &mut Vec<(String, ObjectID)>) {
+    match value {
+        MoveValue::Struct(MoveStruct::WithTypes { type_, fields }) => {
+            let id = if *type_ == ID::type_() {
+                fields.first().map(|(_, value)| value)
+            } else if *type_ == UID::type_() {
+                match fields.first() {
+                    Some((_, MoveValue::Struct(MoveStruct::WithTypes { fields, .. }))) => {
+                        fields.first().map(|(_, value)| value)
+                    }
+                    _ => None,
+                }
+            } else {
+                for (name, value) in fields {
+                    collect_nested_object_ids(value, format!("{path}.{name}"), refs);
+                }
+                return;
+            };
+            if let Some(MoveValue::Address(address)) = id {
+                refs.push((path, ObjectID::from(*address)));
+            }
+        }
+        MoveValue::Vector(values) => {
+            for (i, value) in values.iter().enumerate() {
+                collect_nested_object_ids(value, format!("{path}[{i}]"), refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn nested_object_resolution(version: SequenceNumber, object: &Object) -> SuiNestedObjectResolution {
+    let (type_, name) = match object.type_() {
+        Some(type_) => (type_.to_string(), type_.name.to_string()),
+        None => ("package".to_string(), "Package".to_string()),
+    };
+    let owner = match object.owner {
+        Owner::AddressOwner(address) => format!("owned by {address}"),
+        Owner::ObjectOwner(address) => format!("owned by object {address}"),
+        Owner::Shared { .. } => "shared".to_string(),
+        Owner::Immutable => "immutable".to_string(),
+    };
+    SuiNestedObjectResolution::Resolved {
+        type_,
+        version,
+        summary: format!("{name} {owner}, version {version}"),
+    }
+}
+
+/// The dynamic field names that `value` given by a client can stand for, one for each Move type
+/// the value can be read as, holding the value in its indexed form. Integers wider than u32 and
+/// addresses are indexed as strings, so a string is also read as an integer and an address. The
+/// element type of an empty vector cannot be told, so it has no names.
+fn dynamic_field_name_candidates(value: &Value) -> Vec<DynamicFieldName> {
+    let name = |type_, value| DynamicFieldName { type_, value };
+    match value {
+        Value::Bool(_) => vec![name(TypeTag::Bool, value.clone())],
+        Value::Number(n) => n
+            .as_u64()
+            .map(|n| integer_name_candidates(&n.to_string()))
+            .unwrap_or_default(),
+        Value::String(s) => {
+            let mut names = integer_name_candidates(s);
+            if let Ok(id) = ObjectID::from_str(s) {
+                let address = SuiMoveValue::Address(SuiAddress::from(id)).to_json_value();
+                names.push(name(TypeTag::Address, address.clone()));
+                names.push(name(TypeTag::Struct(Box::new(ID::type_())), address));
+            }
+            for (module, struct_name) in [
+                (STD_UTF8_MODULE_NAME, STD_UTF8_STRUCT_NAME),
+                (STD_ASCII_MODULE_NAME, STD_ASCII_STRUCT_NAME),
+            ] {
+                let type_ = StructTag {
+                    address: MOVE_STDLIB_ADDRESS,
+                    module: module.to_owned(),
+                    name: struct_name.to_owned(),
+                    type_params: vec![],
+                };
+                names.push(name(TypeTag::Struct(Box::new(type_)), value.clone()));
+            }
+            names
+        }
+        Value::Array(values) => {
+            let elements = values
+                .iter()
+                .map(dynamic_field_name_candidates)
+                .collect::<Vec<_>>();
+            let Some((first, rest)) = elements.split_first() else {
+                return vec![];
+            };
+            // Only element types every element can be read as
+            first
+                .iter()
+                .filter_map(|element| {
+                    let mut indexed = vec![element.value.clone()];
+                    for names in rest {
+                        let same_type = names.iter().find(|name| name.type_ == element.type_)?;
+                        indexed.push(same_type.value.clone());
+                    }
+                    Some(name(
+                        TypeTag::Vector(Box::new(element.type_.clone())),
+                        Value::Array(indexed),
+                    ))
+                })
+                .collect()
+        }
+        Value::Null | Value::Object(_) => vec![],
+    }
+}
+
+/// The integer dynamic field names for the decimal `digits`, one for each integer type that can
+/// hold them.
+fn integer_name_candidates(digits: &str) -> Vec<DynamicFieldName> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return vec![];
+    }
+    let digits = match digits.trim_start_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+    let n = digits.parse::<u128>().ok();
+    let mut names = vec![];
+    for (type_, max) in [
+        (TypeTag::U8, u8::MAX as u128),
+        (TypeTag::U16, u16::MAX as u128),
+        (TypeTag::U32, u32::MAX as u128),
+    ] {
+        if let Some(n) = n.filter(|n| *n <= max) {
+            names.push(DynamicFieldName {
+                type_,
+                value: Value::from(n as u32),
+            });
+        }
+    }
+    for (type_, fits) in [
+        (TypeTag::U64, matches!(n, Some(n) if n <= u64::MAX as u128)),
+        (TypeTag::U128, n.is_some()),
+        // A u256 has at most 78 decimal digits
+        (TypeTag::U256, digits.len() <= 78),
+    ] {
+        if fits {
+            names.push(DynamicFieldName {
+                type_,
+                value: Value::String(digits.to_string()),
+            });
+        }
+    }
+    names
+}
+
+/// A token for the blocking work of a request, cancelled once the returned guard is dropped.
+/// Handlers hold the guard, so that dropping the request future, e.g. because its client went
+/// away, also stops the work it handed to blocking threads. Async loops need no token, as they
+/// stop with the future that polls them.
+pub(crate) fn request_cancellation() -> (CancellationToken, DropGuard) {
+    let cancel = CancellationToken::new();
+    (cancel.clone(), cancel.drop_guard())
+}
+
+/// Normalize and cache all modules of `package` that are not cached yet. Return false if every
+/// module was already cached.
+pub(crate) async fn preload_package(
+    fullnode_api: &ReadApi,
+    package: ObjectID,
+    cancel: &CancellationToken,
+) -> RpcResult<bool> {
+    let object_read = fullnode_api
+        .state
+        .get_object_read(&package)
+        .await
+        .map_err(|e| anyhow!("{e}"))?;
+    let package_data = match object_read {
+        ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
+            Data::Package(p) => p,
+            _ => return Err(anyhow!("Object is not a package with ID {}", package).into()),
+        },
+        _ => return Err(anyhow!("Package object does not exist with ID {}", package).into()),
+    };
+
+    let missing = {
+        let cache = fullnode_api.normalized_module_cache.read().unwrap();
+        package_data
+            .serialized_module_map()
+            .iter()
+            .filter(|(name, _)| !cache.contains_key(&(package, (*name).clone())))
+            .map(|(_, bytes)| bytes.clone())
+            .collect::<Vec<_>>()
+    };
+    if missing.is_empty() {
+        return Ok(false);
+    }
+
+    // Normalization is CPU bound, so it runs on a blocking thread, which keeps going after the
+    // request future is dropped unless it checks the token between modules.
+    let cache = fullnode_api.normalized_module_cache.clone();
+    let completed_read_units = fullnode_api.completed_read_units.clone();
+    let cancel = cancel.clone();
+    tokio::task::spawn_blocking(move || {
+        for bytes in missing {
+            if cancel.is_cancelled() {
+                return Err(anyhow!("Request was cancelled"));
+            }
+            let modules = normalize_modules(std::iter::once(&bytes)).map_err(|e| anyhow!("{e}"))?;
+            let mut cache = cache.write().unwrap();
+            for (name, module) in modules {
+                cache.insert((package, name), module);
+            }
+            completed_read_units.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    })
+    .await
+    .map_err(|e| anyhow!("{e}"))??;
+    Ok(true)
 }
 
 pub async fn get_move_modules_by_package(
@@ -419,16 +2964,20 @@ pub async fn get_move_modules_by_package(
 pub fn get_transaction_data_and_digest(
     tx_bytes: Base64,
 ) -> RpcResult<(TransactionData, TransactionDigest)> {
+    let intent_msg = get_transaction_intent_message(tx_bytes)?;
+    let txn_digest = TransactionDigest::new(sha3_hash(&intent_msg.value));
+    Ok((intent_msg.value, txn_digest))
+}
+
+fn get_transaction_intent_message(tx_bytes: Base64) -> RpcResult<IntentMessage<TransactionData>> {
     let tx_data =
         bcs::from_bytes(&tx_bytes.to_vec().map_err(|e| anyhow!(e))?).map_err(|e| anyhow!(e))?;
-    let intent_msg = IntentMessage::new(
+    Ok(IntentMessage::new(
         Intent {
             version: IntentVersion::V0,
             scope: IntentScope::TransactionData,
             app_id: AppId::Sui,
         },
         tx_data,
-    );
-    let txn_digest = TransactionDigest::new(sha3_hash(&intent_msg.value));
-    Ok((intent_msg.value, txn_digest))
+    ))
 }
@@ -120,6 +120,8 @@ impl WriteApiServer for TransactionExecutionApi {
                     timestamp_ms: None,
                     confirmed_local_execution: Some(is_executed_locally),
                     checkpoint: None,
+                    gas_object: None,
+                    execution_digests: None,
                 })
             }
         }
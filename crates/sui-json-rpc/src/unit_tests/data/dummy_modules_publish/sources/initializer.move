@@ -0,0 +1,15 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// A module whose initializer is listed among its exposed functions, used to test that the
+/// initializer is flagged in normalized function responses.
+module examples::initializer {
+    use sui::tx_context::TxContext;
+
+    /// Runs once when the module is published. It is an entry function so that it is exposed.
+    entry fun init(_ctx: &mut TxContext) {}
+
+    public fun version(): u64 {
+        1
+    }
+}
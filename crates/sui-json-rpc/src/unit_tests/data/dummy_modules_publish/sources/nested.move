@@ -0,0 +1,44 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// An object with structs, vectors and options nested in each other, used to test the truncation
+/// of deeply nested object content.
+module examples::nested {
+    use std::option::{Self, Option};
+    use sui::object::{Self, UID};
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    struct Leaf has store {
+        value: u64,
+    }
+
+    struct Branch has store {
+        leaf: Leaf,
+        leaves: vector<Leaf>,
+    }
+
+    struct Tree has key {
+        id: UID,
+        value: u64,
+        branch: Branch,
+        maybe_branch: Option<Branch>,
+    }
+
+    public entry fun create(ctx: &mut TxContext) {
+        let tree = Tree {
+            id: object::new(ctx),
+            value: 0,
+            branch: new_branch(),
+            maybe_branch: option::some(new_branch()),
+        };
+        transfer::transfer(tree, tx_context::sender(ctx));
+    }
+
+    fun new_branch(): Branch {
+        Branch {
+            leaf: Leaf { value: 1 },
+            leaves: vector[Leaf { value: 2 }, Leaf { value: 3 }],
+        }
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// An object referring to other objects by ID, used to test the resolution of nested object
+/// references.
+module examples::object_refs {
+    use std::vector;
+    use sui::object::{Self, ID, UID};
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    struct Pointers has key {
+        id: UID,
+        refs: vector<ID>,
+    }
+
+    public entry fun create(refs: vector<address>, ctx: &mut TxContext) {
+        let ids = vector::empty();
+        while (!vector::is_empty(&refs)) {
+            vector::push_back(&mut ids, object::id_from_address(vector::remove(&mut refs, 0)));
+        };
+        transfer::transfer(Pointers { id: object::new(ctx), refs: ids }, tx_context::sender(ctx));
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// A table keyed by `u64`, used to test dynamic field lookups by name value.
+module examples::u64_table {
+    use sui::table;
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    /// Create a table mapping 1, 2 and 3 to ten times their value, owned by the sender.
+    public entry fun create(ctx: &mut TxContext) {
+        let table = table::new<u64, u64>(ctx);
+        table::add(&mut table, 1, 10);
+        table::add(&mut table, 2, 20);
+        table::add(&mut table, 3, 30);
+        transfer::transfer(table, tx_context::sender(ctx));
+    }
+}
@@ -2,10 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::api::{
-    CoinReadApiClient, GovernanceReadApiClient, ReadApiClient, ThresholdBlsApiClient,
-    TransactionBuilderClient, WriteApiClient,
+    CoinReadApiClient, EventReadApiClient, GovernanceReadApiClient, ReadApiClient, ReadApiServer,
+    ThresholdBlsApiClient, TransactionBuilderClient, WriteApiClient, MAX_CHECKPOINT_EXPORT_RANGE,
+    MAX_TRANSACTION_QUERY_ADDRESSES, QUERY_MAX_RESULT_LIMIT,
 };
+use crate::error::{RATE_LIMITED_CODE, UNSUPPORTED_CODE};
+use crate::rate_limiter::RateLimiter;
+use crate::read_api::{
+    check_checkpoint_contents_size, get_transaction_data_and_digest, preload_package,
+    request_cancellation, ReadApi,
+};
+use fastcrypto::encoding::Base64;
+use jsonrpsee::core::Error as RpcError;
+use jsonrpsee::types::error::CallError;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
+use std::sync::Arc;
 
 #[cfg(not(msim))]
 use std::str::FromStr;
@@ -13,29 +25,52 @@ use sui_config::SUI_KEYSTORE_FILENAME;
 use sui_framework_build::compiled_package::BuildConfig;
 use sui_json::SuiJsonValue;
 
-use sui_json_rpc_types::SuiObjectInfo;
 use sui_json_rpc_types::{
-    Balance, CoinPage, GetObjectDataResponse, SuiCoinMetadata, SuiEvent, SuiExecutionStatus,
-    SuiTBlsSignObjectCommitmentType, SuiTransactionResponse, TransactionBytes,
+    Balance, CheckpointId, Coin, CoinPage, CoinSortOrder, FieldAbi, FunctionAbi,
+    GetObjectDataResponse, ModuleAbi, ObjectOwnershipChange, PackagePreloadResult, StructAbi,
+    SuiCoinMetadata, SuiEvent, SuiExecutionStatus, SuiMoveAbility, SuiMoveCallObjectArgument,
+    SuiMoveFunctionComplexity, SuiMoveNormalizedStructResult, SuiMoveNormalizedType, SuiMoveStruct,
+    SuiMoveValue, SuiMoveVisibility, SuiNestedObjectResolution, SuiObject, SuiObjectResponse,
+    SuiObjectTypeAbilities, SuiParsedData, SuiPastObjectRead, SuiSystemObjectKind,
+    SuiTBlsSignObjectCommitmentType, SuiTransactionKind, SuiTransactionResponse,
+    SuiTransactionSignature, SuiTransactionStatus, TransactionBytes,
 };
+use sui_json_rpc_types::{SuiObjectDataOptions, SuiObjectInfo, SuiTransactionResponseOptions};
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
 use sui_types::balance::Supply;
+use sui_types::base_types::ExecutionDigests;
 use sui_types::base_types::ObjectID;
+use sui_types::base_types::SequenceNumber;
+use sui_types::base_types::SuiAddress;
 use sui_types::base_types::TransactionDigest;
+use sui_types::base_types::TransactionEffectsDigest;
+use sui_types::base_types::SUI_ADDRESS_LENGTH;
 use sui_types::coin::{TreasuryCap, COIN_MODULE_NAME, LOCKED_COIN_MODULE_NAME};
-use sui_types::gas_coin::GAS;
-use sui_types::messages::ExecuteTransactionRequestType;
-use sui_types::object::Owner;
+use sui_types::dynamic_field::DynamicFieldName;
+use sui_types::error::TRANSACTION_NOT_FOUND_MSG_PREFIX;
+use sui_types::event::EventID;
+use sui_types::gas_coin::{GasCoin, GAS};
+use sui_types::message_envelope::Message;
+use sui_types::messages::{ExecuteTransactionRequestType, TransactionEffects};
+use sui_types::messages_checkpoint::{
+    CheckpointContents, CheckpointSequenceNumber, CheckpointSummary,
+};
+use sui_types::object::{Data, Object, Owner};
 use sui_types::query::{EventQuery, TransactionQuery};
 use sui_types::sui_system_state::ValidatorMetadata;
 use sui_types::utils::to_sender_signed_transaction;
-use sui_types::{parse_sui_struct_tag, parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS};
-use test_utils::network::TestClusterBuilder;
+use sui_types::{
+    parse_sui_struct_tag, parse_sui_type_tag, MOVE_STDLIB_ADDRESS, SUI_CLOCK_OBJECT_ID,
+    SUI_FRAMEWORK_ADDRESS, SUI_SYSTEM_STATE_OBJECT_ID,
+};
+use test_utils::network::{TestCluster, TestClusterBuilder};
+use test_utils::transaction::transfer_coin;
 
+use futures::StreamExt;
 use sui_macros::sim_test;
 use sui_types::governance::{DelegatedStake, DelegationStatus};
 
-use tokio::time::{sleep, Duration};
+use tokio::time::{sleep, timeout, Duration};
 
 #[sim_test]
 async fn test_get_objects() -> Result<(), anyhow::Error> {
@@ -340,665 +375,3913 @@ async fn test_get_object_info() -> Result<(), anyhow::Error> {
 }
 
 #[sim_test]
-async fn test_get_coins() -> Result<(), anyhow::Error> {
-    let cluster = TestClusterBuilder::new().build().await?;
+async fn test_get_objects_owned_by_address_version_and_digest() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    // Mutate some objects so that they are no longer at their genesis version.
+    let (_, sender, _, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
     let http_client = cluster.rpc_client();
-    let address = cluster.accounts.first().unwrap();
-
-    let result: CoinPage = http_client.get_coins(*address, None, None, None).await?;
-    assert_eq!(5, result.data.len());
-    assert_eq!(None, result.next_cursor);
 
-    let result: CoinPage = http_client
-        .get_coins(*address, Some("0x2::sui::TestCoin".into()), None, None)
-        .await?;
-    assert_eq!(0, result.data.len());
+    // Version and digest come straight from the owner index, and must match the live objects.
+    let objects = http_client.get_objects_owned_by_address(sender).await?;
+    assert!(!objects.is_empty());
+    for info in objects {
+        let object = http_client
+            .get_object(info.object_id)
+            .await?
+            .into_object()?;
+        assert_eq!(object.reference.version, info.version);
+        assert_eq!(object.reference.digest, info.digest);
+    }
+    Ok(())
+}
 
-    let result: CoinPage = http_client
-        .get_coins(*address, Some("0x2::sui::SUI".into()), None, None)
-        .await?;
-    assert_eq!(5, result.data.len());
-    assert_eq!(None, result.next_cursor);
+#[sim_test]
+async fn test_multi_get_objects_with_duplicates() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let first = objects[0].object_id;
+    let second = objects[1].object_id;
+    let missing = ObjectID::random();
+
+    let object_ids = vec![first, second, first, missing, second, first];
+    let responses = http_client.multi_get_objects(object_ids.clone()).await?;
+    assert_eq!(object_ids.len(), responses.len());
+    for (object_id, response) in object_ids.iter().zip(responses) {
+        match response {
+            GetObjectDataResponse::Exists(object) => assert_eq!(*object_id, object.id()),
+            GetObjectDataResponse::NotExists(id) => {
+                assert_eq!(missing, *object_id);
+                assert_eq!(missing, id);
+            }
+            GetObjectDataResponse::Deleted(_) => panic!("Unexpected deleted object"),
+        }
+    }
+    Ok(())
+}
 
-    // Test paging
-    let result: CoinPage = http_client
-        .get_coins(*address, Some("0x2::sui::SUI".into()), None, Some(3))
-        .await?;
-    assert_eq!(3, result.data.len());
-    assert!(result.next_cursor.is_some());
+#[sim_test]
+async fn test_get_object_with_bcs() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let object_id = objects.first().unwrap().object_id;
 
-    let result: CoinPage = http_client
-        .get_coins(
-            *address,
-            Some("0x2::sui::SUI".into()),
-            result.next_cursor,
-            Some(3),
-        )
+    let response = http_client
+        .get_object_with_options(object_id, Some(SuiObjectDataOptions::bcs()))
         .await?;
-    assert_eq!(2, result.data.len());
-    assert!(result.next_cursor.is_none());
+    let raw = http_client.get_raw_object(object_id).await?;
+    assert_eq!(Some(raw.into_object()?.data), response.bcs);
+    assert_eq!(object_id, response.details.object()?.id());
 
+    let response = http_client.get_object_with_options(object_id, None).await?;
+    assert!(response.bcs.is_none());
     Ok(())
 }
 
 #[sim_test]
-async fn test_get_balance() -> Result<(), anyhow::Error> {
+async fn test_get_object_with_content_max_depth() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let object_id = objects.first().unwrap().object_id;
+
+    let fields = |response: SuiObjectResponse| match response.details {
+        GetObjectDataResponse::Exists(SuiObject {
+            data: SuiParsedData::MoveObject(object),
+            ..
+        }) => object.fields,
+        other => panic!("Unexpected object response: {:?}", other),
+    };
+    let with_max_depth = |depth| SuiObjectDataOptions {
+        content_max_depth: Some(depth),
+        ..Default::default()
+    };
+
+    let full = fields(http_client.get_object_with_options(object_id, None).await?);
+
+    // The fields of a coin are not nested, so they are unaffected at depth 1
+    let shallow = fields(
+        http_client
+            .get_object_with_options(object_id, Some(with_max_depth(1)))
+            .await?,
+    );
+    assert_eq!(full, shallow);
 
-    let result: Balance = http_client.get_balance(*address, None).await?;
-    assert_eq!("0x2::sui::SUI", result.coin_type);
-    assert_eq!(500000000000000, result.total_balance);
-    assert_eq!(5, result.coin_object_count);
-
+    let truncated = fields(
+        http_client
+            .get_object_with_options(object_id, Some(with_max_depth(0)))
+            .await?,
+    );
+    match truncated {
+        SuiMoveStruct::WithFields(fields) => {
+            assert_eq!(
+                vec!["balance", "id"],
+                fields.keys().map(String::as_str).collect::<Vec<_>>()
+            );
+            assert!(fields
+                .values()
+                .all(|value| matches!(value, SuiMoveValue::Truncated { truncated: true })));
+        }
+        other => panic!("Unexpected fields: {:?}", other),
+    }
     Ok(())
 }
 
 #[sim_test]
-async fn test_get_metadata() -> Result<(), anyhow::Error> {
+async fn test_get_object_with_nested_content_max_depth() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
-
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
+    let gas = http_client.get_objects_owned_by_address(*address).await?[0].object_id;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
-    let gas = objects.first().unwrap();
-
-    // Publish test coin package
-    let compiled_modules = BuildConfig::default()
+    let compiled_modules = BuildConfig::new_for_testing()
         .build(Path::new("src/unit_tests/data/dummy_modules_publish").to_path_buf())?
         .get_package_base64(/* with_unpublished_deps */ false);
-
     let transaction_bytes: TransactionBytes = http_client
-        .publish(*address, compiled_modules, Some(gas.object_id), 10000)
+        .publish(*address, compiled_modules, Some(gas), 10000)
         .await?;
-
-    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
-    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
     let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
     let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
-
-    let tx_response = http_client
+    let effects = http_client
         .submit_transaction(
             tx_bytes,
             signatures,
             ExecuteTransactionRequestType::WaitForLocalExecution,
         )
-        .await?;
-
-    let SuiTransactionResponse { effects, .. } = tx_response;
-
+        .await?
+        .effects;
     let package_id = effects
         .events
         .iter()
         .find_map(|e| {
             if let SuiEvent::Publish { package_id, .. } = e {
-                Some(package_id)
+                Some(*package_id)
             } else {
                 None
             }
         })
         .unwrap();
 
-    let result: SuiCoinMetadata = http_client
-        .get_coin_metadata(format!("{package_id}::trusted_coin::TRUSTED_COIN"))
+    let transaction_bytes: TransactionBytes = http_client
+        .move_call(
+            *address,
+            package_id,
+            "nested".to_string(),
+            "create".to_string(),
+            vec![],
+            vec![],
+            Some(gas),
+            10_000,
+            None,
+        )
+        .await?;
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let effects = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?
+        .effects;
+    assert_eq!(SuiExecutionStatus::Success, effects.status);
+    let tree_id = effects.created.first().unwrap().reference.object_id;
+
+    let tree = |max_depth| async move {
+        let options = SuiObjectDataOptions {
+            content_max_depth: max_depth,
+            ..Default::default()
+        };
+        match http_client
+            .get_object_with_options(tree_id, Some(options))
+            .await?
+            .details
+        {
+            GetObjectDataResponse::Exists(SuiObject {
+                data: SuiParsedData::MoveObject(object),
+                ..
+            }) => Ok::<_, anyhow::Error>(SuiMoveValue::Struct(object.fields)),
+            other => panic!("Unexpected object response: {:?}", other),
+        }
+    };
+    /// The value at `path` below `value`, where options are looked through.
+    fn value_at<'a>(value: &'a SuiMoveValue, path: &[&str]) -> &'a SuiMoveValue {
+        let Some((name, rest)) = path.split_first() else {
+            return value;
+        };
+        let next = match value {
+            SuiMoveValue::Struct(
+                SuiMoveStruct::WithTypes { fields, .. } | SuiMoveStruct::WithFields(fields),
+            ) => &fields[*name],
+            SuiMoveValue::Vector(values) => &values[name.parse::<usize>().unwrap()],
+            SuiMoveValue::Option(option) => {
+                return value_at(option.as_ref().as_ref().unwrap(), path)
+            }
+            other => panic!("No {name} in {:?}", other),
+        };
+        value_at(next, rest)
+    }
+    let is_truncated =
+        |value: &SuiMoveValue| matches!(value, SuiMoveValue::Truncated { truncated: true });
+
+    // The fields of the object are at depth 1, and an option does not add a level of nesting
+    let depth_1 = tree(Some(1)).await?;
+    assert_eq!(&SuiMoveValue::Number(0), value_at(&depth_1, &["value"]));
+    assert!(is_truncated(value_at(&depth_1, &["branch", "leaf"])));
+    assert!(is_truncated(value_at(&depth_1, &["branch", "leaves"])));
+    assert!(is_truncated(value_at(&depth_1, &["maybe_branch", "leaf"])));
+
+    let depth_2 = tree(Some(2)).await?;
+    assert!(is_truncated(value_at(
+        &depth_2,
+        &["branch", "leaf", "value"]
+    )));
+    assert!(is_truncated(value_at(&depth_2, &["branch", "leaves", "0"])));
+    assert!(is_truncated(value_at(
+        &depth_2,
+        &["maybe_branch", "leaves", "1"]
+    )));
+
+    let depth_3 = tree(Some(3)).await?;
+    assert_eq!(
+        &SuiMoveValue::Number(1),
+        value_at(&depth_3, &["branch", "leaf", "value"])
+    );
+    assert!(matches!(
+        value_at(&depth_3, &["branch", "leaves", "0"]),
+        SuiMoveValue::Struct(_)
+    ));
+    assert!(is_truncated(value_at(
+        &depth_3,
+        &["branch", "leaves", "0", "value"]
+    )));
+    assert!(is_truncated(value_at(
+        &depth_3,
+        &["maybe_branch", "leaves", "1", "value"]
+    )));
+
+    // Nothing is nested deeper than 4 levels
+    let full = tree(None).await?;
+    assert_eq!(
+        &SuiMoveValue::Number(3),
+        value_at(&full, &["maybe_branch", "leaves", "1", "value"])
+    );
+    assert_eq!(full, tree(Some(4)).await?);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_with_type_abilities() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let coin_id = objects.first().unwrap().object_id;
+
+    // `Coin<SUI>` has key and store
+    let response = http_client
+        .get_object_with_options(coin_id, Some(SuiObjectDataOptions::type_abilities()))
         .await?;
+    assert_eq!(
+        Some(SuiObjectTypeAbilities {
+            has_key: true,
+            has_store: true,
+            has_copy: false,
+            has_drop: false,
+        }),
+        response.type_abilities
+    );
 
-    assert_eq!("TRUSTED", result.symbol);
-    assert_eq!("Trusted Coin for test", result.description);
-    assert_eq!("Trusted Coin", result.name);
-    assert_eq!(2, result.decimals);
+    // `SuiSystemState` only has key, so it cannot be transferred outside of its module
+    let response = http_client
+        .get_object_with_options(
+            SUI_SYSTEM_STATE_OBJECT_ID,
+            Some(SuiObjectDataOptions::type_abilities()),
+        )
+        .await?;
+    assert_eq!(
+        Some(SuiObjectTypeAbilities {
+            has_key: true,
+            has_store: false,
+            has_copy: false,
+            has_drop: false,
+        }),
+        response.type_abilities
+    );
 
+    let response = http_client.get_object_with_options(coin_id, None).await?;
+    assert!(response.type_abilities.is_none());
     Ok(())
 }
 
 #[sim_test]
-async fn test_get_total_supply() -> Result<(), anyhow::Error> {
+async fn test_get_object_receivable() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+
+    let coin = http_client
+        .get_object(objects.first().unwrap().object_id)
+        .await?
+        .into_object()?;
+    assert_eq!(Owner::AddressOwner(*address), coin.owner);
+    assert!(coin.receivable);
+
+    let package = http_client
+        .get_object(SUI_FRAMEWORK_ADDRESS.into())
+        .await?
+        .into_object()?;
+    assert_eq!(Owner::Immutable, package.owner);
+    assert!(!package.receivable);
+
+    let system_state = http_client
+        .get_object(SUI_SYSTEM_STATE_OBJECT_ID)
+        .await?
+        .into_object()?;
+    assert!(!system_state.receivable);
+    Ok(())
+}
 
+#[sim_test]
+async fn test_get_object_system_object_kind() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+
+    let system_state = http_client
+        .get_object(SUI_SYSTEM_STATE_OBJECT_ID)
+        .await?
+        .into_object()?;
+    assert!(system_state.is_system_object);
+    assert_eq!(
+        Some(SuiSystemObjectKind::SystemState),
+        system_state.system_object_kind
+    );
+
+    let clock = http_client
+        .get_object(SUI_CLOCK_OBJECT_ID)
+        .await?
+        .into_object()?;
+    assert!(clock.is_system_object);
+    assert_eq!(Some(SuiSystemObjectKind::Clock), clock.system_object_kind);
+
+    let coin = http_client
+        .get_object(objects.first().unwrap().object_id)
+        .await?
+        .into_object()?;
+    assert!(!coin.is_system_object);
+    assert!(coin.system_object_kind.is_none());
+    Ok(())
+}
 
+#[sim_test]
+async fn test_get_object_with_nested_refs() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
     let objects = http_client.get_objects_owned_by_address(*address).await?;
     let gas = objects.first().unwrap();
+    let (first, second) = (objects[1].object_id, objects[2].object_id);
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
 
-    // Publish test coin package
     let compiled_modules = BuildConfig::new_for_testing()
         .build(Path::new("src/unit_tests/data/dummy_modules_publish").to_path_buf())?
         .get_package_base64(/* with_unpublished_deps */ false);
-
     let transaction_bytes: TransactionBytes = http_client
         .publish(*address, compiled_modules, Some(gas.object_id), 10000)
         .await?;
-
-    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
-    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
     let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
     let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
-
-    let tx_response = http_client
+    let effects = http_client
         .submit_transaction(
             tx_bytes,
             signatures,
             ExecuteTransactionRequestType::WaitForLocalExecution,
         )
-        .await?;
-
-    let SuiTransactionResponse { effects, .. } = tx_response;
-
+        .await?
+        .effects;
     let package_id = effects
         .events
         .iter()
         .find_map(|e| {
             if let SuiEvent::Publish { package_id, .. } = e {
-                Some(package_id)
-            } else {
-                None
-            }
-        })
-        .unwrap();
-
-    let coin_name = format!("{package_id}::trusted_coin::TRUSTED_COIN");
-    let coin_type = parse_sui_type_tag(&coin_name).unwrap();
-    let result: Supply = http_client.get_total_supply(coin_name.clone()).await?;
-
-    assert_eq!(0, result.value);
-
-    let treasury_cap = effects
-        .events
-        .iter()
-        .find_map(|e| {
-            if let SuiEvent::NewObject {
-                object_id,
-                object_type,
-                ..
-            } = e
-            {
-                if TreasuryCap::type_(parse_sui_struct_tag(&coin_name).unwrap())
-                    == parse_sui_struct_tag(object_type).unwrap()
-                {
-                    Some(object_id)
-                } else {
-                    None
-                }
+                Some(*package_id)
             } else {
                 None
             }
         })
         .unwrap();
 
-    // Mint 100000 coin
-
+    // Refer to two coins, and to an ID no object has
+    let missing = ObjectID::random();
     let transaction_bytes: TransactionBytes = http_client
         .move_call(
             *address,
-            SUI_FRAMEWORK_ADDRESS.into(),
-            COIN_MODULE_NAME.to_string(),
-            "mint_and_transfer".into(),
-            vec![coin_type.into()],
-            vec![
-                SuiJsonValue::from_str(&treasury_cap.to_string()).unwrap(),
-                SuiJsonValue::from_str("\"100000\"").unwrap(),
-                SuiJsonValue::from_str(&address.to_string()).unwrap(),
-            ],
+            package_id,
+            "object_refs".to_string(),
+            "create".to_string(),
+            vec![],
+            vec![SuiJsonValue::new(serde_json::json!([
+                first.to_string(),
+                second.to_string(),
+                missing.to_string()
+            ]))?],
             Some(gas.object_id),
             10_000,
             None,
         )
         .await?;
-
-    let tx = transaction_bytes.to_data()?;
-
-    let tx = to_sender_signed_transaction(tx, keystore.get_key(address)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
     let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
-
-    let tx_response = http_client
+    let effects = http_client
         .submit_transaction(
             tx_bytes,
             signatures,
             ExecuteTransactionRequestType::WaitForLocalExecution,
         )
+        .await?
+        .effects;
+    assert_eq!(SuiExecutionStatus::Success, effects.status);
+    let pointers_id = effects.created.first().unwrap().reference.object_id;
+
+    let response = http_client
+        .get_object_with_options(pointers_id, Some(SuiObjectDataOptions::nested_refs()))
         .await?;
+    let nested_refs = response.nested_refs.unwrap();
+    assert_eq!(3, nested_refs.len());
+    for (i, (nested_ref, object_id)) in nested_refs.iter().zip([first, second]).enumerate() {
+        assert_eq!(format!("refs[{i}]"), nested_ref.path);
+        assert_eq!(object_id, nested_ref.object_id);
+        match &nested_ref.resolution {
+            SuiNestedObjectResolution::Resolved { type_, .. } => {
+                assert_eq!(&GasCoin::type_().to_string(), type_)
+            }
+            other => panic!("Expected the coin to be resolved: {:?}", other),
+        }
+    }
+    assert_eq!(missing, nested_refs[2].object_id);
+    assert_eq!(
+        SuiNestedObjectResolution::Unresolved,
+        nested_refs[2].resolution
+    );
 
-    let SuiTransactionResponse { effects, .. } = tx_response;
+    let response = http_client
+        .get_object_with_options(pointers_id, None)
+        .await?;
+    assert!(response.nested_refs.is_none());
+    Ok(())
+}
 
-    assert_eq!(SuiExecutionStatus::Success, effects.status);
+#[sim_test]
+async fn test_get_normalized_move_struct_field_order() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
 
-    let result: Supply = http_client.get_total_supply(coin_name.clone()).await?;
-    assert_eq!(100000, result.value);
+    let struct_ = http_client
+        .get_normalized_move_struct(
+            SUI_FRAMEWORK_ADDRESS.into(),
+            COIN_MODULE_NAME.to_string(),
+            "CoinMetadata".to_string(),
+        )
+        .await?;
+    // Fields are listed in the order they are declared in `sui::coin::CoinMetadata`
+    let fields = struct_
+        .fields
+        .iter()
+        .map(|field| (field.index, field.name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (0, "id"),
+            (1, "decimals"),
+            (2, "name"),
+            (3, "symbol"),
+            (4, "description"),
+            (5, "icon_url"),
+        ],
+        fields
+    );
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_module_abi() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
 
+    let abi = http_client
+        .get_module_abi(SUI_FRAMEWORK_ADDRESS.into(), "url".to_string())
+        .await?;
+    let function = |name: &str, parameters: &[&str], return_: &[&str]| FunctionAbi {
+        name: name.to_string(),
+        visibility: SuiMoveVisibility::Public,
+        is_entry: false,
+        type_parameters: vec![],
+        parameters: parameters.iter().map(|t| t.to_string()).collect(),
+        return_: return_.iter().map(|t| t.to_string()).collect(),
+    };
+    let expected = ModuleAbi {
+        address: "0x2".to_string(),
+        name: "url".to_string(),
+        structs: vec![StructAbi {
+            name: "Url".to_string(),
+            abilities: vec![
+                SuiMoveAbility::Copy,
+                SuiMoveAbility::Drop,
+                SuiMoveAbility::Store,
+            ],
+            type_parameters: vec![],
+            fields: vec![FieldAbi {
+                name: "url".to_string(),
+                type_: "0x1::ascii::String".to_string(),
+            }],
+        }],
+        functions: vec![
+            function("inner_url", &["&0x2::url::Url"], &["0x1::ascii::String"]),
+            function("new_unsafe", &["0x1::ascii::String"], &["0x2::url::Url"]),
+            function("new_unsafe_from_bytes", &["vector<u8>"], &["0x2::url::Url"]),
+            function("update", &["&mut 0x2::url::Url", "0x1::ascii::String"], &[]),
+        ],
+    };
+    assert_eq!(expected, abi);
+
+    // Type parameters are referred to by position
+    let abi = http_client
+        .get_module_abi(SUI_FRAMEWORK_ADDRESS.into(), COIN_MODULE_NAME.to_string())
+        .await?;
+    let value = abi.functions.iter().find(|f| f.name == "value").unwrap();
+    assert_eq!("T0", value.type_parameters[0].name);
+    assert_eq!(vec!["&0x2::coin::Coin<T0>".to_string()], value.parameters);
+    assert_eq!(vec!["u64".to_string()], value.return_);
     Ok(())
 }
 
 #[sim_test]
-async fn test_get_transaction() -> Result<(), anyhow::Error> {
+async fn test_move_function_exists() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
     let http_client = cluster.rpc_client();
-    let address = cluster.accounts.first().unwrap();
+    let package = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
-    let gas_id = objects.last().unwrap().object_id;
+    assert!(
+        http_client
+            .move_function_exists(package, "coin".to_string(), "value".to_string())
+            .await?
+    );
+    assert!(
+        !http_client
+            .move_function_exists(package, "coin".to_string(), "no_such_function".to_string())
+            .await?
+    );
+    assert!(http_client
+        .move_function_exists(package, "no_such_module".to_string(), "value".to_string())
+        .await
+        .is_err());
+    assert!(http_client
+        .move_function_exists(ObjectID::random(), "coin".to_string(), "value".to_string())
+        .await
+        .is_err());
+    Ok(())
+}
 
-    // Make some transactions
-    let mut tx_responses: Vec<SuiTransactionResponse> = Vec::new();
-    for oref in &objects[..objects.len() - 1] {
-        let transaction_bytes: TransactionBytes = http_client
-            .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
-            .await?;
-        let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
-        let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
-        let tx =
-            to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+#[sim_test]
+async fn test_get_coins() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
 
-        let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let result: CoinPage = http_client
+        .get_coins(*address, None, None, None, None)
+        .await?;
+    assert_eq!(5, result.data.len());
+    assert_eq!(None, result.next_cursor);
 
-        let response = http_client
-            .submit_transaction(
-                tx_bytes,
-                signatures,
-                ExecuteTransactionRequestType::WaitForLocalExecution,
-            )
-            .await?;
+    let result: CoinPage = http_client
+        .get_coins(
+            *address,
+            Some("0x2::sui::TestCoin".into()),
+            None,
+            None,
+            None,
+        )
+        .await?;
+    assert_eq!(0, result.data.len());
 
-        tx_responses.push(response);
-    }
-    // test get_transactions_in_range
-    let tx: Vec<TransactionDigest> = http_client.get_transactions_in_range(0, 10).await?;
-    assert_eq!(5, tx.len());
+    let result: CoinPage = http_client
+        .get_coins(*address, Some("0x2::sui::SUI".into()), None, None, None)
+        .await?;
+    assert_eq!(5, result.data.len());
+    assert_eq!(None, result.next_cursor);
 
-    // test get_transactions_in_range with smaller range
-    let tx: Vec<TransactionDigest> = http_client.get_transactions_in_range(1, 3).await?;
-    assert_eq!(2, tx.len());
+    // Test paging
+    let result: CoinPage = http_client
+        .get_coins(*address, Some("0x2::sui::SUI".into()), None, Some(3), None)
+        .await?;
+    assert_eq!(3, result.data.len());
+    assert!(result.next_cursor.is_some());
 
-    // test get_transaction
-    for tx_digest in tx {
-        let response: SuiTransactionResponse = http_client.get_transaction(tx_digest).await?;
-        assert!(tx_responses.iter().any(
-            |resp| matches!(resp, SuiTransactionResponse {effects, ..} if effects.transaction_digest == response.effects.transaction_digest)
-        ))
-    }
+    let result: CoinPage = http_client
+        .get_coins(
+            *address,
+            Some("0x2::sui::SUI".into()),
+            result.next_cursor,
+            Some(3),
+            None,
+        )
+        .await?;
+    assert_eq!(2, result.data.len());
+    assert!(result.next_cursor.is_none());
 
     Ok(())
 }
 
 #[sim_test]
-async fn test_get_fullnode_transaction() -> Result<(), anyhow::Error> {
-    let mut cluster = TestClusterBuilder::new().build().await.unwrap();
+async fn test_get_coins_sorted_by_balance() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    // Paying for gas leaves the sender's coins with different balances
+    let (_, address, _, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
 
-    let context = &mut cluster.wallet;
+    let page = http_client
+        .get_coins(address, None, None, None, None)
+        .await?;
+    let mut by_id = page
+        .data
+        .iter()
+        .map(|coin| coin.balance)
+        .collect::<Vec<_>>();
 
-    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
-    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
-    let mut tx_responses: Vec<SuiTransactionResponse> = Vec::new();
+    let page = http_client
+        .get_coins(address, None, None, None, Some(CoinSortOrder::BalanceDesc))
+        .await?;
+    let descending = page
+        .data
+        .iter()
+        .map(|coin| coin.balance)
+        .collect::<Vec<_>>();
+    assert!(descending.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(descending.first() > descending.last());
 
-    let client = context.get_client().await.unwrap();
+    let page = http_client
+        .get_coins(address, None, None, None, Some(CoinSortOrder::BalanceAsc))
+        .await?;
+    let ascending = page
+        .data
+        .iter()
+        .map(|coin| coin.balance)
+        .collect::<Vec<_>>();
+    by_id.sort();
+    assert_eq!(by_id, ascending);
+    Ok(())
+}
 
-    for address in cluster.accounts.iter() {
-        let objects = client
-            .read_api()
-            .get_objects_owned_by_address(*address)
-            .await
-            .unwrap();
-        let gas_id = objects.last().unwrap().object_id;
+#[sim_test]
+async fn test_select_coins() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, address, _, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
 
-        // Make some transactions
-        for oref in &objects[..objects.len() - 1] {
-            let data = client
-                .transaction_builder()
-                .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
-                .await?;
-            let tx = to_sender_signed_transaction(data, keystore.get_key(address).unwrap());
+    let mut coins = http_client
+        .get_coins(address, None, None, None, Some(CoinSortOrder::BalanceDesc))
+        .await?
+        .data;
+    assert!(coins.len() > 2);
+    let total = coins.iter().map(|coin| coin.balance as u128).sum::<u128>();
+    let largest = coins[0].balance as u128 + coins[1].balance as u128;
+    let ids = |coins: &[Coin]| {
+        coins
+            .iter()
+            .map(|coin| coin.coin_object_id)
+            .collect::<Vec<_>>()
+    };
 
-            let response = client
-                .quorum_driver()
-                .execute_transaction(
-                    tx,
-                    Some(ExecuteTransactionRequestType::WaitForLocalExecution),
-                )
-                .await
-                .unwrap();
+    // Exact fit with the two largest coins
+    let selected = http_client
+        .select_coins(address, None, largest, None)
+        .await?;
+    assert_eq!(ids(&coins[..2]), ids(&selected));
 
-            tx_responses.push(response);
-        }
-    }
+    // One more than the two largest coins hold needs a third coin
+    let selected = http_client
+        .select_coins(address, None, largest + 1, None)
+        .await?;
+    assert_eq!(ids(&coins[..3]), ids(&selected));
 
-    // test get_recent_transactions with smaller range
-    let tx = client
-        .read_api()
-        .get_transactions(TransactionQuery::All, None, Some(3), true)
+    let error = http_client
+        .select_coins(address, None, total + 1, None)
         .await
-        .unwrap();
-    assert_eq!(3, tx.data.len());
-
-    // test get all transactions paged
-    let first_page = client
-        .read_api()
-        .get_transactions(TransactionQuery::All, None, Some(5), false)
+        .unwrap_err();
+    assert!(error.to_string().contains("Insufficient fund"));
+
+    // Excluded coins are neither selected nor counted
+    let excluded = coins.remove(0);
+    let selected = http_client
+        .select_coins(
+            address,
+            None,
+            total - excluded.balance as u128,
+            Some(vec![excluded.coin_object_id]),
+        )
+        .await?;
+    assert_eq!(ids(&coins), ids(&selected));
+    assert!(http_client
+        .select_coins(
+            address,
+            None,
+            total - excluded.balance as u128 + 1,
+            Some(vec![excluded.coin_object_id]),
+        )
         .await
-        .unwrap();
-    assert_eq!(5, first_page.data.len());
-    assert!(first_page.next_cursor.is_some());
+        .is_err());
+    Ok(())
+}
 
-    // test get all transactions in ascending order
-    let second_page = client
-        .read_api()
-        .get_transactions(TransactionQuery::All, first_page.next_cursor, None, false)
-        .await
-        .unwrap();
-    assert_eq!(16, second_page.data.len());
-    assert!(second_page.next_cursor.is_none());
+#[sim_test]
+async fn test_select_coins_beyond_one_page() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = *cluster.accounts.first().unwrap();
 
-    let mut all_txs_rev = first_page.data.clone();
-    all_txs_rev.extend(second_page.data);
-    all_txs_rev.reverse();
+    // More coins than a single read of the owner index returns
+    let coin_count = split_into_coins(&cluster, address, 300).await?;
+    assert!(coin_count > 256);
 
-    // test get 10 latest transactions paged
-    let latest = client
-        .read_api()
-        .get_transactions(TransactionQuery::All, None, Some(10), true)
-        .await
-        .unwrap();
-    assert_eq!(10, latest.data.len());
+    let balance = http_client.get_balance(address, None).await?;
+    assert_eq!(coin_count, balance.coin_object_count);
 
-    assert_eq!(Some(all_txs_rev[10]), latest.next_cursor);
-    assert_eq!(all_txs_rev[0..10], latest.data);
+    // The full balance can only be met by selecting every coin
+    let selected = http_client
+        .select_coins(address, None, balance.total_balance, None)
+        .await?;
+    assert_eq!(coin_count, selected.len());
+    assert_eq!(
+        balance.total_balance,
+        selected
+            .iter()
+            .map(|coin| coin.balance as u128)
+            .sum::<u128>()
+    );
+    Ok(())
+}
 
-    // test get from address txs in ascending order
-    let address_txs_asc = client
-        .read_api()
-        .get_transactions(
-            TransactionQuery::FromAddress(cluster.accounts[0]),
-            None,
-            None,
-            false,
-        )
-        .await
-        .unwrap();
-    assert_eq!(4, address_txs_asc.data.len());
+#[sim_test]
+async fn test_get_balance() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
 
-    // test get from address txs in descending order
-    let address_txs_desc = client
-        .read_api()
-        .get_transactions(
-            TransactionQuery::FromAddress(cluster.accounts[0]),
-            None,
-            None,
-            true,
+    let result: Balance = http_client.get_balance(*address, None).await?;
+    assert_eq!("0x2::sui::SUI", result.coin_type);
+    assert_eq!(500000000000000, result.total_balance);
+    assert_eq!(5, result.coin_object_count);
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_metadata() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas = objects.first().unwrap();
+
+    // Publish test coin package
+    let compiled_modules = BuildConfig::default()
+        .build(Path::new("src/unit_tests/data/dummy_modules_publish").to_path_buf())?
+        .get_package_base64(/* with_unpublished_deps */ false);
+
+    let transaction_bytes: TransactionBytes = http_client
+        .publish(*address, compiled_modules, Some(gas.object_id), 10000)
+        .await?;
+
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    let tx_response = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
         )
-        .await
-        .unwrap();
-    assert_eq!(4, address_txs_desc.data.len());
+        .await?;
 
-    // test get from address txs in both ordering are the same.
-    let mut data_asc = address_txs_asc.data;
-    data_asc.reverse();
-    assert_eq!(data_asc, address_txs_desc.data);
+    let SuiTransactionResponse { effects, .. } = tx_response;
 
-    // test get_recent_transactions
-    let tx = client
-        .read_api()
-        .get_transactions(TransactionQuery::All, None, Some(20), true)
-        .await
+    let package_id = effects
+        .events
+        .iter()
+        .find_map(|e| {
+            if let SuiEvent::Publish { package_id, .. } = e {
+                Some(package_id)
+            } else {
+                None
+            }
+        })
         .unwrap();
-    assert_eq!(20, tx.data.len());
+
+    let result: SuiCoinMetadata = http_client
+        .get_coin_metadata(format!("{package_id}::trusted_coin::TRUSTED_COIN"))
+        .await?;
+
+    assert_eq!("TRUSTED", result.symbol);
+    assert_eq!("Trusted Coin for test", result.description);
+    assert_eq!("Trusted Coin", result.name);
+    assert_eq!(2, result.decimals);
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_multi_get_coin_metadata() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas = objects.first().unwrap();
+
+    // Publish test coin package
+    let compiled_modules = BuildConfig::default()
+        .build(Path::new("src/unit_tests/data/dummy_modules_publish").to_path_buf())?
+        .get_package_base64(/* with_unpublished_deps */ false);
+
+    let transaction_bytes: TransactionBytes = http_client
+        .publish(*address, compiled_modules, Some(gas.object_id), 10000)
+        .await?;
+
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    let tx_response = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    let package_id = tx_response
+        .effects
+        .events
+        .iter()
+        .find_map(|e| {
+            if let SuiEvent::Publish { package_id, .. } = e {
+                Some(*package_id)
+            } else {
+                None
+            }
+        })
+        .unwrap();
+
+    // Types with metadata are interleaved with types that never published any
+    let result = http_client
+        .multi_get_coin_metadata(vec![
+            format!("{package_id}::object_refs::Pointers"),
+            format!("{package_id}::trusted_coin::TRUSTED_COIN"),
+            format!("{}::missing::MISSING", ObjectID::random()),
+            "0x2::sui::SUI".to_string(),
+        ])
+        .await?;
+
+    assert_eq!(4, result.len());
+    assert!(result[0].is_none());
+    assert_eq!("TRUSTED", result[1].as_ref().unwrap().symbol);
+    assert!(result[2].is_none());
+    assert_eq!("SUI", result[3].as_ref().unwrap().symbol);
+
+    // Requesting more types than the limit is rejected
+    let too_many = vec!["0x2::sui::SUI".to_string(); QUERY_MAX_RESULT_LIMIT + 1];
+    assert!(http_client.multi_get_coin_metadata(too_many).await.is_err());
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_total_supply() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas = objects.first().unwrap();
+
+    // Publish test coin package
+    let compiled_modules = BuildConfig::new_for_testing()
+        .build(Path::new("src/unit_tests/data/dummy_modules_publish").to_path_buf())?
+        .get_package_base64(/* with_unpublished_deps */ false);
+
+    let transaction_bytes: TransactionBytes = http_client
+        .publish(*address, compiled_modules, Some(gas.object_id), 10000)
+        .await?;
+
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    let tx_response = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    let SuiTransactionResponse { effects, .. } = tx_response;
+
+    let package_id = effects
+        .events
+        .iter()
+        .find_map(|e| {
+            if let SuiEvent::Publish { package_id, .. } = e {
+                Some(package_id)
+            } else {
+                None
+            }
+        })
+        .unwrap();
+
+    let coin_name = format!("{package_id}::trusted_coin::TRUSTED_COIN");
+    let coin_type = parse_sui_type_tag(&coin_name).unwrap();
+    let result: Supply = http_client.get_total_supply(coin_name.clone()).await?;
+
+    assert_eq!(0, result.value);
+
+    let treasury_cap = effects
+        .events
+        .iter()
+        .find_map(|e| {
+            if let SuiEvent::NewObject {
+                object_id,
+                object_type,
+                ..
+            } = e
+            {
+                if TreasuryCap::type_(parse_sui_struct_tag(&coin_name).unwrap())
+                    == parse_sui_struct_tag(object_type).unwrap()
+                {
+                    Some(object_id)
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .unwrap();
+
+    // Mint 100000 coin
+
+    let transaction_bytes: TransactionBytes = http_client
+        .move_call(
+            *address,
+            SUI_FRAMEWORK_ADDRESS.into(),
+            COIN_MODULE_NAME.to_string(),
+            "mint_and_transfer".into(),
+            vec![coin_type.into()],
+            vec![
+                SuiJsonValue::from_str(&treasury_cap.to_string()).unwrap(),
+                SuiJsonValue::from_str("\"100000\"").unwrap(),
+                SuiJsonValue::from_str(&address.to_string()).unwrap(),
+            ],
+            Some(gas.object_id),
+            10_000,
+            None,
+        )
+        .await?;
+
+    let tx = transaction_bytes.to_data()?;
+
+    let tx = to_sender_signed_transaction(tx, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    let tx_response = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    let SuiTransactionResponse { effects, .. } = tx_response;
+
+    assert_eq!(SuiExecutionStatus::Success, effects.status);
+
+    let result: Supply = http_client.get_total_supply(coin_name.clone()).await?;
+    assert_eq!(100000, result.value);
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas_id = objects.last().unwrap().object_id;
+
+    // Make some transactions
+    let mut tx_responses: Vec<SuiTransactionResponse> = Vec::new();
+    for oref in &objects[..objects.len() - 1] {
+        let transaction_bytes: TransactionBytes = http_client
+            .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
+            .await?;
+        let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+        let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+        let tx =
+            to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+
+        let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+        let response = http_client
+            .submit_transaction(
+                tx_bytes,
+                signatures,
+                ExecuteTransactionRequestType::WaitForLocalExecution,
+            )
+            .await?;
+
+        tx_responses.push(response);
+    }
+    // test get_transactions_in_range
+    let tx: Vec<TransactionDigest> = http_client.get_transactions_in_range(0, 10).await?;
+    assert_eq!(5, tx.len());
+
+    // test get_transactions_in_range with smaller range
+    let tx: Vec<TransactionDigest> = http_client.get_transactions_in_range(1, 3).await?;
+    assert_eq!(2, tx.len());
 
     // test get_transaction
-    for tx_digest in tx.data {
-        let response: SuiTransactionResponse =
-            client.read_api().get_transaction(tx_digest).await.unwrap();
-        assert!(tx_responses
-            .iter()
-            .any(|resp| resp.effects.transaction_digest == response.effects.transaction_digest))
+    for tx_digest in tx {
+        let response: SuiTransactionResponse = http_client.get_transaction(tx_digest).await?;
+        assert!(tx_responses.iter().any(
+            |resp| matches!(resp, SuiTransactionResponse {effects, ..} if effects.transaction_digest == response.effects.transaction_digest)
+        ))
+    }
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transactions_in_range_paged() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    for _ in 0..3 {
+        transfer_coin(&mut cluster.wallet).await?;
+    }
+    let http_client = cluster.rpc_client();
+
+    let all: Vec<TransactionDigest> = http_client.get_transactions_in_range(0, 10).await?;
+    assert!(all.len() > 2);
+
+    // Pages of 2 in both directions cover the range exactly once
+    for descending in [false, true] {
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = http_client
+                .get_transactions_in_range_paged(0, 10, cursor, Some(2), Some(descending))
+                .await?;
+            assert!(page.data.len() <= 2);
+            paged.extend(page.data);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        let mut expected = all.clone();
+        if descending {
+            expected.reverse();
+        }
+        assert_eq!(expected, paged);
     }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transactions_from_address_by_timestamp() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas_id = objects.last().unwrap().object_id;
+    for oref in &objects[..objects.len() - 1] {
+        let transaction_bytes: TransactionBytes = http_client
+            .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
+            .await?;
+        let tx =
+            to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+        let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+        http_client
+            .submit_transaction(
+                tx_bytes,
+                signatures,
+                ExecuteTransactionRequestType::WaitForLocalExecution,
+            )
+            .await?;
+    }
+    let sent = http_client
+        .get_transactions(TransactionQuery::FromAddress(*address), None, None, false)
+        .await?
+        .data;
+    assert!(sent.len() > 2);
+
+    for descending in [false, true] {
+        let all = http_client
+            .get_transactions_from_address_by_timestamp(
+                *address,
+                0,
+                u64::MAX,
+                None,
+                None,
+                Some(descending),
+            )
+            .await?;
+        assert!(all.next_cursor.is_none());
+        assert_eq!(
+            sent.iter().collect::<BTreeSet<_>>(),
+            all.data.iter().collect::<BTreeSet<_>>()
+        );
+
+        // Pages of 2 from each cursor go through the transactions in the same order
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = http_client
+                .get_transactions_from_address_by_timestamp(
+                    *address,
+                    0,
+                    u64::MAX,
+                    cursor,
+                    Some(2),
+                    Some(descending),
+                )
+                .await?;
+            assert!(page.data.len() <= 2);
+            paged.extend(page.data);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(all.data, paged);
+    }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_signatures() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let response: SuiTransactionResponse = http_client.get_transaction(digest).await?;
+    let signatures = http_client.get_transaction_signatures(digest).await?;
+    assert_eq!(
+        response
+            .transaction
+            .tx_signatures
+            .into_iter()
+            .map(SuiTransactionSignature::Signature)
+            .collect::<Vec<_>>(),
+        signatures
+    );
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_effects() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let response: SuiTransactionResponse = http_client.get_transaction(digest).await?;
+    let effects = http_client.get_transaction_effects(digest).await?;
+    assert_eq!(response.effects, effects);
+    assert!(!effects.events.is_empty());
+
+    assert!(http_client
+        .get_transaction_effects(TransactionDigest::random())
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_effects_and_events() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new()
+        .enable_fullnode_events()
+        .build()
+        .await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    // Add a delay to ensure event processing is done after transaction commits.
+    sleep(Duration::from_millis(100)).await;
+    let http_client = cluster.rpc_client();
+
+    let effects = http_client.get_transaction_effects(digest).await?;
+    let effects_and_events = http_client
+        .get_transaction_effects_and_events(digest)
+        .await?;
+    assert_eq!(effects, effects_and_events.effects);
+    let events = http_client
+        .get_events(EventQuery::Transaction(digest), None, None, None)
+        .await?;
+    assert_eq!(events.data, effects_and_events.events);
+    assert_eq!(
+        effects.events,
+        effects_and_events
+            .events
+            .into_iter()
+            .map(|envelope| envelope.event)
+            .collect::<Vec<_>>()
+    );
+
+    assert!(http_client
+        .get_transaction_effects_and_events(TransactionDigest::random())
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_command_effects_unsupported() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let read_api = ReadApi::new(cluster.fullnode_handle.sui_node.state());
+
+    match ReadApiServer::get_transaction_command_effects(&read_api, digest).await {
+        Err(RpcError::Call(CallError::Custom(error))) => {
+            assert_eq!(UNSUPPORTED_CODE, error.code());
+            assert!(error.message().contains("sui_getTransactionCommandEffects"));
+        }
+        other => panic!("Expected an unsupported error, got {other:?}"),
+    }
+    // Unknown transactions are not found rather than unsupported
+    match ReadApiServer::get_transaction_command_effects(&read_api, TransactionDigest::random())
+        .await
+    {
+        Err(RpcError::Call(CallError::Failed(error))) => {
+            assert!(error
+                .to_string()
+                .starts_with(TRANSACTION_NOT_FOUND_MSG_PREFIX));
+        }
+        other => panic!("Expected a transaction not found error, got {other:?}"),
+    }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_dependencies() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let response: SuiTransactionResponse = http_client.get_transaction(digest).await?;
+    let dependencies = http_client.get_transaction_dependencies(digest).await?;
+    assert!(!dependencies.is_empty());
+    assert_eq!(response.effects.dependencies, dependencies);
+
+    assert!(http_client
+        .get_transaction_dependencies(TransactionDigest::random())
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_with_gas_object() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, sender, _, digest, gas, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let response = http_client
+        .get_transaction_with_options(digest, None)
+        .await?;
+    assert!(response.gas_object.is_none());
+
+    let response = http_client
+        .get_transaction_with_options(digest, Some(SuiTransactionResponseOptions::gas_object()))
+        .await?;
+    let gas_object = response.gas_object.unwrap();
+    assert_eq!(gas.0, gas_object.reference.object_id);
+    assert_eq!(response.effects.gas_object.reference, gas_object.reference);
+
+    // The gas coin has not been used since, so its balance matches a separate read
+    let coin = http_client
+        .get_coins(sender, None, None, None, None)
+        .await?
+        .data
+        .into_iter()
+        .find(|coin| coin.coin_object_id == gas.0)
+        .unwrap();
+    assert_eq!(coin.version, gas_object.reference.version);
+    assert_eq!(coin.balance, gas_object.balance);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_with_execution_digests() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let checkpoint = wait_for_checkpoint(&cluster, digest).await?;
+    let http_client = cluster.rpc_client();
+
+    let response = http_client
+        .get_transaction_with_options(digest, None)
+        .await?;
+    assert!(response.execution_digests.is_none());
+
+    let response = http_client
+        .get_transaction_with_options(
+            digest,
+            Some(SuiTransactionResponseOptions::execution_digests()),
+        )
+        .await?;
+    let execution_digests = response.execution_digests.unwrap();
+    assert_eq!(digest, execution_digests.transaction);
+
+    // The effects digest is the digest of the effects as stored by the node
+    let effects = cluster
+        .fullnode_handle
+        .sui_node
+        .state()
+        .database
+        .get_executed_effects(&digest)?
+        .unwrap();
+    let effects: TransactionEffects = bcs::from_bytes(&bcs::to_bytes(&effects)?)?;
+    assert_eq!(effects.digest(), execution_digests.effects);
+
+    // The digests identify the transaction in the contents of its checkpoint
+    let contents = http_client.get_checkpoint_contents(checkpoint).await?;
+    assert!(contents.iter().any(|digests| *digests == execution_digests));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_with_object_argument_types() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas = objects.first().unwrap();
+    let (first, second) = (objects[1].to_object_ref(), objects[2].to_object_ref());
+
+    // `pay::join` takes both coins as object arguments
+    let transaction_bytes: TransactionBytes = http_client
+        .move_call(
+            *address,
+            ObjectID::from(SUI_FRAMEWORK_ADDRESS),
+            "pay".to_string(),
+            "join".to_string(),
+            vec![GAS::type_tag().into()],
+            vec![
+                SuiJsonValue::from_object_id(first.0),
+                SuiJsonValue::from_object_id(second.0),
+            ],
+            Some(gas.object_id),
+            10_000,
+            None,
+        )
+        .await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let digest = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?
+        .effects
+        .transaction_digest;
+
+    let call = |response: SuiTransactionResponse| match response.transaction.data.transactions {
+        transactions if transactions.len() == 1 => match transactions.into_iter().next() {
+            Some(SuiTransactionKind::Call(call)) => call,
+            other => panic!("Expected a Move call: {:?}", other),
+        },
+        other => panic!("Expected a single transaction: {:?}", other),
+    };
+    let response = http_client
+        .get_transaction_with_options(digest, None)
+        .await?;
+    assert!(call(response).object_arguments.is_none());
+
+    let response = http_client
+        .get_transaction_with_options(
+            digest,
+            Some(SuiTransactionResponseOptions::object_argument_types()),
+        )
+        .await?;
+    // The second coin is deleted by the join, its type is read at the version the call used
+    let coin_type = GasCoin::type_().to_string();
+    assert_eq!(
+        Some(vec![
+            SuiMoveCallObjectArgument {
+                index: 0,
+                object_id: first.0,
+                version: first.1,
+                type_: Some(coin_type.clone()),
+            },
+            SuiMoveCallObjectArgument {
+                index: 1,
+                object_id: second.0,
+                version: second.1,
+                type_: Some(coin_type),
+            },
+        ]),
+        call(response).object_arguments
+    );
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_with_unresolved_type() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let address = cluster.accounts.first().unwrap();
+
+    // A coin whose type is defined by a package that does not exist
+    let mut object = Object::new_gas_with_balance_and_owner_for_testing(100, *address);
+    let object_id = object.id();
+    let contents = match &mut object.data {
+        Data::Move(move_object) => {
+            move_object.type_ =
+                parse_sui_struct_tag(&format!("{}::missing::Missing", ObjectID::random()))?;
+            move_object.contents().to_vec()
+        }
+        Data::Package(_) => unreachable!(),
+    };
+    cluster
+        .fullnode_handle
+        .sui_node
+        .state()
+        .insert_genesis_object(object)
+        .await;
+
+    let response = cluster.rpc_client().get_object(object_id).await?;
+    let object = response.object()?;
+    assert_eq!(object_id, object.id());
+    assert!(object.type_resolution_error.is_some());
+    assert_eq!(Some(contents), object.raw_content);
+    match &object.data {
+        SuiParsedData::MoveObject(move_object) => {
+            assert!(move_object.type_.ends_with("::missing::Missing"));
+            assert_eq!(
+                SuiMoveStruct::WithFields(BTreeMap::new()),
+                move_object.fields
+            );
+        }
+        other => panic!("Unexpected object data: {other:?}"),
+    }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_move_bcs() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let coin = http_client
+        .get_coins(*address, None, None, None, None)
+        .await?
+        .data
+        .remove(0);
+
+    let bcs_bytes = http_client
+        .get_object_move_bcs(coin.coin_object_id)
+        .await?
+        .to_vec()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let gas_coin: GasCoin = bcs::from_bytes(&bcs_bytes)?;
+    assert_eq!(&coin.coin_object_id, gas_coin.id());
+    assert_eq!(coin.balance, gas_coin.value());
+
+    // Packages have no Move value
+    assert!(http_client
+        .get_object_move_bcs(ObjectID::from(SUI_FRAMEWORK_ADDRESS))
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_at_least_version() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client().clone();
+    let sender = cluster.wallet.config.keystore.addresses()[0];
+    // transfer_coin sends the second object owned by the sender
+    let object = http_client.get_objects_owned_by_address(sender).await?[1].clone();
+    let min_version = SequenceNumber::from_u64(object.version.value() + 1);
+
+    let error = http_client
+        .get_object_at_least_version(object.object_id, min_version, Some(100))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("did not reach version"));
+
+    let (response, transfer) = futures::join!(
+        http_client.get_object_at_least_version(object.object_id, min_version, Some(10_000)),
+        async {
+            sleep(Duration::from_millis(500)).await;
+            transfer_coin(&mut cluster.wallet).await
+        }
+    );
+    let (transferred, _, _, _, _, _) = transfer?;
+    assert_eq!(object.object_id, transferred);
+    assert!(response?.object()?.version() >= min_version);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_digest() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (object_id, _, _, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let object = http_client.get_object(object_id).await?;
+    assert_eq!(
+        object.object()?.reference.digest,
+        http_client.get_object_digest(object_id).await?
+    );
+
+    let error = http_client
+        .get_object_digest(ObjectID::random())
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Could not find the referenced object"));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_gas_budget_and_price() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let response = http_client.get_transaction(digest).await?;
+    let (transaction, _) = cluster
+        .fullnode_handle
+        .sui_node
+        .state()
+        .get_executed_transaction_and_effects(digest)
+        .await?;
+    let stored = &transaction.data().intent_message.value;
+
+    let data = &response.transaction.data;
+    assert_eq!(stored.gas_budget(), data.gas_budget);
+    assert_eq!(stored.gas_price(), data.gas_price);
+    assert_eq!(data.gas_data.budget, data.gas_budget);
+    assert_eq!(data.gas_data.price, data.gas_price);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_execution_status() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    assert_eq!(
+        Some(SuiExecutionStatus::Success),
+        http_client.get_transaction_execution_status(digest).await?
+    );
+    assert_eq!(
+        None,
+        http_client
+            .get_transaction_execution_status(TransactionDigest::random())
+            .await?
+    );
+
+    // Splitting more than the balance of a coin aborts
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas = objects.first().unwrap();
+    let coin = &objects[1];
+    let transaction_bytes: TransactionBytes = http_client
+        .move_call(
+            *address,
+            ObjectID::from(SUI_FRAMEWORK_ADDRESS),
+            "pay".to_string(),
+            "split".to_string(),
+            vec![GAS::type_tag().into()],
+            vec![
+                SuiJsonValue::from_object_id(coin.object_id),
+                SuiJsonValue::new(serde_json::json!(u64::MAX.to_string()))?,
+            ],
+            Some(gas.object_id),
+            10_000,
+            None,
+        )
+        .await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let response = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+    let status = http_client
+        .get_transaction_execution_status(response.effects.transaction_digest)
+        .await?;
+    assert!(status.as_ref().unwrap().is_err());
+    assert_eq!(Some(response.effects.status), status);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_checkpoint_by_transaction() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    // Wait for the transaction to be included in a checkpoint.
+    let mut checkpoint = None;
+    for _ in 0..100 {
+        checkpoint = http_client.get_transaction(digest).await?.checkpoint;
+        if checkpoint.is_some() {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    let sequence_number = checkpoint.expect("transaction should be checkpointed");
+
+    let checkpoint = http_client.get_checkpoint_by_transaction(digest).await?;
+    assert_eq!(sequence_number, checkpoint.sequence_number);
+    assert!(checkpoint.transactions.contains(&digest));
+
+    assert!(http_client
+        .get_checkpoint_by_transaction(TransactionDigest::random())
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_objects_owned_by_address_updated_since() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (first, _, receiver, first_digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    wait_for_checkpoint(&cluster, first_digest).await?;
+    let (second, _, _, second_digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let checkpoint = wait_for_checkpoint(&cluster, second_digest).await?;
+    let http_client = cluster.rpc_client();
+
+    let all = http_client.get_objects_owned_by_address(receiver).await?;
+    let ids = |objects: &[SuiObjectInfo]| {
+        objects
+            .iter()
+            .map(|object| object.object_id)
+            .collect::<Vec<_>>()
+    };
+    assert!(ids(&all).contains(&first));
+    assert!(ids(&all).contains(&second));
+
+    let updated = http_client
+        .get_objects_owned_by_address_updated_since(receiver, 0)
+        .await?;
+    assert_eq!(ids(&all), ids(&updated));
+
+    // Only the object received in the latest checkpoint changed since then
+    let updated = http_client
+        .get_objects_owned_by_address_updated_since(receiver, checkpoint)
+        .await?;
+    assert_eq!(vec![second], ids(&updated));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_objects_owned_by_address_updated_since_beyond_one_page(
+) -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts[0];
+    let object_count = split_into_coins(&cluster, address, 300).await?;
+    assert!(object_count > 256);
+
+    // Every object changed since the genesis, including those past the first page of the index
+    let updated = http_client
+        .get_objects_owned_by_address_updated_since(address, 0)
+        .await?;
+    assert_eq!(object_count, updated.len());
+    assert_eq!(
+        object_count,
+        updated
+            .iter()
+            .map(|object| object.object_id)
+            .collect::<BTreeSet<_>>()
+            .len()
+    );
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_objects_by_type() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (transferred, sender, receiver, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let coin_type = GasCoin::type_().to_string();
+    let mut ids = vec![];
+    let mut cursor = None;
+    loop {
+        let page = http_client
+            .get_objects_by_type(coin_type.clone(), cursor, Some(2))
+            .await?;
+        assert!(page.data.len() <= 2);
+        for object in &page.data {
+            assert_eq!(coin_type, object.type_);
+            ids.push(object.object_id);
+        }
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    // Pages are ordered by object id and don't overlap
+    let mut sorted = ids.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted, ids);
+
+    // Coins of every owner are included, including the one that just changed owner
+    assert!(ids.contains(&transferred));
+    for owner in [sender, receiver] {
+        for object in http_client.get_objects_owned_by_address(owner).await? {
+            if object.type_ == coin_type {
+                assert!(ids.contains(&object.object_id));
+            }
+        }
+    }
+
+    let page = http_client
+        .get_objects_by_type("0x2::coin::Coin<0x2::sui::NOT_SUI>".to_string(), None, None)
+        .await?;
+    assert!(page.data.is_empty());
+    assert!(page.next_cursor.is_none());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_owned_storage_rebate() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, sender, receiver, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    for owner in [sender, receiver] {
+        let mut expected = 0;
+        for info in http_client.get_objects_owned_by_address(owner).await? {
+            expected += http_client
+                .get_object(info.object_id)
+                .await?
+                .object()?
+                .storage_rebate;
+        }
+        assert_eq!(expected, http_client.get_owned_storage_rebate(owner).await?);
+    }
+    // The transfer mutated the sender's gas coin, which now holds a rebate
+    assert!(http_client.get_owned_storage_rebate(sender).await? > 0);
+
+    assert_eq!(
+        0,
+        http_client
+            .get_owned_storage_rebate(SuiAddress::random_for_testing_only())
+            .await?
+    );
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_owned_storage_rebate_beyond_one_page() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts[0];
+    let object_count = split_into_coins(&cluster, address, 300).await?;
+    assert!(object_count > 256);
+
+    // All objects of the address are coins, list them page by page
+    let mut object_ids = vec![];
+    let mut cursor = None;
+    loop {
+        let page = http_client
+            .get_all_coins(address, cursor, Some(100))
+            .await?;
+        object_ids.extend(page.data.iter().map(|coin| coin.coin_object_id));
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    assert_eq!(object_count, object_ids.len());
+
+    let mut expected = 0;
+    for response in http_client.multi_get_objects(object_ids).await? {
+        expected += response.object()?.storage_rebate;
+    }
+    assert_eq!(
+        expected,
+        http_client.get_owned_storage_rebate(address).await?
+    );
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_address_summary() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, sender, receiver, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    for owner in [sender, receiver] {
+        let summary = http_client.get_address_summary(owner).await?;
+        assert!(!summary.truncated);
+
+        let balance = http_client.get_balance(owner, None).await?;
+        assert_eq!(balance.total_balance, summary.sui_balance);
+
+        let coin_object_count: usize = http_client
+            .get_all_balances(owner)
+            .await?
+            .iter()
+            .map(|balance| balance.coin_object_count)
+            .sum();
+        assert_eq!(coin_object_count, summary.coin_object_count);
+
+        let staked_sui: u128 = http_client
+            .get_delegated_stakes(owner)
+            .await?
+            .iter()
+            .map(|stake| stake.staked_sui.principal() as u128)
+            .sum();
+        assert_eq!(staked_sui, summary.staked_sui);
+
+        let mut object_count_by_type = BTreeMap::new();
+        for info in http_client.get_objects_owned_by_address(owner).await? {
+            *object_count_by_type.entry(info.type_).or_default() += 1;
+        }
+        assert_eq!(object_count_by_type, summary.object_count_by_type);
+    }
+
+    let summary = http_client
+        .get_address_summary(SuiAddress::random_for_testing_only())
+        .await?;
+    assert_eq!(0, summary.sui_balance);
+    assert_eq!(0, summary.coin_object_count);
+    assert!(summary.object_count_by_type.is_empty());
+    assert!(!summary.truncated);
+    Ok(())
+}
+
+/// Wait for a transaction to be included in a checkpoint, returning the checkpoint.
+async fn wait_for_checkpoint(
+    cluster: &TestCluster,
+    digest: TransactionDigest,
+) -> Result<CheckpointSequenceNumber, anyhow::Error> {
+    for _ in 0..100 {
+        let checkpoint = cluster
+            .rpc_client()
+            .get_transaction(digest)
+            .await?
+            .checkpoint;
+        if let Some(checkpoint) = checkpoint {
+            return Ok(checkpoint);
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    Err(anyhow::anyhow!("transaction {digest} was not checkpointed"))
+}
+
+/// Split a gas coin of `address` into `count` coins, so that it owns more objects than a page of
+/// the owner index holds. Returns the number of objects the address owns afterwards.
+async fn split_into_coins(
+    cluster: &TestCluster,
+    address: SuiAddress,
+    count: u64,
+) -> Result<usize, anyhow::Error> {
+    let http_client = cluster.rpc_client();
+    let objects = http_client.get_objects_owned_by_address(address).await?;
+    let transaction_bytes: TransactionBytes = http_client
+        .split_coin_equal(
+            address,
+            objects[0].object_id,
+            count,
+            Some(objects[1].object_id),
+            1_000_000,
+        )
+        .await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx =
+        to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(&address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let response = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+    assert_eq!(SuiExecutionStatus::Success, response.effects.status);
+    Ok(objects.len() + response.effects.created.len())
+}
+
+#[sim_test]
+async fn test_get_shared_object_info() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let info = http_client
+        .get_shared_object_info(SUI_SYSTEM_STATE_OBJECT_ID)
+        .await?;
+    let object = http_client
+        .get_object(SUI_SYSTEM_STATE_OBJECT_ID)
+        .await?
+        .into_object()?;
+    let Owner::Shared { initial_shared_version } = object.owner else {
+        panic!("system state object should be shared");
+    };
+    assert_eq!(SUI_SYSTEM_STATE_OBJECT_ID, info.object_id);
+    assert_eq!(initial_shared_version, info.initial_shared_version);
+    assert_eq!(object.reference.version, info.version);
+    assert!(info.type_.ends_with("::sui_system::SuiSystemState"));
+
+    // An address owned object is rejected
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let error = http_client
+        .get_shared_object_info(objects[0].object_id)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("is not shared"));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_multi_get_objects_at_checkpoint() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (object_id, sender, receiver, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    // Wait for the transfer to be included in a checkpoint.
+    let mut checkpoint = None;
+    for _ in 0..100 {
+        checkpoint = http_client.get_transaction(digest).await?.checkpoint;
+        if checkpoint.is_some() {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    let checkpoint = checkpoint.expect("transaction should be checkpointed");
+    let current = http_client.get_object(object_id).await?.into_object()?;
+    let missing = ObjectID::random();
+
+    // At genesis the coin is still owned by the sender, the unknown object never existed
+    let snapshot = http_client
+        .multi_get_objects_at_checkpoint(vec![object_id, missing], 0)
+        .await?;
+    assert_eq!(2, snapshot.len());
+    let genesis_coin = snapshot[0].object()?;
+    assert_eq!(Owner::AddressOwner(sender), genesis_coin.owner);
+    assert!(genesis_coin.reference.version < current.reference.version);
+    assert!(matches!(snapshot[1], SuiPastObjectRead::ObjectNotExists(id) if id == missing));
+
+    // At the checkpoint of the transfer the coin matches its latest state
+    let snapshot = http_client
+        .multi_get_objects_at_checkpoint(vec![missing, object_id], checkpoint)
+        .await?;
+    assert!(matches!(snapshot[0], SuiPastObjectRead::ObjectNotExists(_)));
+    let coin = snapshot[1].object()?;
+    assert_eq!(Owner::AddressOwner(receiver), coin.owner);
+    assert_eq!(current.reference, coin.reference);
+
+    assert!(http_client
+        .multi_get_objects_at_checkpoint(vec![object_id], checkpoint + 1_000_000)
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_owned_object_refs_consistent() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (transferred, sender, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let checkpoint = wait_for_checkpoint(&cluster, digest).await?;
+    let http_client = cluster.rpc_client();
+    let object_ids = http_client
+        .get_objects_owned_by_address(sender)
+        .await?
+        .into_iter()
+        .map(|info| info.object_id)
+        .rev()
+        .collect::<Vec<_>>();
+
+    let refs = http_client
+        .get_owned_object_refs_consistent(sender, object_ids.clone())
+        .await?;
+    let latest = http_client.get_latest_checkpoint_sequence_number().await?;
+    assert!(latest >= checkpoint);
+    assert_eq!(object_ids.len(), refs.len());
+
+    // Nothing was executed since the transfer, so the references are the latest ones, and they
+    // all match the state of the objects at the latest checkpoint
+    let snapshot = http_client
+        .multi_get_objects_at_checkpoint(object_ids.clone(), latest)
+        .await?;
+    for ((object_id, reference), read) in object_ids.iter().zip(&refs).zip(&snapshot) {
+        assert_eq!(*object_id, reference.object_id);
+        assert_eq!(read.object()?.reference, *reference);
+        let object = http_client.get_object(*object_id).await?.into_object()?;
+        assert_eq!(object.reference, *reference);
+    }
+
+    // The transferred coin is no longer owned by the sender
+    let result = http_client
+        .get_owned_object_refs_consistent(sender, vec![object_ids[0], transferred])
+        .await;
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_genesis_checkpoint() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let checkpoint = http_client.get_genesis_checkpoint().await?;
+    assert_eq!(0, checkpoint.sequence_number);
+    assert_eq!(0, checkpoint.epoch);
+    assert!(checkpoint.previous_digest.is_none());
+
+    // The genesis gas objects of the test accounts are among the genesis objects.
+    let genesis_objects = http_client.get_genesis_objects().await?;
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    for object in objects {
+        assert!(genesis_objects
+            .iter()
+            .any(|genesis| genesis.reference.object_id == object.object_id));
+    }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_checkpoint_epoch_context() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new()
+        .with_epoch_duration_ms(1000)
+        .build()
+        .await?;
+    let mut epoch_rx = cluster.fullnode_handle.sui_node.subscribe_to_epoch_change();
+    timeout(Duration::from_secs(60), epoch_rx.recv()).await??;
+    let http_client = cluster.rpc_client();
+
+    // Walk epoch 0 until its final checkpoint
+    let mut last_of_epoch_0 = 0;
+    while !http_client
+        .get_checkpoint(CheckpointId::SequenceNumber(last_of_epoch_0))
+        .await?
+        .end_of_epoch
+    {
+        last_of_epoch_0 += 1;
+    }
+
+    // Epoch 0 is finished, so its checkpoints know how many it has
+    let checkpoint = http_client
+        .get_checkpoint(CheckpointId::SequenceNumber(last_of_epoch_0))
+        .await?;
+    assert_eq!(0, checkpoint.epoch);
+    assert_eq!(Some(0), checkpoint.epoch_first_checkpoint);
+    assert_eq!(Some(last_of_epoch_0), checkpoint.epoch_checkpoint_index);
+    assert_eq!(Some(last_of_epoch_0 + 1), checkpoint.epoch_checkpoint_count);
+
+    let by_digest = http_client
+        .get_checkpoint(CheckpointId::Digest(checkpoint.digest))
+        .await?;
+    assert_eq!(
+        checkpoint.epoch_checkpoint_count,
+        by_digest.epoch_checkpoint_count
+    );
+
+    // The next checkpoint starts epoch 1
+    let checkpoint = timeout(Duration::from_secs(60), async {
+        loop {
+            let checkpoint = http_client
+                .get_checkpoint(CheckpointId::SequenceNumber(last_of_epoch_0 + 1))
+                .await;
+            if let Ok(checkpoint) = checkpoint {
+                return checkpoint;
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await?;
+    assert_eq!(1, checkpoint.epoch);
+    assert_eq!(Some(last_of_epoch_0 + 1), checkpoint.epoch_first_checkpoint);
+    assert_eq!(Some(0), checkpoint.epoch_checkpoint_index);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_checkpoint_tips() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let checkpoint = wait_for_checkpoint(&cluster, digest).await?;
+    let http_client = cluster.rpc_client();
+
+    let tips = http_client.get_checkpoint_tips().await?;
+    assert!(tips.highest_executed >= checkpoint);
+    assert!(tips.highest_synced >= tips.highest_executed);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_by_checkpoint_index() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let checkpoint = wait_for_checkpoint(&cluster, digest).await?;
+    let http_client = cluster.rpc_client();
+
+    let contents = http_client.get_checkpoint_contents(checkpoint).await?;
+    let index = contents
+        .iter()
+        .position(|digests| digests.transaction == digest)
+        .unwrap();
+    let response = http_client
+        .get_transaction_by_checkpoint_index(checkpoint, index)
+        .await?;
+    assert_eq!(digest, response.effects.transaction_digest);
+    assert_eq!(Some(checkpoint), response.checkpoint);
+
+    let error = http_client
+        .get_transaction_by_checkpoint_index(checkpoint, contents.size())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("out of range"));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_checkpoint_transactions_for_address() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let (senders, recipient) = (&cluster.accounts[..2], cluster.accounts[2]);
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+
+    // Sign a transfer for each sender first and submit them together, so that they are likely
+    // to share a checkpoint. The first sender transfers to a third address.
+    let mut submissions = vec![];
+    for (i, address) in senders.iter().enumerate() {
+        let objects = http_client.get_objects_owned_by_address(*address).await?;
+        let to = if i == 0 { recipient } else { *address };
+        let transaction_bytes: TransactionBytes = http_client
+            .transfer_object(
+                *address,
+                objects[0].object_id,
+                Some(objects.last().unwrap().object_id),
+                1000,
+                to,
+            )
+            .await?;
+        let tx =
+            to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+        let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+        submissions.push(http_client.submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        ));
+    }
+    let mut digests = vec![];
+    for response in futures::future::join_all(submissions).await {
+        digests.push(response?.effects.transaction_digest);
+    }
+    let mut checkpoints = vec![];
+    for digest in &digests {
+        checkpoints.push(wait_for_checkpoint(&cluster, *digest).await?);
+    }
+
+    let checkpoint = checkpoints[0];
+    let contents = http_client.get_checkpoint_contents(checkpoint).await?;
+    let position = |digest: &TransactionDigest| {
+        contents
+            .iter()
+            .position(|digests| digests.transaction == *digest)
+    };
+    // The second sender's transaction is only returned if it landed in the same checkpoint
+    let expected = |address: SuiAddress| {
+        let mut expected: Vec<_> = digests
+            .iter()
+            .zip(&checkpoints)
+            .enumerate()
+            .filter(|(i, (_, cp))| {
+                **cp == checkpoint && (senders[*i] == address || (*i == 0 && address == recipient))
+            })
+            .map(|(_, (digest, _))| *digest)
+            .collect();
+        expected.sort_by_key(position);
+        expected
+    };
+
+    let summary = http_client.get_checkpoint_summary(checkpoint).await?;
+    for address in [senders[0], senders[1], recipient] {
+        for id in [
+            CheckpointId::SequenceNumber(checkpoint),
+            CheckpointId::Digest(summary.digest()),
+        ] {
+            let responses = http_client
+                .get_checkpoint_transactions_for_address(id, address)
+                .await?;
+            let returned: Vec<_> = responses
+                .iter()
+                .map(|response| response.effects.transaction_digest)
+                .collect();
+            assert_eq!(expected(address), returned);
+            assert!(responses
+                .iter()
+                .all(|response| response.checkpoint == Some(checkpoint)));
+        }
+    }
+    assert!(expected(senders[0]).contains(&digests[0]));
+
+    // An address without activity in the checkpoint gets no transactions
+    assert!(http_client
+        .get_checkpoint_transactions_for_address(
+            checkpoint.into(),
+            SuiAddress::random_for_testing_only()
+        )
+        .await?
+        .is_empty());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_ownership_proof() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (object_id, _, receiver, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let checkpoint = wait_for_checkpoint(&cluster, digest).await?;
+    let http_client = cluster.rpc_client();
+
+    let proof = http_client.get_object_ownership_proof(object_id).await?;
+    assert_eq!(Owner::AddressOwner(receiver), proof.owner);
+    assert_eq!(digest, proof.transaction_digest);
+
+    // Verify the proof against the summary of the checkpoint, as a light client would
+    let summary = http_client.get_checkpoint_summary(checkpoint).await?;
+    assert_eq!(summary, proof.checkpoint_summary);
+    assert_eq!(summary.content_digest, proof.checkpoint_contents.digest());
+    let effects: TransactionEffects = bcs::from_bytes(&proof.effects)?;
+    assert_eq!(digest, effects.transaction_digest);
+    let execution_digests = ExecutionDigests::new(digest, effects.digest());
+    assert!(proof
+        .checkpoint_contents
+        .iter()
+        .any(|digests| *digests == execution_digests));
+    assert!(effects.mutated.iter().any(|(object_ref, owner)| *object_ref
+        == proof.object_ref.to_object_ref()
+        && *owner == proof.owner));
+
+    // The proof covers the current state of the object
+    let object = http_client.get_object(object_id).await?;
+    assert_eq!(object.object()?.reference, proof.object_ref);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_export_checkpoints_bcs() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let checkpoint = wait_for_checkpoint(&cluster, digest).await?;
+    let http_client = cluster.rpc_client();
+
+    let start = checkpoint.saturating_sub(2);
+    let export = http_client
+        .export_checkpoints_bcs(start, checkpoint + 1)
+        .await?;
+    let checkpoints: Vec<(CheckpointSummary, CheckpointContents)> =
+        bcs::from_bytes(&export.to_vec().map_err(|e| anyhow::anyhow!(e))?)?;
+    assert_eq!(checkpoint + 1 - start, checkpoints.len() as u64);
+    for (sequence_number, (summary, contents)) in (start..).zip(checkpoints) {
+        assert_eq!(sequence_number, summary.sequence_number);
+        assert_eq!(
+            http_client.get_checkpoint_summary(sequence_number).await?,
+            summary
+        );
+        assert_eq!(
+            http_client.get_checkpoint_contents(sequence_number).await?,
+            contents
+        );
+    }
+
+    // Empty and too wide ranges are rejected
+    assert!(http_client.export_checkpoints_bcs(1, 1).await.is_err());
+    assert!(http_client
+        .export_checkpoints_bcs(0, MAX_CHECKPOINT_EXPORT_RANGE + 1)
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_checkpoint_signatures() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let checkpoint = http_client
+        .get_checkpoint(CheckpointId::SequenceNumber(0))
+        .await?;
+    let info = http_client
+        .get_checkpoint_signatures(CheckpointId::SequenceNumber(0))
+        .await?;
+    assert_eq!(0, info.sequence_number);
+    assert_eq!(checkpoint.epoch, info.epoch);
+    assert!(!info.signers.is_empty());
+    assert!(info.signers.iter().all(|(_, stake)| *stake > 0));
+    assert_eq!(
+        info.signers.iter().map(|(_, stake)| stake).sum::<u64>(),
+        info.signed_stake
+    );
+    assert!(info.signed_stake >= info.quorum_threshold);
+    assert!(info.signed_stake <= info.total_stake);
+
+    let by_digest = http_client
+        .get_checkpoint_signatures(CheckpointId::Digest(checkpoint.digest))
+        .await?;
+    assert_eq!(info, by_digest);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_verify_checkpoint_contents() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let summary = http_client.get_checkpoint_summary(0).await?;
+    let contents = http_client.get_checkpoint_contents(0).await?;
+    assert!(
+        http_client
+            .verify_checkpoint_contents(summary.clone(), contents)
+            .await?
+    );
+
+    let tampered =
+        CheckpointContents::new_with_causally_ordered_transactions(
+            vec![ExecutionDigests::random()],
+        );
+    assert!(
+        !http_client
+            .verify_checkpoint_contents(summary, tampered)
+            .await?
+    );
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_total_transaction_number_at_checkpoint() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    // Wait for at least two checkpoints to be available.
+    let mut latest = 0;
+    for _ in 0..100 {
+        latest = http_client.get_latest_checkpoint_sequence_number().await?;
+        if latest >= 1 {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert!(latest >= 1);
+
+    let previous_total = http_client
+        .get_total_transaction_number_at_checkpoint(Some(latest - 1))
+        .await?;
+    let total = http_client
+        .get_total_transaction_number_at_checkpoint(Some(latest))
+        .await?;
+    let checkpoint = http_client
+        .get_checkpoint(CheckpointId::SequenceNumber(latest))
+        .await?;
+    assert_eq!(previous_total + checkpoint.transactions.len() as u64, total);
+    assert_eq!(checkpoint.network_total_transactions, total);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_fullnode_transaction() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await.unwrap();
+
+    let context = &mut cluster.wallet;
+
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
+    let mut tx_responses: Vec<SuiTransactionResponse> = Vec::new();
+
+    let client = context.get_client().await.unwrap();
+
+    for address in cluster.accounts.iter() {
+        let objects = client
+            .read_api()
+            .get_objects_owned_by_address(*address)
+            .await
+            .unwrap();
+        let gas_id = objects.last().unwrap().object_id;
+
+        // Make some transactions
+        for oref in &objects[..objects.len() - 1] {
+            let data = client
+                .transaction_builder()
+                .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
+                .await?;
+            let tx = to_sender_signed_transaction(data, keystore.get_key(address).unwrap());
+
+            let response = client
+                .quorum_driver()
+                .execute_transaction(
+                    tx,
+                    Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+                )
+                .await
+                .unwrap();
+
+            tx_responses.push(response);
+        }
+    }
+
+    // test get_recent_transactions with smaller range
+    let tx = client
+        .read_api()
+        .get_transactions(TransactionQuery::All, None, Some(3), true)
+        .await
+        .unwrap();
+    assert_eq!(3, tx.data.len());
+
+    // test get all transactions paged
+    let first_page = client
+        .read_api()
+        .get_transactions(TransactionQuery::All, None, Some(5), false)
+        .await
+        .unwrap();
+    assert_eq!(5, first_page.data.len());
+    assert!(first_page.next_cursor.is_some());
+
+    // test get all transactions in ascending order
+    let second_page = client
+        .read_api()
+        .get_transactions(TransactionQuery::All, first_page.next_cursor, None, false)
+        .await
+        .unwrap();
+    assert_eq!(16, second_page.data.len());
+    assert!(second_page.next_cursor.is_none());
+
+    let mut all_txs_rev = first_page.data.clone();
+    all_txs_rev.extend(second_page.data);
+    all_txs_rev.reverse();
+
+    // test get 10 latest transactions paged
+    let latest = client
+        .read_api()
+        .get_transactions(TransactionQuery::All, None, Some(10), true)
+        .await
+        .unwrap();
+    assert_eq!(10, latest.data.len());
+
+    assert_eq!(Some(all_txs_rev[10]), latest.next_cursor);
+    assert_eq!(all_txs_rev[0..10], latest.data);
+
+    // test get from address txs in ascending order
+    let address_txs_asc = client
+        .read_api()
+        .get_transactions(
+            TransactionQuery::FromAddress(cluster.accounts[0]),
+            None,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(4, address_txs_asc.data.len());
+
+    // test get from address txs in descending order
+    let address_txs_desc = client
+        .read_api()
+        .get_transactions(
+            TransactionQuery::FromAddress(cluster.accounts[0]),
+            None,
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(4, address_txs_desc.data.len());
+
+    // test get from address txs in both ordering are the same.
+    let mut data_asc = address_txs_asc.data;
+    data_asc.reverse();
+    assert_eq!(data_asc, address_txs_desc.data);
+
+    // test get_recent_transactions
+    let tx = client
+        .read_api()
+        .get_transactions(TransactionQuery::All, None, Some(20), true)
+        .await
+        .unwrap();
+    assert_eq!(20, tx.data.len());
+
+    // test get_transaction
+    for tx_digest in tx.data {
+        let response: SuiTransactionResponse =
+            client.read_api().get_transaction(tx_digest).await.unwrap();
+        assert!(tx_responses
+            .iter()
+            .any(|resp| resp.effects.transaction_digest == response.effects.transaction_digest))
+    }
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_fullnode_events() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new()
+        .enable_fullnode_events()
+        .build()
+        .await
+        .unwrap();
+    let client = cluster.wallet.get_client().await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
+    let mut tx_responses: Vec<SuiTransactionResponse> = Vec::new();
+
+    for address in cluster.accounts.iter() {
+        let objects = client
+            .read_api()
+            .get_objects_owned_by_address(*address)
+            .await
+            .unwrap();
+        let gas_id = objects.last().unwrap().object_id;
+
+        // Make some transactions
+        for oref in &objects[..objects.len() - 1] {
+            let data = client
+                .transaction_builder()
+                .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
+                .await?;
+            let tx = to_sender_signed_transaction(data, keystore.get_key(address).unwrap());
+
+            let response = client
+                .quorum_driver()
+                .execute_transaction(
+                    tx,
+                    Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+                )
+                .await
+                .unwrap();
+            tx_responses.push(response);
+        }
+    }
+
+    // Add a delay to ensure event processing is done after transaction commits.
+    sleep(Duration::from_millis(100)).await;
+
+    // test get all events ascending
+    let page1 = client
+        .event_api()
+        .get_events(
+            EventQuery::All,
+            Some((tx_responses[2].effects.transaction_digest, 0).into()),
+            Some(3),
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(3, page1.data.len());
+    assert_eq!(
+        Some((tx_responses[5].effects.transaction_digest, 0).into()),
+        page1.next_cursor
+    );
+    let page2 = client
+        .event_api()
+        .get_events(
+            EventQuery::All,
+            Some((tx_responses[5].effects.transaction_digest, 0).into()),
+            Some(20),
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(15, page2.data.len());
+    assert_eq!(None, page2.next_cursor);
+
+    // test get all events descending
+    let page1 = client
+        .event_api()
+        .get_events(EventQuery::All, None, Some(3), true)
+        .await
+        .unwrap();
+    assert_eq!(3, page1.data.len());
+    assert_eq!(
+        Some((tx_responses[16].effects.transaction_digest, 0).into()),
+        page1.next_cursor
+    );
+
+    let page2 = client
+        .event_api()
+        .get_events(
+            EventQuery::All,
+            Some((tx_responses[16].effects.transaction_digest, 0).into()),
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+    // 17 events created by this test + 34 Genesis event
+    assert_eq!(51, page2.data.len());
+    assert_eq!(None, page2.next_cursor);
+
+    // test get sender events
+    let page = client
+        .event_api()
+        .get_events(
+            EventQuery::Sender(cluster.accounts[0]),
+            None,
+            Some(10),
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(4, page.data.len());
+
+    // test get recipient events
+    let page = client
+        .event_api()
+        .get_events(
+            EventQuery::Recipient(Owner::AddressOwner(cluster.accounts[1])),
+            None,
+            Some(10),
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(9, page.data.len());
+
+    let object = client
+        .read_api()
+        .get_objects_owned_by_address(cluster.accounts[2])
+        .await
+        .unwrap()
+        .last()
+        .unwrap()
+        .object_id;
+
+    // test get object events
+    let page = client
+        .event_api()
+        .get_events(EventQuery::Object(object), None, Some(10), false)
+        .await
+        .unwrap();
+    assert_eq!(5, page.data.len());
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_locked_sui() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    assert_eq!(5, objects.len());
+    // verify coins and balance before test
+    let coins: CoinPage = http_client
+        .get_coins(*address, None, None, None, None)
+        .await?;
+    let balance: Vec<Balance> = http_client.get_all_balances(*address).await?;
+
+    assert_eq!(5, coins.data.len());
+    for coin in &coins.data {
+        assert!(coin.locked_until_epoch.is_none());
+    }
+
+    assert_eq!(1, balance.len());
+    assert!(balance[0].locked_balance.is_empty());
+
+    // lock one coin
+    let transaction_bytes: TransactionBytes = http_client
+        .move_call(
+            *address,
+            SUI_FRAMEWORK_ADDRESS.into(),
+            LOCKED_COIN_MODULE_NAME.to_string(),
+            "lock_coin".to_string(),
+            vec![parse_sui_type_tag("0x2::sui::SUI")?.into()],
+            vec![
+                SuiJsonValue::from_str(&coins.data[0].coin_object_id.to_string())?,
+                SuiJsonValue::from_str(&format!("{address}"))?,
+                SuiJsonValue::from_bcs_bytes(&bcs::to_bytes(&"20")?)?,
+            ],
+            None,
+            1000,
+            None,
+        )
+        .await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    let balances: Vec<Balance> = http_client.get_all_balances(*address).await?;
+
+    assert_eq!(1, balance.len());
+
+    let balance = balances.first().unwrap();
+
+    assert_eq!(5, balance.coin_object_count);
+    assert_eq!(1, balance.locked_balance.len());
+    assert!(balance.locked_balance.contains_key(&20));
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_delegation() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects: Vec<SuiObjectInfo> = http_client.get_objects_owned_by_address(*address).await?;
+    assert_eq!(5, objects.len());
+
+    // Check StakedSui object before test
+    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
+    assert!(staked_sui.is_empty());
+
+    let validators: Vec<ValidatorMetadata> = http_client.get_validators().await?;
+
+    // Delegate some SUI
+    let transaction_bytes: TransactionBytes = http_client
+        .request_add_delegation(
+            *address,
+            vec![objects[0].object_id],
+            Some(1000000),
+            validators[0].sui_address,
+            None,
+            10000,
+        )
+        .await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    // Check DelegatedStake object
+    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
+    assert_eq!(1, staked_sui.len());
+    assert_eq!(1000000, staked_sui[0].staked_sui.principal());
+    assert!(staked_sui[0].staked_sui.sui_token_lock().is_none());
+    assert!(matches!(
+        staked_sui[0].delegation_status,
+        DelegationStatus::Pending
+    ));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_delegation_multiple_coins() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let coins: CoinPage = http_client
+        .get_coins(*address, None, None, None, None)
+        .await?;
+    assert_eq!(5, coins.data.len());
+
+    let genesis_coin_amount = coins.data[0].balance;
+
+    // Check StakedSui object before test
+    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
+    assert!(staked_sui.is_empty());
+
+    let validators: Vec<ValidatorMetadata> = http_client.get_validators().await?;
+
+    // Delegate some SUI
+    let transaction_bytes: TransactionBytes = http_client
+        .request_add_delegation(
+            *address,
+            vec![
+                coins.data[0].coin_object_id,
+                coins.data[1].coin_object_id,
+                coins.data[2].coin_object_id,
+            ],
+            Some(1000000),
+            validators[0].sui_address,
+            None,
+            10000,
+        )
+        .await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    // Check DelegatedStake object
+    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
+    assert_eq!(1, staked_sui.len());
+    assert_eq!(1000000, staked_sui[0].staked_sui.principal());
+    assert!(staked_sui[0].staked_sui.sui_token_lock().is_none());
+    assert!(matches!(
+        staked_sui[0].delegation_status,
+        DelegationStatus::Pending
+    ));
+
+    // Coins should be merged into one and returned to the sender.
+    let coins: CoinPage = http_client
+        .get_coins(*address, None, None, None, None)
+        .await?;
+    assert_eq!(3, coins.data.len());
+
+    // Find the new coin
+    let new_coin = coins
+        .data
+        .iter()
+        .find(|coin| coin.balance > genesis_coin_amount)
+        .unwrap();
+    assert_eq!((genesis_coin_amount * 3) - 1000000, new_coin.balance);
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_delegation_with_locked_sui() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects: Vec<SuiObjectInfo> = http_client.get_objects_owned_by_address(*address).await?;
+    assert_eq!(5, objects.len());
+
+    // lock some SUI
+    let transaction_bytes: TransactionBytes = http_client
+        .move_call(
+            *address,
+            SUI_FRAMEWORK_ADDRESS.into(),
+            LOCKED_COIN_MODULE_NAME.to_string(),
+            "lock_coin".to_string(),
+            vec![parse_sui_type_tag("0x2::sui::SUI")?.into()],
+            vec![
+                SuiJsonValue::from_str(&objects[0].object_id.to_string())?,
+                SuiJsonValue::from_str(&format!("{address}"))?,
+                SuiJsonValue::from_bcs_bytes(&bcs::to_bytes(&"20")?)?,
+            ],
+            None,
+            1000,
+            None,
+        )
+        .await?;
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    let validators: Vec<ValidatorMetadata> = http_client.get_validators().await?;
+
+    // Delegate some locked SUI
+    let coins: CoinPage = http_client
+        .get_coins(*address, None, None, None, None)
+        .await?;
+    let locked_sui = coins
+        .data
+        .iter()
+        .find_map(|coin| coin.locked_until_epoch.map(|_| coin.coin_object_id))
+        .unwrap();
+
+    let transaction_bytes: TransactionBytes = http_client
+        .request_add_delegation(
+            *address,
+            vec![locked_sui],
+            Some(1000000),
+            validators[0].sui_address,
+            None,
+            10000,
+        )
+        .await?;
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    // Check StakedSui object
+    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
+    assert_eq!(1, staked_sui.len());
+    assert_eq!(1000000, staked_sui[0].staked_sui.principal());
+    assert_eq!(Some(20), staked_sui[0].staked_sui.sui_token_lock());
+
+    assert!(matches!(
+        staked_sui[0].delegation_status,
+        DelegationStatus::Pending
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_checkpoint_contents_size_guard() {
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(
+        (0..100).map(|_| ExecutionDigests::random()),
+    );
+    assert!(check_checkpoint_contents_size(contents.clone(), 100).is_ok());
+    assert!(check_checkpoint_contents_size(contents, 99).is_err());
+}
+
+#[sim_test]
+async fn test_get_object_storage_history() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (object_id, _, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let history = http_client
+        .get_object_storage_history(object_id, None, None)
+        .await?;
+    assert!(history.data.len() >= 2);
+    assert!(history.next_cursor.is_none());
+    assert_eq!(digest, history.data[0].previous_transaction);
+    assert!(history
+        .data
+        .windows(2)
+        .all(|entries| entries[0].version > entries[1].version));
+
+    let page = http_client
+        .get_object_storage_history(object_id, None, Some(1))
+        .await?;
+    assert_eq!(history.data[..1], page.data[..]);
+    assert_eq!(Some(history.data[1].version), page.next_cursor);
+
+    let page = http_client
+        .get_object_storage_history(object_id, page.next_cursor, None)
+        .await?;
+    assert_eq!(history.data[1..], page.data[..]);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_pages_with_fabricated_cursor() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (object_id, sender, _, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+    let assert_cursor_not_found = |error: jsonrpsee::core::Error| {
+        assert!(
+            error.to_string().contains("was not found"),
+            "unexpected error: {error}"
+        )
+    };
+
+    assert_cursor_not_found(
+        http_client
+            .get_transactions(
+                TransactionQuery::All,
+                Some(TransactionDigest::random()),
+                None,
+                false,
+            )
+            .await
+            .unwrap_err(),
+    );
+    assert_cursor_not_found(
+        http_client
+            .get_events(
+                EventQuery::All,
+                Some(EventID {
+                    tx_digest: TransactionDigest::random(),
+                    event_seq: 0,
+                }),
+                None,
+                false,
+            )
+            .await
+            .unwrap_err(),
+    );
+    assert_cursor_not_found(
+        http_client
+            .get_dynamic_fields(object_id, Some(ObjectID::random()), None, None)
+            .await
+            .unwrap_err(),
+    );
+    assert_cursor_not_found(
+        http_client
+            .get_coins(sender, None, Some(ObjectID::random()), None, None)
+            .await
+            .unwrap_err(),
+    );
+    assert_cursor_not_found(
+        http_client
+            .get_object_storage_history(
+                object_id,
+                Some(SequenceNumber::from_u64(u64::MAX - 1)),
+                None,
+            )
+            .await
+            .unwrap_err(),
+    );
+    assert_cursor_not_found(
+        http_client
+            .get_checkpoint_digests_paged(CheckpointId::SequenceNumber(0), Some(usize::MAX), None)
+            .await
+            .unwrap_err(),
+    );
+
+    // Cursors returned by a previous page are still accepted
+    let page = http_client
+        .get_transactions(TransactionQuery::All, None, Some(1), false)
+        .await?;
+    assert!(http_client
+        .get_transactions(TransactionQuery::All, page.next_cursor, None, false)
+        .await
+        .is_ok());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_dynamic_field_object_parent_validation() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let object_id = objects.first().unwrap().object_id;
+    let name = DynamicFieldName {
+        type_: parse_sui_type_tag("0x2::object::ID")?,
+        value: SuiJsonValue::from_object_id(object_id).to_json_value(),
+    };
+
+    // A package cannot hold dynamic fields
+    let error = http_client
+        .get_dynamic_field_object(ObjectID::from(SUI_FRAMEWORK_ADDRESS), name.clone())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("cannot hold dynamic fields"));
+
+    // A missing parent is reported as such
+    let error = http_client
+        .get_dynamic_field_object(ObjectID::random(), name.clone())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("does not exist"));
+
+    // A valid container without the field reports a missing field
+    let error = http_client
+        .get_dynamic_field_object(object_id, name)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Cannot find dynamic field"));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_normalized_move_function_is_init() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas = objects.first().unwrap();
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+
+    let compiled_modules = BuildConfig::new_for_testing()
+        .build(Path::new("src/unit_tests/data/dummy_modules_publish").to_path_buf())?
+        .get_package_base64(/* with_unpublished_deps */ false);
+    let transaction_bytes: TransactionBytes = http_client
+        .publish(*address, compiled_modules, Some(gas.object_id), 10000)
+        .await?;
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let effects = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?
+        .effects;
+    let package_id = effects
+        .events
+        .iter()
+        .find_map(|e| {
+            if let SuiEvent::Publish { package_id, .. } = e {
+                Some(*package_id)
+            } else {
+                None
+            }
+        })
+        .unwrap();
+
+    let init = http_client
+        .get_normalized_move_function(
+            package_id,
+            "initializer".to_string(),
+            "init".to_string(),
+            None,
+        )
+        .await?;
+    assert!(init.is_init);
+    let version = http_client
+        .get_normalized_move_function(
+            package_id,
+            "initializer".to_string(),
+            "version".to_string(),
+            None,
+        )
+        .await?;
+    assert!(!version.is_init);
+
+    let module = http_client
+        .get_normalized_move_module(package_id, "initializer".to_string())
+        .await?;
+    assert!(module.exposed_functions["init"].is_init);
+    assert!(!module.exposed_functions["version"].is_init);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_dynamic_field_object_by_value() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let gas = objects.first().unwrap();
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+
+    let compiled_modules = BuildConfig::new_for_testing()
+        .build(Path::new("src/unit_tests/data/dummy_modules_publish").to_path_buf())?
+        .get_package_base64(/* with_unpublished_deps */ false);
+    let transaction_bytes: TransactionBytes = http_client
+        .publish(*address, compiled_modules, Some(gas.object_id), 10000)
+        .await?;
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let effects = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?
+        .effects;
+    let package_id = effects
+        .events
+        .iter()
+        .find_map(|e| {
+            if let SuiEvent::Publish { package_id, .. } = e {
+                Some(*package_id)
+            } else {
+                None
+            }
+        })
+        .unwrap();
+
+    // Create a table keyed by u64
+    let transaction_bytes: TransactionBytes = http_client
+        .move_call(
+            *address,
+            package_id,
+            "u64_table".to_string(),
+            "create".to_string(),
+            vec![],
+            vec![],
+            Some(gas.object_id),
+            10_000,
+            None,
+        )
+        .await?;
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let effects = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?
+        .effects;
+    assert_eq!(SuiExecutionStatus::Success, effects.status);
+    let table_id = effects
+        .created
+        .iter()
+        .find(|object| object.owner == Owner::AddressOwner(*address))
+        .unwrap()
+        .reference
+        .object_id;
+
+    let expected = http_client
+        .get_dynamic_field_object(
+            table_id,
+            DynamicFieldName {
+                type_: parse_sui_type_tag("u64")?,
+                value: serde_json::json!("2"),
+            },
+        )
+        .await?
+        .object()?
+        .id();
+
+    // A plain JSON number resolves to the u64 key, as does the indexed string form
+    for value in [serde_json::json!(2), serde_json::json!("2")] {
+        let object = http_client
+            .get_dynamic_field_object_by_value(table_id, SuiJsonValue::new(value)?)
+            .await?;
+        assert_eq!(expected, object.object()?.id());
+    }
+
+    let error = http_client
+        .get_dynamic_field_object_by_value(table_id, SuiJsonValue::new(serde_json::json!(7))?)
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("tried name types [u8, u16, u32, u64, u128, u256]"));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_multi_get_normalized_move_structs_by_type() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
+
+    let results = http_client
+        .multi_get_normalized_move_structs_by_type(vec![
+            "0x2::coin::Coin<0x2::sui::SUI>".to_string(),
+            "not a type".to_string(),
+            "0x2::coin::TreasuryCap".to_string(),
+            "0x2::coin::NoSuchStruct".to_string(),
+        ])
+        .await?;
+    assert_eq!(4, results.len());
+
+    let coin = http_client
+        .get_normalized_move_struct(framework, "coin".to_string(), "Coin".to_string())
+        .await?;
+    assert_eq!(SuiMoveNormalizedStructResult::Resolved(coin), results[0]);
+    let treasury_cap = http_client
+        .get_normalized_move_struct(framework, "coin".to_string(), "TreasuryCap".to_string())
+        .await?;
+    assert_eq!(
+        SuiMoveNormalizedStructResult::Resolved(treasury_cap),
+        results[2]
+    );
+    for result in [&results[1], &results[3]] {
+        assert!(matches!(
+            result,
+            SuiMoveNormalizedStructResult::Error { .. }
+        ));
+    }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_preload_packages() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let package = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
+
+    // A missing package is reported without failing the whole request
+    let missing = ObjectID::random();
+    let results = http_client.preload_packages(vec![missing, package]).await?;
+    assert_eq!(2, results.len());
+    assert!(matches!(
+        &results[0],
+        PackagePreloadResult::Error { error } if error.contains(&missing.to_string())
+    ));
+    assert_eq!(PackagePreloadResult::Cached, results[1]);
+
+    // Already cached packages are skipped
+    assert_eq!(
+        vec![PackagePreloadResult::AlreadyCached],
+        http_client.preload_packages(vec![package]).await?
+    );
+
+    // Requesting more packages than the limit is rejected
+    let too_many = vec![package; QUERY_MAX_RESULT_LIMIT + 1];
+    assert!(http_client.preload_packages(too_many).await.is_err());
+
+    let module = http_client
+        .get_normalized_move_module(package, COIN_MODULE_NAME.to_string())
+        .await?;
+    assert_eq!(COIN_MODULE_NAME.to_string(), module.name);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_clear_module_cache() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
+    let stdlib = ObjectID::from(MOVE_STDLIB_ADDRESS);
+    let framework_modules = http_client
+        .get_normalized_move_modules_by_package(framework)
+        .await?
+        .len();
+    let stdlib_modules = http_client
+        .get_normalized_move_modules_by_package(stdlib)
+        .await?
+        .len();
+
+    // Admin methods are not served by default
+    let error = http_client.clear_module_cache(None).await.unwrap_err();
+    assert!(error.to_string().contains("admin method"));
+
+    let mut read_api = ReadApi::new(cluster.fullnode_handle.sui_node.state());
+    read_api.enable_admin_methods = true;
+    assert_eq!(
+        vec![PackagePreloadResult::Cached; 2],
+        ReadApiServer::preload_packages(&read_api, vec![framework, stdlib]).await?
+    );
+
+    // Only the modules of the given package are evicted
+    let evicted = ReadApiServer::clear_module_cache(&read_api, Some(framework)).await?;
+    assert_eq!(framework_modules, evicted);
+    assert_eq!(
+        0,
+        ReadApiServer::clear_module_cache(&read_api, Some(framework)).await?
+    );
+
+    // The next request normalizes the package again
+    assert_eq!(
+        vec![PackagePreloadResult::Cached],
+        ReadApiServer::preload_packages(&read_api, vec![framework]).await?
+    );
 
+    let evicted = ReadApiServer::clear_module_cache(&read_api, None).await?;
+    assert_eq!(framework_modules + stdlib_modules, evicted);
+    assert_eq!(0, ReadApiServer::clear_module_cache(&read_api, None).await?);
     Ok(())
 }
 
 #[sim_test]
-async fn test_get_fullnode_events() -> Result<(), anyhow::Error> {
-    let cluster = TestClusterBuilder::new()
-        .enable_fullnode_events()
-        .build()
-        .await
+async fn test_get_latest_transaction_by_address() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    transfer_coin(&mut cluster.wallet).await?;
+    let (_, sender, receiver, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let response = http_client
+        .get_latest_transaction_by_address(sender)
+        .await?
         .unwrap();
-    let client = cluster.wallet.get_client().await?;
-    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
-    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
-    let mut tx_responses: Vec<SuiTransactionResponse> = Vec::new();
+    assert_eq!(digest, response.effects.transaction_digest);
 
-    for address in cluster.accounts.iter() {
-        let objects = client
-            .read_api()
-            .get_objects_owned_by_address(*address)
-            .await
-            .unwrap();
-        let gas_id = objects.last().unwrap().object_id;
+    let response = http_client
+        .get_latest_transaction_by_address(receiver)
+        .await?
+        .unwrap();
+    assert_eq!(digest, response.effects.transaction_digest);
 
-        // Make some transactions
-        for oref in &objects[..objects.len() - 1] {
-            let data = client
-                .transaction_builder()
-                .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
-                .await?;
-            let tx = to_sender_signed_transaction(data, keystore.get_key(address).unwrap());
+    assert!(http_client
+        .get_latest_transaction_by_address(SuiAddress::random_for_testing_only())
+        .await?
+        .is_none());
+    Ok(())
+}
 
-            let response = client
-                .quorum_driver()
-                .execute_transaction(
-                    tx,
-                    Some(ExecuteTransactionRequestType::WaitForLocalExecution),
-                )
-                .await
-                .unwrap();
-            tx_responses.push(response);
+struct GetObjectRateLimiter;
+
+impl RateLimiter for GetObjectRateLimiter {
+    fn check(&self, method: &str) -> Result<(), Duration> {
+        if method == "sui_getObject" {
+            Err(Duration::from_secs(2))
+        } else {
+            Ok(())
         }
     }
+}
 
-    // Add a delay to ensure event processing is done after transaction commits.
-    sleep(Duration::from_millis(100)).await;
+#[sim_test]
+async fn test_read_api_rate_limiter() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let address = cluster.accounts.first().unwrap();
+    let objects = cluster
+        .rpc_client()
+        .get_objects_owned_by_address(*address)
+        .await?;
+    let object_id = objects.first().unwrap().object_id;
 
-    // test get all events ascending
-    let page1 = client
-        .event_api()
-        .get_events(
-            EventQuery::All,
-            Some((tx_responses[2].effects.transaction_digest, 0).into()),
-            Some(3),
-            false,
-        )
+    let mut read_api = ReadApi::new(cluster.fullnode_handle.sui_node.state());
+    read_api.rate_limiter = Arc::new(GetObjectRateLimiter);
+
+    match ReadApiServer::get_object(&read_api, object_id).await {
+        Err(RpcError::Call(CallError::Custom(error))) => {
+            assert_eq!(RATE_LIMITED_CODE, error.code());
+            assert_eq!("2000", error.data().unwrap().get());
+        }
+        other => panic!("Expected a rate limited error, got {other:?}"),
+    }
+    // Other methods are not limited
+    assert!(ReadApiServer::get_raw_object(&read_api, object_id)
         .await
-        .unwrap();
-    assert_eq!(3, page1.data.len());
-    assert_eq!(
-        Some((tx_responses[5].effects.transaction_digest, 0).into()),
-        page1.next_cursor
-    );
-    let page2 = client
-        .event_api()
-        .get_events(
-            EventQuery::All,
-            Some((tx_responses[5].effects.transaction_digest, 0).into()),
-            Some(20),
-            false,
+        .is_ok());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_with_previous_transaction_summary() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (object_id, sender, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    let http_client = cluster.rpc_client();
+
+    let response = http_client
+        .get_object_with_options(
+            object_id,
+            Some(SuiObjectDataOptions::previous_transaction_summary()),
         )
-        .await
-        .unwrap();
-    assert_eq!(15, page2.data.len());
-    assert_eq!(None, page2.next_cursor);
+        .await?;
+    let summary = response.previous_transaction_summary.unwrap();
+    let transaction = http_client.get_transaction(digest).await?;
+    assert_eq!(digest, summary.digest);
+    assert_eq!(sender, summary.sender);
+    assert_eq!(transaction.transaction.data.sender, summary.sender);
+    assert_eq!(transaction.effects.status, summary.status);
+    assert_eq!(transaction.timestamp_ms, summary.timestamp_ms);
+
+    // Not included unless requested
+    let response = http_client.get_object_with_options(object_id, None).await?;
+    assert!(response.previous_transaction_summary.is_none());
+
+    // The framework package was created at genesis
+    let response = http_client
+        .get_object_with_options(
+            ObjectID::from(SUI_FRAMEWORK_ADDRESS),
+            Some(SuiObjectDataOptions::previous_transaction_summary()),
+        )
+        .await?;
+    assert!(response.previous_transaction_summary.is_none());
+    Ok(())
+}
 
-    // test get all events descending
-    let page1 = client
-        .event_api()
-        .get_events(EventQuery::All, None, Some(3), true)
-        .await
-        .unwrap();
-    assert_eq!(3, page1.data.len());
+#[sim_test]
+async fn test_get_checkpoint_digests_paged() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let summary = http_client.get_checkpoint_summary(0).await?;
+    let contents = http_client.get_checkpoint_contents(0).await?;
+    let expected = contents.iter().cloned().collect::<Vec<_>>();
+
+    // Walk the checkpoint one pair at a time.
+    let mut digests = vec![];
+    let mut cursor = None;
+    loop {
+        let page = http_client
+            .get_checkpoint_digests_paged(CheckpointId::SequenceNumber(0), cursor, Some(1))
+            .await?;
+        assert!(page.data.len() <= 1);
+        digests.extend(page.data);
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    assert_eq!(expected, digests);
+
+    let page = http_client
+        .get_checkpoint_digests_paged(CheckpointId::Digest(summary.digest()), None, None)
+        .await?;
+    assert_eq!(expected, page.data);
+    assert!(page.next_cursor.is_none());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_normalized_move_module_friends() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let module = http_client
+        .get_normalized_move_module(SUI_FRAMEWORK_ADDRESS.into(), "object".to_string())
+        .await?;
+    // `#[test_only]` friends are not part of the published module
+    let friends = module
+        .friends
+        .iter()
+        .map(|friend| {
+            assert_eq!(SUI_FRAMEWORK_ADDRESS.to_hex_literal(), friend.address);
+            friend.name.as_str()
+        })
+        .collect::<Vec<_>>();
     assert_eq!(
-        Some((tx_responses[16].effects.transaction_digest, 0).into()),
-        page1.next_cursor
+        vec![
+            "clock",
+            "dynamic_field",
+            "dynamic_object_field",
+            "sui_system",
+            "transfer"
+        ],
+        friends
     );
+    Ok(())
+}
 
-    let page2 = client
-        .event_api()
-        .get_events(
-            EventQuery::All,
-            Some((tx_responses[16].effects.transaction_digest, 0).into()),
-            None,
-            true,
-        )
-        .await
-        .unwrap();
-    // 17 events created by this test + 34 Genesis event
-    assert_eq!(51, page2.data.len());
-    assert_eq!(None, page2.next_cursor);
+#[sim_test]
+async fn test_get_normalized_move_function_calls() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
 
-    // test get sender events
-    let page = client
-        .event_api()
-        .get_events(
-            EventQuery::Sender(cluster.accounts[0]),
+    let function = http_client
+        .get_normalized_move_function(
+            framework,
+            "coin".to_string(),
+            "destroy_zero".to_string(),
             None,
-            Some(10),
-            false,
         )
-        .await
-        .unwrap();
-    assert_eq!(4, page.data.len());
+        .await?;
+    assert_eq!(None, function.calls);
+
+    // `coin::destroy_zero` calls `object::delete` and the generic `balance::destroy_zero`
+    let function = http_client
+        .get_normalized_move_function(
+            framework,
+            "coin".to_string(),
+            "destroy_zero".to_string(),
+            Some(true),
+        )
+        .await?;
+    let calls = function
+        .calls
+        .unwrap()
+        .into_iter()
+        .map(|callee| {
+            assert_eq!(SUI_FRAMEWORK_ADDRESS.to_hex_literal(), callee.address);
+            format!("{}::{}", callee.module, callee.function)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["object::delete", "balance::destroy_zero"], calls);
+    Ok(())
+}
 
-    // test get recipient events
-    let page = client
-        .event_api()
-        .get_events(
-            EventQuery::Recipient(Owner::AddressOwner(cluster.accounts[1])),
+#[sim_test]
+async fn test_get_normalized_move_function_complexity_hint() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
+    let get_function = |module: &str, function: &str| {
+        http_client.get_normalized_move_function(
+            framework,
+            module.to_string(),
+            function.to_string(),
             None,
-            Some(10),
-            false,
         )
-        .await
-        .unwrap();
-    assert_eq!(9, page.data.len());
-
-    let object = client
-        .read_api()
-        .get_objects_owned_by_address(cluster.accounts[2])
-        .await
-        .unwrap()
-        .last()
-        .unwrap()
-        .object_id;
+    };
 
-    // test get object events
-    let page = client
-        .event_api()
-        .get_events(EventQuery::Object(object), None, Some(10), false)
-        .await
-        .unwrap();
-    assert_eq!(5, page.data.len());
+    // A getter that reads a single field
+    let getter = get_function("balance", "value").await?.complexity_hint;
+    assert_eq!(Some(SuiMoveFunctionComplexity::Low), getter);
 
+    // A loop that calls `coin::join` for every coin in a vector
+    let looping = get_function("pay", "join_vec").await?.complexity_hint;
+    assert_eq!(Some(SuiMoveFunctionComplexity::High), looping);
+    assert!(getter < looping);
     Ok(())
 }
 
 #[sim_test]
-async fn test_locked_sui() -> Result<(), anyhow::Error> {
+async fn test_get_normalized_move_module_constants() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
+
+    // coin::ENotEnough
+    let coin = http_client
+        .get_normalized_move_module(framework, COIN_MODULE_NAME.to_string())
+        .await?;
+    assert!(coin.constants.iter().any(|constant| {
+        constant.type_ == SuiMoveNormalizedType::U64
+            && constant.value == Some(SuiMoveValue::String("2".to_string()))
+            && constant.bytes == 2u64.to_le_bytes()
+    }));
+
+    // validator::PROOF_OF_POSSESSION_DOMAIN
+    let validator = http_client
+        .get_normalized_move_module(framework, "validator".to_string())
+        .await?;
+    let domain = [107u8, 111, 115, 107];
+    assert!(validator.constants.iter().any(|constant| {
+        constant.type_ == SuiMoveNormalizedType::Vector(Box::new(SuiMoveNormalizedType::U8))
+            && constant.value
+                == Some(SuiMoveValue::Vector(
+                    domain
+                        .iter()
+                        .map(|byte| SuiMoveValue::Number(*byte as u64))
+                        .collect(),
+                ))
+    }));
+    Ok(())
+}
 
+#[sim_test]
+async fn test_get_normalized_move_struct_fixed_size() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
     let http_client = cluster.rpc_client();
-    let address = cluster.accounts.first().unwrap();
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
-    assert_eq!(5, objects.len());
-    // verify coins and balance before test
-    let coins: CoinPage = http_client.get_coins(*address, None, None, None).await?;
-    let balance: Vec<Balance> = http_client.get_all_balances(*address).await?;
+    // A single u64 field
+    let balance = http_client
+        .get_normalized_move_struct(framework, "balance".to_string(), "Balance".to_string())
+        .await?;
+    assert_eq!(Some(8), balance.fixed_size);
 
-    assert_eq!(5, coins.data.len());
-    for coin in &coins.data {
-        assert!(coin.locked_until_epoch.is_none());
+    // UID and Balance<T> are resolved through their modules
+    let coin = http_client
+        .get_normalized_move_struct(framework, "coin".to_string(), "Coin".to_string())
+        .await?;
+    assert_eq!(Some(SUI_ADDRESS_LENGTH + 8), coin.fixed_size);
+
+    // Url holds an ascii string, which is a vector
+    let url = http_client
+        .get_normalized_move_struct(framework, "url".to_string(), "Url".to_string())
+        .await?;
+    assert_eq!(None, url.fixed_size);
+
+    let module = http_client
+        .get_normalized_move_module(framework, "balance".to_string())
+        .await?;
+    assert_eq!(Some(8), module.structs["Supply"].fixed_size);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_stream_transactions() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, sender, _, _, _, _) = transfer_coin(&mut cluster.wallet).await?;
+    for _ in 0..2 {
+        transfer_coin(&mut cluster.wallet).await?;
     }
+    let http_client = cluster.rpc_client();
+    let ws_client = &cluster.fullnode_handle.ws_client;
 
-    assert_eq!(1, balance.len());
-    assert!(balance[0].locked_balance.is_empty());
+    let expected = http_client
+        .get_transactions(TransactionQuery::All, None, None, false)
+        .await?
+        .data;
 
-    // lock one coin
-    let transaction_bytes: TransactionBytes = http_client
-        .move_call(
-            *address,
-            SUI_FRAMEWORK_ADDRESS.into(),
-            LOCKED_COIN_MODULE_NAME.to_string(),
-            "lock_coin".to_string(),
-            vec![parse_sui_type_tag("0x2::sui::SUI")?.into()],
-            vec![
-                SuiJsonValue::from_str(&coins.data[0].coin_object_id.to_string())?,
-                SuiJsonValue::from_str(&format!("{address}"))?,
-                SuiJsonValue::from_bcs_bytes(&bcs::to_bytes(&"20")?)?,
-            ],
-            None,
-            1000,
-            None,
+    // The subscription closes once every matching transaction has been pushed
+    let mut subscription = ws_client
+        .stream_transactions(TransactionQuery::All, None)
+        .await?;
+    let mut streamed = vec![];
+    while let Some(batch) = timeout(Duration::from_secs(10), subscription.next()).await? {
+        streamed.extend(batch?.into_iter().map(|tx| tx.effects.transaction_digest));
+    }
+    assert_eq!(expected, streamed);
+
+    let from_address = http_client
+        .get_transactions(TransactionQuery::FromAddress(sender), None, None, false)
+        .await?
+        .data;
+    let mut subscription = ws_client
+        .stream_transactions(
+            TransactionQuery::FromAddress(sender),
+            from_address.get(1).cloned(),
         )
         .await?;
-    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
-    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
-    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let mut streamed = vec![];
+    while let Some(batch) = timeout(Duration::from_secs(10), subscription.next()).await? {
+        streamed.extend(batch?.into_iter().map(|tx| tx.effects.transaction_digest));
+    }
+    assert_eq!(from_address[1..], streamed);
+    Ok(())
+}
 
-    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+#[sim_test]
+async fn test_get_transactions_from_address_in_time_range() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let mut digests = vec![];
+    for _ in 0..3 {
+        let (_, sender, _, digest, _, _) = transfer_coin(&mut cluster.wallet).await?;
+        digests.push((sender, digest));
+        sleep(Duration::from_millis(100)).await;
+    }
+    let sender = digests[0].0;
+    let digests = digests
+        .into_iter()
+        .map(|(_, digest)| digest)
+        .collect::<Vec<_>>();
+    let http_client = cluster.rpc_client();
 
-    http_client
-        .submit_transaction(
-            tx_bytes,
-            signatures,
-            ExecuteTransactionRequestType::WaitForLocalExecution,
-        )
+    let mut timestamps = vec![];
+    for digest in &digests {
+        let response = http_client.get_transaction(*digest).await?;
+        timestamps.push(response.timestamp_ms.unwrap());
+    }
+    assert!(timestamps.windows(2).all(|ts| ts[0] < ts[1]));
+
+    let query = |start_time, end_time| TransactionQuery::FromAddressInTimeRange {
+        address: sender,
+        start_time,
+        end_time,
+    };
+    for descending in [false, true] {
+        let page = http_client
+            .get_transactions(query(timestamps[1], timestamps[2]), None, None, descending)
+            .await?;
+        assert_eq!(digests[1..2], page.data[..]);
+    }
+    let page = http_client
+        .get_transactions(query(timestamps[0], timestamps[2] + 1), None, None, false)
         .await?;
+    assert_eq!(digests, page.data);
+    Ok(())
+}
 
-    let balances: Vec<Balance> = http_client.get_all_balances(*address).await?;
+#[sim_test]
+async fn test_get_transactions_from_any_of() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let senders = cluster.accounts[..3].to_vec();
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
 
-    assert_eq!(1, balance.len());
+    // Interleave the transactions of the three senders
+    let mut digests = vec![];
+    for round in 0..2 {
+        for address in &senders {
+            let objects = http_client.get_objects_owned_by_address(*address).await?;
+            let gas_id = objects.last().unwrap().object_id;
+            let transaction_bytes: TransactionBytes = http_client
+                .transfer_object(
+                    *address,
+                    objects[round].object_id,
+                    Some(gas_id),
+                    1000,
+                    *address,
+                )
+                .await?;
+            let tx = to_sender_signed_transaction(
+                transaction_bytes.to_data()?,
+                keystore.get_key(address)?,
+            );
+            let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+            let response = http_client
+                .submit_transaction(
+                    tx_bytes,
+                    signatures,
+                    ExecuteTransactionRequestType::WaitForLocalExecution,
+                )
+                .await?;
+            digests.push(response.effects.transaction_digest);
+        }
+    }
 
-    let balance = balances.first().unwrap();
+    let query = TransactionQuery::FromAnyOf(senders.clone());
+    let page = http_client
+        .get_transactions(query.clone(), None, None, false)
+        .await?;
+    assert_eq!(digests, page.data);
+    assert!(page.next_cursor.is_none());
 
-    assert_eq!(5, balance.coin_object_count);
-    assert_eq!(1, balance.locked_balance.len());
-    assert!(balance.locked_balance.contains_key(&20));
+    let page = http_client
+        .get_transactions(query.clone(), None, None, true)
+        .await?;
+    let mut reversed = digests.clone();
+    reversed.reverse();
+    assert_eq!(reversed, page.data);
+
+    // The cursor carries over between the senders
+    let mut paged = vec![];
+    let mut cursor = None;
+    loop {
+        let page = http_client
+            .get_transactions(query.clone(), cursor, Some(2), false)
+            .await?;
+        paged.extend(page.data);
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    assert_eq!(digests, paged);
 
+    let query = TransactionQuery::FromAnyOf(vec![senders[0]; MAX_TRANSACTION_QUERY_ADDRESSES + 1]);
+    assert!(http_client
+        .get_transactions(query, None, None, false)
+        .await
+        .is_err());
     Ok(())
 }
 
 #[sim_test]
-async fn test_delegation() -> Result<(), anyhow::Error> {
+async fn test_subscribe_address_objects() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
-
     let http_client = cluster.rpc_client();
-    let address = cluster.accounts.first().unwrap();
-
-    let objects: Vec<SuiObjectInfo> = http_client.get_objects_owned_by_address(*address).await?;
-    assert_eq!(5, objects.len());
-
-    // Check StakedSui object before test
-    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
-    assert!(staked_sui.is_empty());
+    let ws_client = &cluster.fullnode_handle.ws_client;
+    let address = cluster.accounts[0];
+    let recipient = cluster.accounts[1];
 
-    let validators: Vec<ValidatorMetadata> = http_client.get_validators().await?;
+    let mut subscription = ws_client.subscribe_address_objects(address).await?;
 
-    // Delegate some SUI
+    // The owned objects are reported first
+    let mut snapshot = vec![];
+    loop {
+        match timeout(Duration::from_secs(10), subscription.next()).await? {
+            Some(Ok(ObjectOwnershipChange::Gained {
+                object_id,
+                tx_digest: None,
+                ..
+            })) => snapshot.push(object_id),
+            Some(Ok(ObjectOwnershipChange::SnapshotComplete)) => break,
+            other => panic!("Unexpected snapshot item: {:?}", other),
+        }
+    }
+    let mut owned = http_client
+        .get_objects_owned_by_address(address)
+        .await?
+        .into_iter()
+        .map(|info| info.object_id)
+        .collect::<Vec<_>>();
+    snapshot.sort();
+    owned.sort();
+    assert_eq!(owned, snapshot);
+
+    let object_id = owned[0];
     let transaction_bytes: TransactionBytes = http_client
-        .request_add_delegation(
-            *address,
-            vec![objects[0].object_id],
-            Some(1000000),
-            validators[0].sui_address,
-            None,
-            10000,
-        )
+        .transfer_object(address, object_id, Some(owned[1]), 1000, recipient)
         .await?;
     let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
     let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
-    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
-
+    let tx =
+        to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(&address)?);
     let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
-
-    http_client
+    let response = http_client
         .submit_transaction(
             tx_bytes,
             signatures,
@@ -1006,57 +4289,103 @@ async fn test_delegation() -> Result<(), anyhow::Error> {
         )
         .await?;
 
-    // Check DelegatedStake object
-    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
-    assert_eq!(1, staked_sui.len());
-    assert_eq!(1000000, staked_sui[0].staked_sui.principal());
-    assert!(staked_sui[0].staked_sui.sui_token_lock().is_none());
-    assert!(matches!(
-        staked_sui[0].delegation_status,
-        DelegationStatus::Pending
-    ));
+    // The gas object stays with the sender, so the transferred object is the only change
+    match timeout(Duration::from_secs(10), subscription.next()).await? {
+        Some(Ok(ObjectOwnershipChange::Lost {
+            object_id: lost,
+            tx_digest,
+            ..
+        })) => {
+            assert_eq!(object_id, lost);
+            assert_eq!(response.effects.transaction_digest, tx_digest);
+        }
+        other => panic!("Expected the transferred object to be lost: {:?}", other),
+    }
     Ok(())
 }
 
 #[sim_test]
-async fn test_delegation_multiple_coins() -> Result<(), anyhow::Error> {
+async fn test_subscribe_address_objects_snapshot_pages() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
+    let ws_client = &cluster.fullnode_handle.ws_client;
+    let address = cluster.accounts[0];
+    let object_count = split_into_coins(&cluster, address, 300).await?;
+    assert!(object_count > 256);
+
+    // The snapshot reads past the first page of the owner index before it is complete
+    let mut subscription = ws_client.subscribe_address_objects(address).await?;
+    let mut snapshot = BTreeSet::new();
+    loop {
+        match timeout(Duration::from_secs(10), subscription.next()).await? {
+            Some(Ok(ObjectOwnershipChange::Gained {
+                object_id,
+                tx_digest: None,
+                ..
+            })) => assert!(snapshot.insert(object_id)),
+            Some(Ok(ObjectOwnershipChange::SnapshotComplete)) => break,
+            other => panic!("Unexpected snapshot item: {:?}", other),
+        }
+    }
+    assert_eq!(object_count, snapshot.len());
+    Ok(())
+}
 
+#[sim_test]
+async fn test_get_transaction_by_effects_digest() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
     let http_client = cluster.rpc_client();
-    let address = cluster.accounts.first().unwrap();
-
-    let coins: CoinPage = http_client.get_coins(*address, None, None, None).await?;
-    assert_eq!(5, coins.data.len());
 
-    let genesis_coin_amount = coins.data[0].balance;
+    let contents = http_client.get_checkpoint_contents(0).await?;
+    for digests in contents.iter() {
+        let response = http_client
+            .get_transaction_by_effects_digest(digests.effects)
+            .await?;
+        assert_eq!(digests.transaction, response.effects.transaction_digest);
+        assert_eq!(
+            http_client
+                .get_transaction(digests.transaction)
+                .await?
+                .effects,
+            response.effects
+        );
+    }
 
-    // Check StakedSui object before test
-    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
-    assert!(staked_sui.is_empty());
+    let error = http_client
+        .get_transaction_by_effects_digest(TransactionEffectsDigest::random())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains(TRANSACTION_NOT_FOUND_MSG_PREFIX));
+    Ok(())
+}
 
-    let validators: Vec<ValidatorMetadata> = http_client.get_validators().await?;
+#[sim_test]
+async fn test_get_transaction_status_by_bytes() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
 
-    // Delegate some SUI
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
     let transaction_bytes: TransactionBytes = http_client
-        .request_add_delegation(
+        .transfer_object(
+            *address,
+            objects.first().unwrap().object_id,
+            Some(objects.last().unwrap().object_id),
+            1000,
             *address,
-            vec![
-                coins.data[0].coin_object_id,
-                coins.data[1].coin_object_id,
-                coins.data[2].coin_object_id,
-            ],
-            Some(1000000),
-            validators[0].sui_address,
-            None,
-            10000,
         )
         .await?;
+
     let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
     let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
     let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
-
+    let digest = *tx.digest();
     let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
 
+    let status = http_client
+        .get_transaction_status_by_bytes(transaction_bytes.tx_bytes.clone())
+        .await?;
+    assert_eq!(SuiTransactionStatus::NotExecuted { digest }, status);
+
     http_client
         .submit_transaction(
             tx_bytes,
@@ -1065,114 +4394,271 @@ async fn test_delegation_multiple_coins() -> Result<(), anyhow::Error> {
         )
         .await?;
 
-    // Check DelegatedStake object
-    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
-    assert_eq!(1, staked_sui.len());
-    assert_eq!(1000000, staked_sui[0].staked_sui.principal());
-    assert!(staked_sui[0].staked_sui.sui_token_lock().is_none());
-    assert!(matches!(
-        staked_sui[0].delegation_status,
-        DelegationStatus::Pending
-    ));
-
-    // Coins should be merged into one and returned to the sender.
-    let coins: CoinPage = http_client.get_coins(*address, None, None, None).await?;
-    assert_eq!(3, coins.data.len());
-
-    // Find the new coin
-    let new_coin = coins
-        .data
-        .iter()
-        .find(|coin| coin.balance > genesis_coin_amount)
-        .unwrap();
-    assert_eq!((genesis_coin_amount * 3) - 1000000, new_coin.balance);
+    let status = http_client
+        .get_transaction_status_by_bytes(transaction_bytes.tx_bytes)
+        .await?;
+    assert!(
+        matches!(status, SuiTransactionStatus::Executed { digest: executed, .. } if executed == digest)
+    );
 
+    assert!(http_client
+        .get_transaction_status_by_bytes(Base64::from_bytes(&[1, 2, 3]))
+        .await
+        .is_err());
     Ok(())
 }
 
 #[sim_test]
-async fn test_delegation_with_locked_sui() -> Result<(), anyhow::Error> {
+async fn test_get_transaction_intent_info() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
-
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects: Vec<SuiObjectInfo> = http_client.get_objects_owned_by_address(*address).await?;
-    assert_eq!(5, objects.len());
-
-    // lock some SUI
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
     let transaction_bytes: TransactionBytes = http_client
-        .move_call(
+        .transfer_object(
             *address,
-            SUI_FRAMEWORK_ADDRESS.into(),
-            LOCKED_COIN_MODULE_NAME.to_string(),
-            "lock_coin".to_string(),
-            vec![parse_sui_type_tag("0x2::sui::SUI")?.into()],
-            vec![
-                SuiJsonValue::from_str(&objects[0].object_id.to_string())?,
-                SuiJsonValue::from_str(&format!("{address}"))?,
-                SuiJsonValue::from_bcs_bytes(&bcs::to_bytes(&"20")?)?,
-            ],
-            None,
+            objects.first().unwrap().object_id,
+            Some(objects.last().unwrap().object_id),
             1000,
-            None,
+            *address,
         )
         .await?;
-    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
-    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
-    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
-
-    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
 
-    http_client
-        .submit_transaction(
-            tx_bytes,
-            signatures,
-            ExecuteTransactionRequestType::WaitForLocalExecution,
-        )
+    let info = http_client
+        .get_transaction_intent_info(transaction_bytes.tx_bytes.clone())
         .await?;
+    assert_eq!("TransactionData", info.scope);
+    assert_eq!(0, info.version);
+    assert_eq!("Sui", info.app_id);
+    assert_eq!(
+        vec![0, 0, 0],
+        info.intent_bytes.to_vec().map_err(|e| anyhow::anyhow!(e))?
+    );
 
-    let validators: Vec<ValidatorMetadata> = http_client.get_validators().await?;
+    let (_, digest) = get_transaction_data_and_digest(transaction_bytes.tx_bytes)?;
+    assert_eq!(digest, info.digest);
 
-    // Delegate some locked SUI
-    let coins: CoinPage = http_client.get_coins(*address, None, None, None).await?;
-    let locked_sui = coins
-        .data
-        .iter()
-        .find_map(|coin| coin.locked_until_epoch.map(|_| coin.coin_object_id))
-        .unwrap();
+    assert!(http_client
+        .get_transaction_intent_info(Base64::from_bytes(&[1, 2, 3]))
+        .await
+        .is_err());
+    Ok(())
+}
 
-    let transaction_bytes: TransactionBytes = http_client
-        .request_add_delegation(
-            *address,
-            vec![locked_sui],
-            Some(1000000),
-            validators[0].sui_address,
-            None,
-            10000,
-        )
+#[sim_test]
+async fn test_dropped_read_stops_work() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
+    let stdlib = ObjectID::from(MOVE_STDLIB_ADDRESS);
+    let mut module_count = 0;
+    for package in [framework, stdlib] {
+        module_count += cluster
+            .rpc_client()
+            .get_normalized_move_modules_by_package(package)
+            .await?
+            .len();
+    }
+
+    // Work handed to a blocking thread checks the token, and stops before the first module once
+    // the token is cancelled.
+    let read_api = ReadApi::new(cluster.fullnode_handle.sui_node.state());
+    let (cancel, cancel_on_drop) = request_cancellation();
+    drop(cancel_on_drop);
+    assert!(cancel.is_cancelled());
+    assert!(preload_package(&read_api, framework, &cancel)
+        .await
+        .is_err());
+    assert_eq!(0, read_api.completed_read_units());
+    assert_eq!(0, read_api.cached_module_count());
+
+    // Dropping a request mid-flight cancels its token, so the blocking work it started stops too.
+    let read_api = ReadApi::new(cluster.fullnode_handle.sui_node.state());
+    let mut read = Box::pin(ReadApiServer::preload_packages(
+        &read_api,
+        vec![framework, stdlib],
+    ));
+    assert!(futures::poll!(&mut read).is_pending());
+    drop(read);
+    sleep(Duration::from_millis(500)).await;
+    let completed = read_api.completed_read_units();
+    assert!(completed < module_count);
+    assert_eq!(completed, read_api.cached_module_count());
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(completed, read_api.completed_read_units());
+
+    // A request that runs to the end does a unit of work per module and per package.
+    let read_api = ReadApi::new(cluster.fullnode_handle.sui_node.state());
+    assert_eq!(
+        vec![PackagePreloadResult::Cached; 2],
+        ReadApiServer::preload_packages(&read_api, vec![framework, stdlib]).await?
+    );
+    assert_eq!(module_count, read_api.cached_module_count());
+    assert_eq!(module_count + 2, read_api.completed_read_units());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_immutable_object_cache() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let address = cluster.accounts.first().unwrap();
+    let owned = cluster
+        .rpc_client()
+        .get_objects_owned_by_address(*address)
+        .await?
+        .first()
+        .unwrap()
+        .object_id;
+    let framework = ObjectID::from(SUI_FRAMEWORK_ADDRESS);
+    let stdlib = ObjectID::from(MOVE_STDLIB_ADDRESS);
+
+    let mut read_api = ReadApi::new(cluster.fullnode_handle.sui_node.state());
+    read_api.max_immutable_object_cache_size = 1;
+
+    // Owned objects can change and are not cached
+    ReadApiServer::get_object(&read_api, owned).await?;
+    assert_eq!(0, read_api.cached_immutable_object_count());
+
+    let first = ReadApiServer::get_object(&read_api, framework).await?;
+    assert_eq!(1, read_api.cached_immutable_object_count());
+    let second = ReadApiServer::get_object(&read_api, framework).await?;
+    assert_eq!(first.object()?, second.object()?);
+    assert_eq!(1, read_api.cached_immutable_object_count());
+
+    // Once the cache is full, further immutable objects are still read from the store
+    ReadApiServer::get_object(&read_api, stdlib)
+        .await?
+        .object()?;
+    assert_eq!(1, read_api.cached_immutable_object_count());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_layout_cache() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let address = cluster.accounts.first().unwrap();
+    let coins: Vec<_> = cluster
+        .rpc_client()
+        .get_objects_owned_by_address(*address)
+        .await?
+        .into_iter()
+        .map(|info| info.object_id)
+        .collect();
+    let state = cluster.fullnode_handle.sui_node.state();
+
+    // Objects of the same type share a single cached layout
+    let read_api = ReadApi::new(state.clone());
+    let mut cached = vec![];
+    for coin in &coins[..2] {
+        cached.push(
+            ReadApiServer::get_object(&read_api, *coin)
+                .await?
+                .object()?
+                .clone(),
+        );
+    }
+    assert_eq!(1, read_api.cached_layout_count());
+
+    // Layouts built without the cache parse the objects the same way
+    let mut uncached_api = ReadApi::new(state);
+    uncached_api.max_layout_cache_size = 0;
+    for (coin, cached) in coins[..2].iter().zip(cached) {
+        let uncached = ReadApiServer::get_object(&uncached_api, *coin).await?;
+        assert_eq!(&cached, uncached.object()?);
+    }
+    assert_eq!(0, uncached_api.cached_layout_count());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_object_with_fields() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let object_id = SUI_SYSTEM_STATE_OBJECT_ID;
+
+    let response = http_client
+        .get_object_with_fields(object_id, Some(1))
         .await?;
-    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
-    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let object = http_client.get_object(object_id).await?;
+    let fields = http_client
+        .get_dynamic_fields(object_id, None, Some(1), None)
+        .await?;
+    assert_eq!(
+        object.object()?.reference,
+        response.object.object()?.reference
+    );
+    assert_eq!(
+        fields
+            .data
+            .iter()
+            .map(|field| field.object_id)
+            .collect::<Vec<_>>(),
+        response
+            .dynamic_fields
+            .data
+            .iter()
+            .map(|field| field.object_id)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(fields.next_cursor, response.dynamic_fields.next_cursor);
+    Ok(())
+}
 
-    http_client
-        .submit_transaction(
-            tx_bytes,
-            signatures,
-            ExecuteTransactionRequestType::WaitForLocalExecution,
-        )
+#[sim_test]
+async fn test_get_checkpoint_end_of_epoch() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new()
+        .with_epoch_duration_ms(1000)
+        .build()
         .await?;
+    let mut epoch_rx = cluster.fullnode_handle.sui_node.subscribe_to_epoch_change();
+    let next_committee = timeout(Duration::from_secs(60), epoch_rx.recv()).await??;
+    let http_client = cluster.rpc_client();
 
-    // Check StakedSui object
-    let staked_sui: Vec<DelegatedStake> = http_client.get_delegated_stakes(*address).await?;
-    assert_eq!(1, staked_sui.len());
-    assert_eq!(1000000, staked_sui[0].staked_sui.principal());
-    assert_eq!(Some(20), staked_sui[0].staked_sui.sui_token_lock());
+    // Walk epoch 0 until its final checkpoint.
+    let mut sequence_number = 0;
+    loop {
+        let checkpoint = http_client
+            .get_checkpoint(CheckpointId::SequenceNumber(sequence_number))
+            .await?;
+        assert_eq!(0, checkpoint.epoch);
+        if checkpoint.end_of_epoch {
+            assert!(checkpoint.end_of_epoch_data.is_some());
+            assert_eq!(
+                Some(next_committee.digest()),
+                checkpoint.next_epoch_committee_digest
+            );
+            break;
+        }
+        assert!(checkpoint.next_epoch_committee_digest.is_none());
+        sequence_number += 1;
+    }
+    Ok(())
+}
 
-    assert!(matches!(
-        staked_sui[0].delegation_status,
-        DelegationStatus::Pending
-    ));
+#[sim_test]
+async fn test_get_reference_gas_price_history() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new()
+        .with_epoch_duration_ms(1000)
+        .build()
+        .await?;
+    let mut epoch_rx = cluster.fullnode_handle.sui_node.subscribe_to_epoch_change();
+    timeout(Duration::from_secs(60), epoch_rx.recv()).await??;
+    let http_client = cluster.rpc_client();
 
+    let history = http_client.get_reference_gas_price_history(10).await?;
+    let reference_gas_price = http_client.get_reference_gas_price().await?;
+    // The node keeps the state of every epoch, which follow each other without gaps
+    assert!(history.len() >= 2);
+    assert_eq!(0, history[0].0);
+    assert!(history.windows(2).all(|w| w[1].0 == w[0].0 + 1));
+    assert_eq!(reference_gas_price, history.last().unwrap().1);
+
+    let latest = http_client.get_reference_gas_price_history(1).await?;
+    assert_eq!(1, latest.len());
+    assert!(latest[0].0 >= history.last().unwrap().0);
+    assert!(http_client
+        .get_reference_gas_price_history(0)
+        .await?
+        .is_empty());
     Ok(())
 }
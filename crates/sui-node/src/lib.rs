@@ -936,7 +936,9 @@ pub async fn build_server(
 
     let mut server = JsonRpcServerBuilder::new(env!("CARGO_PKG_VERSION"), prometheus_registry);
 
-    server.register_module(ReadApi::new(state.clone()))?;
+    let mut read_api = ReadApi::new(state.clone());
+    read_api.enable_admin_methods = config.enable_rpc_admin_methods;
+    server.register_module(read_api)?;
     server.register_module(CoinReadApi::new(state.clone()))?;
     server.register_module(ThresholdBlsApi::new(state.clone()))?;
     server.register_module(TransactionBuilderApi::new(state.clone()))?;
@@ -226,6 +226,11 @@ impl RpcExampleProvider {
                 SequenceNumber::from_u64(1),
                 ObjectDigest::new(self.rng.gen()),
             )),
+            type_resolution_error: None,
+            raw_content: None,
+            receivable: true,
+            is_system_object: false,
+            system_object_kind: None,
         });
 
         Examples::new(
@@ -257,6 +262,11 @@ impl RpcExampleProvider {
                 SequenceNumber::from_u64(4),
                 ObjectDigest::new(self.rng.gen()),
             )),
+            type_resolution_error: None,
+            raw_content: None,
+            receivable: true,
+            is_system_object: false,
+            system_object_kind: None,
         });
 
         Examples::new(
@@ -279,7 +289,12 @@ impl RpcExampleProvider {
             epoch_rolling_gas_cost_summary: Default::default(),
             timestamp_ms: 1676911928,
             end_of_epoch_data: None,
+            end_of_epoch: false,
+            next_epoch_committee_digest: None,
             transactions: vec![TransactionDigest::new(self.rng.gen())],
+            epoch_first_checkpoint: Some(990),
+            epoch_checkpoint_index: Some(10),
+            epoch_checkpoint_count: None,
         };
 
         Examples::new(
@@ -335,6 +350,11 @@ impl RpcExampleProvider {
                 SequenceNumber::from_u64(1),
                 ObjectDigest::new(self.rng.gen()),
             )),
+            type_resolution_error: None,
+            raw_content: None,
+            receivable: true,
+            is_system_object: false,
+            system_object_kind: None,
         });
 
         Examples::new(
@@ -490,6 +510,8 @@ impl RpcExampleProvider {
             },
             confirmed_local_execution: None,
             checkpoint: None,
+            gas_object: None,
+            execution_digests: None,
         };
 
         (data2, signatures, recipient, obj_id, result, events)
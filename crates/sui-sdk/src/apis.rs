@@ -23,6 +23,7 @@ use sui_types::base_types::{
     ObjectID, SequenceNumber, SuiAddress, TransactionDigest, TxSequenceNumber,
 };
 use sui_types::committee::EpochId;
+use sui_types::dynamic_field::DynamicFieldType;
 use sui_types::error::TRANSACTION_NOT_FOUND_MSG_PREFIX;
 use sui_types::event::EventID;
 use sui_types::messages::{
@@ -58,11 +59,12 @@ impl ReadApi {
         object_id: ObjectID,
         cursor: Option<ObjectID>,
         limit: Option<usize>,
+        field_type: Option<DynamicFieldType>,
     ) -> SuiRpcResult<DynamicFieldPage> {
         Ok(self
             .api
             .http
-            .get_dynamic_fields(object_id, cursor, limit)
+            .get_dynamic_fields(object_id, cursor, limit, field_type)
             .await?)
     }
 
@@ -231,7 +233,7 @@ impl CoinReadApi {
         Ok(self
             .api
             .http
-            .get_coins(owner, coin_type, cursor, limit)
+            .get_coins(owner, coin_type, cursor, limit, None)
             .await?)
     }
 
@@ -256,7 +258,7 @@ impl CoinReadApi {
                     Some((item, (data, cursor, false, coin_type)))
                 } else if (cursor.is_none() && first) || cursor.is_some() {
                     let page = self
-                        .get_coins(owner, coin_type.clone(), cursor, Some(100))
+                        .get_coins(owner, coin_type.clone(), cursor, Some(100), None)
                         .await
                         .ok()?;
                     let mut data = page.data;
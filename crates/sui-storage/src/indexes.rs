@@ -6,10 +6,13 @@
 
 use anyhow::anyhow;
 use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
 use serde::{de::DeserializeOwned, Serialize};
 use std::cmp::min;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 use tracing::debug;
 use typed_store::rocks::DBOptions;
 use typed_store::rocks::{DBMap, MetricConf};
@@ -19,9 +22,10 @@ use typed_store_derive::DBMapUtils;
 
 use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest, TxSequenceNumber};
 use sui_types::base_types::{ObjectInfo, ObjectRef};
-use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName};
+use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName, DynamicFieldType};
 use sui_types::error::{SuiError, SuiResult};
 use sui_types::fp_ensure;
+use sui_types::messages::TransactionKindType;
 use sui_types::object::Owner;
 use sui_types::query::TransactionQuery;
 
@@ -29,11 +33,14 @@ use crate::default_db_options;
 
 type OwnerIndexKey = (SuiAddress, ObjectID);
 type DynamicFieldKey = (ObjectID, ObjectID);
+type TypeIndexKey = (String, ObjectID);
 
 pub const MAX_TX_RANGE_SIZE: u64 = 4096;
 
 pub const MAX_GET_OWNED_OBJECT_SIZE: usize = 256;
 
+const TYPE_INDEX_BACKFILL_BATCH_SIZE: usize = 10_000;
+
 pub struct ObjectIndexChanges {
     pub deleted_owners: Vec<OwnerIndexKey>,
     pub deleted_dynamic_fields: Vec<DynamicFieldKey>,
@@ -64,6 +71,10 @@ pub struct IndexStoreTables {
     transactions_by_move_function:
         DBMap<(ObjectID, String, String, TxSequenceNumber), TransactionDigest>,
 
+    /// Index from transaction kind to transactions of that kind.
+    #[default_options_override_fn = "transactions_by_kind_table_default_config"]
+    transactions_by_kind: DBMap<(TransactionKindType, TxSequenceNumber), TransactionDigest>,
+
     /// This is a map between the transaction digest and its timestamp (UTC timestamp in
     /// **milliseconds** since epoch 1/1/1970). A transaction digest is subjectively time stamped
     /// on a node according to the local machine time, so it varies across nodes.
@@ -92,10 +103,20 @@ pub struct IndexStoreTables {
     /// by a specific object, and their object reference.
     #[default_options_override_fn = "dynamic_field_index_table_default_config"]
     dynamic_field_index: DBMap<DynamicFieldKey, DynamicFieldInfo>,
+
+    /// This is an index of object references to currently existing address owned objects,
+    /// indexed by the composite key of the object's type (in its string form) and the object ID.
+    /// It is maintained alongside `owner_index`, so shared, immutable and object owned objects
+    /// are not covered.
+    #[default_options_override_fn = "type_index_table_default_config"]
+    type_index: DBMap<TypeIndexKey, ObjectInfo>,
 }
 
 pub struct IndexStore {
     next_sequence_number: AtomicU64,
+    /// The timestamp of the latest indexed transaction, held while a sequence number is taken so
+    /// that timestamps never decrease along the sequence numbers.
+    last_timestamp_ms: Mutex<u64>,
     tables: IndexStoreTables,
 }
 
@@ -121,6 +142,9 @@ fn transactions_by_mutated_object_id_table_default_config() -> DBOptions {
 fn transactions_by_move_function_table_default_config() -> DBOptions {
     default_db_options(None, Some(1_000_000)).0
 }
+fn transactions_by_kind_table_default_config() -> DBOptions {
+    default_db_options(None, Some(1_000_000)).0
+}
 fn timestamps_table_default_config() -> DBOptions {
     default_db_options(None, Some(1_000_000)).1
 }
@@ -130,24 +154,48 @@ fn owner_index_table_default_config() -> DBOptions {
 fn dynamic_field_index_table_default_config() -> DBOptions {
     default_db_options(None, Some(1_000_000)).0
 }
+fn type_index_table_default_config() -> DBOptions {
+    default_db_options(None, Some(1_000_000)).0
+}
 
 impl IndexStore {
     pub fn new(path: PathBuf) -> Self {
         let tables =
             IndexStoreTables::open_tables_read_write(path, MetricConf::default(), None, None);
-        let next_sequence_number = tables
-            .transaction_order
-            .iter()
-            .skip_to_last()
-            .next()
-            .map(|(seq, _)| seq + 1)
-            .unwrap_or(0)
-            .into();
+        let last = tables.transaction_order.iter().skip_to_last().next();
+        let next_sequence_number = last.map(|(seq, _)| seq + 1).unwrap_or(0).into();
+        let last_timestamp_ms = last
+            .and_then(|(_, digest)| tables.timestamps.get(&digest).ok().flatten())
+            .unwrap_or(0);
+        // Stores indexed before the type index was introduced only have the owner index.
+        if tables.type_index.is_empty() && !tables.owner_index.is_empty() {
+            Self::backfill_type_index(&tables).expect("Failed to backfill the type index");
+        }
 
         Self {
             tables,
             next_sequence_number,
+            last_timestamp_ms: Mutex::new(last_timestamp_ms),
+        }
+    }
+
+    fn backfill_type_index(tables: &IndexStoreTables) -> SuiResult {
+        let mut objects = tables
+            .owner_index
+            .iter()
+            .map(|(_, info)| ((info.type_.to_string(), info.object_id), info))
+            .peekable();
+        while objects.peek().is_some() {
+            tables
+                .type_index
+                .batch()
+                .insert_batch(
+                    &tables.type_index,
+                    objects.by_ref().take(TYPE_INDEX_BACKFILL_BATCH_SIZE),
+                )?
+                .write()?;
         }
+        Ok(())
     }
 
     pub fn index_tx(
@@ -156,11 +204,21 @@ impl IndexStore {
         active_inputs: impl Iterator<Item = ObjectID>,
         mutated_objects: impl Iterator<Item = (ObjectRef, Owner)> + Clone,
         move_functions: impl Iterator<Item = (ObjectID, Identifier, Identifier)> + Clone,
+        kinds: impl Iterator<Item = TransactionKindType>,
         object_index_changes: ObjectIndexChanges,
         digest: &TransactionDigest,
         timestamp_ms: u64,
     ) -> SuiResult<u64> {
-        let sequence = self.next_sequence_number.fetch_add(1, Ordering::SeqCst);
+        // Transactions are post-processed concurrently, so the time a transaction was seen at can
+        // be earlier than that of a transaction sequenced before it. The timestamp is taken along
+        // with the sequence number, and raised to that of the previous transaction if earlier, so
+        // that timestamps follow the sequence numbers.
+        let (sequence, timestamp_ms) = {
+            let mut last_timestamp_ms = self.last_timestamp_ms.lock().unwrap();
+            *last_timestamp_ms = (*last_timestamp_ms).max(timestamp_ms);
+            let sequence = self.next_sequence_number.fetch_add(1, Ordering::SeqCst);
+            (sequence, *last_timestamp_ms)
+        };
 
         let batch = self.tables.transactions_from_addr.batch();
 
@@ -201,6 +259,11 @@ impl IndexStore {
             }),
         )?;
 
+        let batch = batch.insert_batch(
+            &self.tables.transactions_by_kind,
+            kinds.map(|kind| ((kind, sequence), *digest)),
+        )?;
+
         let batch = batch.insert_batch(
             &self.tables.transactions_to_addr,
             mutated_objects.filter_map(|(_, owner)| {
@@ -216,6 +279,18 @@ impl IndexStore {
             std::iter::once((*digest, timestamp_ms)),
         )?;
 
+        // Type index, the types of deleted entries are read from the owner index before it is
+        // updated below.
+        let deleted_types = self.type_index_keys(&object_index_changes.deleted_owners)?;
+        let batch = batch.delete_batch(&self.tables.type_index, deleted_types.into_iter())?;
+        let batch = batch.insert_batch(
+            &self.tables.type_index,
+            object_index_changes
+                .new_owners
+                .iter()
+                .map(|(_, info)| ((info.type_.to_string(), info.object_id), info.clone())),
+        )?;
+
         // Owner index
         let batch = batch.delete_batch(
             &self.tables.owner_index,
@@ -278,9 +353,22 @@ impl IndexStore {
             TransactionQuery::FromAddress(address) => {
                 self.get_transactions_from_addr(address, cursor, limit, reverse)?
             }
+            TransactionQuery::FromAddressInTimeRange {
+                address,
+                start_time,
+                end_time,
+            } => self.get_transactions_from_addr_in_time_range(
+                address, start_time, end_time, cursor, limit, reverse,
+            )?,
+            TransactionQuery::FromAnyOf(addresses) => {
+                self.get_transactions_from_any_addr(addresses, cursor, limit, reverse)?
+            }
             TransactionQuery::ToAddress(address) => {
                 self.get_transactions_to_addr(address, cursor, limit, reverse)?
             }
+            TransactionQuery::Kind(kind) => {
+                self.get_transactions_by_kind(kind, cursor, limit, reverse)?
+            }
             TransactionQuery::All => {
                 let iter = self.tables.transaction_order.iter();
 
@@ -460,6 +548,224 @@ impl IndexStore {
         )
     }
 
+    /// The sequence number of the first transaction indexed at or after `time`, or one past the
+    /// last transaction if there is none. Timestamps are taken along with the sequence numbers in
+    /// `index_tx` and never decrease along them, so they can be binary searched.
+    fn first_sequence_at_or_after(&self, time: u64) -> SuiResult<TxSequenceNumber> {
+        let (mut low, mut high) = (TxSequenceNumber::MIN, self.next_sequence_number());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            // The first transaction from `mid` on, in case none was indexed at `mid`
+            match self.tables.transaction_order.iter().skip_to(&mid)?.next() {
+                Some((seq, digest)) if seq < high => {
+                    let timestamp = self.get_timestamp_ms(&digest)?.unwrap_or_default();
+                    if timestamp < time {
+                        low = seq + 1;
+                    } else {
+                        high = mid;
+                    }
+                }
+                _ => high = mid,
+            }
+        }
+        Ok(low)
+    }
+
+    /// Transactions sent by `addr` with a timestamp in `[start_time, end_time)`. Timestamps never
+    /// decrease along the sequence numbers, see `index_tx`, so the scan of the sender index starts
+    /// at the sequence number of the interval's bound and stops at the first transaction past its
+    /// other bound.
+    pub fn get_transactions_from_addr_in_time_range(
+        &self,
+        addr: SuiAddress,
+        start_time: u64,
+        end_time: u64,
+        cursor: TxSequenceNumber,
+        limit: Option<usize>,
+        reverse: bool,
+    ) -> SuiResult<Vec<TransactionDigest>> {
+        let iter = self.tables.transactions_from_addr.iter();
+        let iter: Box<dyn Iterator<Item = _>> = if reverse {
+            let end = self.first_sequence_at_or_after(end_time)?;
+            Box::new(iter.skip_prior_to(&(addr, cursor.min(end)))?.reverse())
+        } else {
+            let start = self.first_sequence_at_or_after(start_time)?;
+            Box::new(iter.skip_to(&(addr, cursor.max(start)))?)
+        };
+        let mut digests = vec![];
+        for ((sender, _), digest) in iter {
+            if sender != addr || matches!(limit, Some(limit) if digests.len() >= limit) {
+                break;
+            }
+            let timestamp = self.get_timestamp_ms(&digest)?.unwrap_or_default();
+            let (before, after) = (timestamp < start_time, timestamp >= end_time);
+            if (before && reverse) || (after && !reverse) {
+                break;
+            }
+            if !before && !after {
+                digests.push(digest);
+            }
+        }
+        Ok(digests)
+    }
+
+    /// Transactions sent by `addr` with a timestamp in `[start_time, end_time)`, as
+    /// `(timestamp, digest)` pairs ordered by timestamp and then by digest, starting from
+    /// `cursor` (inclusive). Timestamps never decrease along the sequence numbers, see
+    /// `index_tx`, so the scan of the sender index starts at the cursor, or at the bound of the
+    /// interval, and stops at the first timestamp past that of the `limit`-th transaction found,
+    /// so that every transaction sharing a timestamp is ordered before the page is cut.
+    pub fn get_transactions_from_addr_by_timestamp(
+        &self,
+        addr: SuiAddress,
+        start_time: u64,
+        end_time: u64,
+        cursor: Option<(u64, TransactionDigest)>,
+        limit: usize,
+        reverse: bool,
+    ) -> SuiResult<Vec<(u64, TransactionDigest)>> {
+        let cursor_seq = match cursor {
+            Some(cursor) => self.get_cursor_timestamp_bound(addr, cursor, reverse)?,
+            None => None,
+        };
+        let iter = self.tables.transactions_from_addr.iter();
+        let iter: Box<dyn Iterator<Item = _>> = if reverse {
+            let mut end = self.first_sequence_at_or_after(end_time)?;
+            if let Some(cursor_seq) = cursor_seq {
+                end = end.min(cursor_seq);
+            }
+            Box::new(iter.skip_prior_to(&(addr, end))?.reverse())
+        } else {
+            let mut start = self.first_sequence_at_or_after(start_time)?;
+            if let Some(cursor_seq) = cursor_seq {
+                start = start.max(cursor_seq);
+            }
+            Box::new(iter.skip_to(&(addr, start))?)
+        };
+        let mut entries = vec![];
+        let mut last_timestamp = None;
+        for ((sender, _), digest) in iter {
+            if sender != addr {
+                break;
+            }
+            let timestamp = self.get_timestamp_ms(&digest)?.unwrap_or_default();
+            let (before, after) = (timestamp < start_time, timestamp >= end_time);
+            let past_page = matches!(last_timestamp, Some(last)
+                if (reverse && timestamp < last) || (!reverse && timestamp > last));
+            if past_page || (before && reverse) || (after && !reverse) {
+                break;
+            }
+            let entry = (timestamp, digest);
+            let from_cursor = cursor.map_or(true, |cursor| {
+                if reverse {
+                    entry <= cursor
+                } else {
+                    entry >= cursor
+                }
+            });
+            if !before && !after && from_cursor {
+                entries.push(entry);
+                if entries.len() == limit {
+                    last_timestamp = Some(timestamp);
+                }
+            }
+        }
+        entries.sort();
+        if reverse {
+            entries.reverse();
+        }
+        entries.truncate(limit);
+        Ok(entries)
+    }
+
+    /// The sequence number to scan the transactions of `addr` from to page on from `cursor`, or
+    /// None if its digest is not indexed. Transactions sharing the cursor's timestamp have
+    /// adjacent sequence numbers, as timestamps never decrease along them, but are ordered by
+    /// digest rather than by sequence number, so the scan starts at the first of them in the
+    /// direction of the scan.
+    fn get_cursor_timestamp_bound(
+        &self,
+        addr: SuiAddress,
+        (timestamp, digest): (u64, TransactionDigest),
+        reverse: bool,
+    ) -> SuiResult<Option<TxSequenceNumber>> {
+        let Some(mut bound) = self.get_transaction_seq(&digest)? else {
+            return Ok(None);
+        };
+        // Walk back from the cursor, against the direction of the scan
+        let iter = self.tables.transactions_from_addr.iter();
+        let iter: Box<dyn Iterator<Item = _>> = if reverse {
+            Box::new(iter.skip_to(&(addr, bound))?)
+        } else {
+            Box::new(iter.skip_prior_to(&(addr, bound))?.reverse())
+        };
+        for ((sender, seq), digest) in iter {
+            if sender != addr || self.get_timestamp_ms(&digest)?.unwrap_or_default() != timestamp {
+                break;
+            }
+            bound = seq;
+        }
+        Ok(Some(bound))
+    }
+
+    /// Transactions sent by any of `addrs`, merged by sequence number. Each sender index is
+    /// scanned for at most `limit` entries from the cursor, which is enough to fill the merged
+    /// page.
+    pub fn get_transactions_from_any_addr(
+        &self,
+        mut addrs: Vec<SuiAddress>,
+        cursor: TxSequenceNumber,
+        limit: Option<usize>,
+        reverse: bool,
+    ) -> SuiResult<Vec<TransactionDigest>> {
+        addrs.sort();
+        addrs.dedup();
+        let mut transactions = vec![];
+        for addr in addrs {
+            let iter = self.tables.transactions_from_addr.iter();
+            let iter: Box<dyn Iterator<Item = _>> = if reverse {
+                Box::new(iter.skip_prior_to(&(addr, cursor))?.reverse())
+            } else {
+                Box::new(iter.skip_to(&(addr, cursor))?)
+            };
+            let iter = iter
+                .take_while(|((sender, _), _)| *sender == addr)
+                .map(|((_, seq), digest)| (seq, digest));
+            if let Some(limit) = limit {
+                transactions.extend(iter.take(limit));
+            } else {
+                transactions.extend(iter);
+            }
+        }
+        if reverse {
+            transactions.sort_by(|(a, _), (b, _)| b.cmp(a));
+        } else {
+            transactions.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+        let iter = transactions.into_iter().map(|(_, digest)| digest);
+        Ok(if let Some(limit) = limit {
+            iter.take(limit).collect()
+        } else {
+            iter.collect()
+        })
+    }
+
+    pub fn get_transactions_by_kind(
+        &self,
+        kind: TransactionKindType,
+        cursor: TxSequenceNumber,
+        limit: Option<usize>,
+        reverse: bool,
+    ) -> SuiResult<Vec<TransactionDigest>> {
+        Self::get_transactions_from_index(
+            &self.tables.transactions_by_kind,
+            kind,
+            cursor,
+            limit,
+            reverse,
+        )
+    }
+
     pub fn get_transactions_by_move_function(
         &self,
         package: ObjectID,
@@ -524,6 +830,70 @@ impl IndexStore {
         )
     }
 
+    /// Returns the most recent transaction sent from or to `addr`, if any.
+    pub fn get_latest_transaction_by_address(
+        &self,
+        addr: SuiAddress,
+    ) -> SuiResult<Option<TransactionDigest>> {
+        let mut latest = None;
+        for index in [
+            &self.tables.transactions_from_addr,
+            &self.tables.transactions_to_addr,
+        ] {
+            let digest = Self::get_transactions_from_index(
+                index,
+                addr,
+                TxSequenceNumber::MAX,
+                Some(1),
+                true,
+            )?
+            .pop();
+            if let Some(digest) = digest {
+                let seq = self.get_transaction_seq(&digest)?;
+                if latest.map_or(true, |(latest_seq, _)| seq > latest_seq) {
+                    latest = Some((seq, digest));
+                }
+            }
+        }
+        Ok(latest.map(|(_, digest)| digest))
+    }
+
+    /// The subset of `digests` sent from or to `addr`, in the order given. Only the part of the
+    /// address indexes between the lowest and highest sequence numbers of `digests` is scanned,
+    /// so this is cheap for a batch of nearby transactions such as a checkpoint's contents.
+    pub fn filter_transactions_involving_addr(
+        &self,
+        addr: SuiAddress,
+        digests: Vec<TransactionDigest>,
+    ) -> SuiResult<Vec<TransactionDigest>> {
+        let mut seqs = vec![];
+        for digest in &digests {
+            if let Some(seq) = self.get_transaction_seq(digest)? {
+                seqs.push(seq);
+            }
+        }
+        let (Some(first), Some(last)) = (seqs.iter().min(), seqs.iter().max()) else {
+            return Ok(vec![]);
+        };
+        let mut involved = HashSet::new();
+        for index in [
+            &self.tables.transactions_from_addr,
+            &self.tables.transactions_to_addr,
+        ] {
+            involved.extend(
+                index
+                    .iter()
+                    .skip_to(&(addr, *first))?
+                    .take_while(|((owner, seq), _)| *owner == addr && seq <= last)
+                    .map(|(_, digest)| digest),
+            );
+        }
+        Ok(digests
+            .into_iter()
+            .filter(|digest| involved.contains(digest))
+            .collect())
+    }
+
     pub fn get_transaction_seq(
         &self,
         digest: &TransactionDigest,
@@ -536,8 +906,9 @@ impl IndexStore {
         object: ObjectID,
         cursor: Option<ObjectID>,
         limit: usize,
+        field_type: Option<DynamicFieldType>,
     ) -> SuiResult<Vec<DynamicFieldInfo>> {
-        debug!(?object, "get_dynamic_fields");
+        debug!(?object, ?field_type, "get_dynamic_fields");
         let cursor = cursor.unwrap_or(ObjectID::ZERO);
         Ok(self
             .tables
@@ -547,6 +918,11 @@ impl IndexStore {
             .skip_to(&(object, cursor))?
             .take_while(|((object_owner, _), _)| (object_owner == &object))
             .map(|(_, object_info)| object_info)
+            .filter(|object_info| {
+                field_type
+                    .as_ref()
+                    .map_or(true, |field_type| &object_info.type_ == field_type)
+            })
             .take(limit)
             .collect())
     }
@@ -571,12 +947,55 @@ impl IndexStore {
             .map(|(_, object_info)| object_info.object_id))
     }
 
+    /// Returns up to `limit` address owned objects of the given type, ordered by object id and
+    /// starting from `cursor` (inclusive).
+    pub fn get_objects_by_type(
+        &self,
+        type_: &StructTag,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> SuiResult<Vec<ObjectInfo>> {
+        debug!(?type_, ?cursor, ?limit, "get_objects_by_type");
+        let type_ = type_.to_string();
+        let cursor = cursor.unwrap_or(ObjectID::ZERO);
+        Ok(self
+            .tables
+            .type_index
+            .iter()
+            // The object id 0 is the smallest possible
+            .skip_to(&(type_.clone(), cursor))?
+            .take_while(|((object_type, _), _)| object_type == &type_)
+            .take(limit)
+            .map(|(_, object_info)| object_info)
+            .collect())
+    }
+
+    fn type_index_keys(&self, owner_keys: &[OwnerIndexKey]) -> SuiResult<Vec<TypeIndexKey>> {
+        Ok(self
+            .tables
+            .owner_index
+            .multi_get(owner_keys)?
+            .into_iter()
+            .flatten()
+            .map(|info| (info.type_.to_string(), info.object_id))
+            .collect())
+    }
+
     pub fn get_owner_objects(&self, owner: SuiAddress) -> SuiResult<Vec<ObjectInfo>> {
         Ok(self
             .get_owner_objects_iterator(owner, ObjectID::ZERO, MAX_GET_OWNED_OBJECT_SIZE)?
             .collect())
     }
 
+    /// The owner index entry of an object owned by `owner`, if any.
+    pub fn get_owner_object_info(
+        &self,
+        owner: SuiAddress,
+        object_id: ObjectID,
+    ) -> SuiResult<Option<ObjectInfo>> {
+        Ok(self.tables.owner_index.get(&(owner, object_id))?)
+    }
+
     /// starting_object_id can be used to implement pagination, where a client remembers the last
     /// object id of each page, and use it to query the next page.
     pub fn get_owner_objects_iterator(
@@ -600,6 +1019,13 @@ impl IndexStore {
 
     pub fn insert_genesis_objects(&self, object_index_changes: ObjectIndexChanges) -> SuiResult {
         let batch = self.tables.owner_index.batch();
+        let batch = batch.insert_batch(
+            &self.tables.type_index,
+            object_index_changes
+                .new_owners
+                .iter()
+                .map(|(_, info)| ((info.type_.to_string(), info.object_id), info.clone())),
+        )?;
         let batch = batch.insert_batch(
             &self.tables.owner_index,
             object_index_changes.new_owners.into_iter(),
@@ -616,3 +1042,242 @@ impl IndexStore {
         self.tables.owner_index.is_empty()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sui_types::base_types::{ObjectDigest, ObjectType};
+    use sui_types::gas_coin::GasCoin;
+
+    fn index_tx_at(store: &IndexStore, sender: SuiAddress, timestamp_ms: u64) -> TransactionDigest {
+        let digest = TransactionDigest::random();
+        let object_index_changes = ObjectIndexChanges {
+            deleted_owners: vec![],
+            deleted_dynamic_fields: vec![],
+            new_owners: vec![],
+            new_dynamic_fields: vec![],
+        };
+        store
+            .index_tx(
+                sender,
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::empty(),
+                object_index_changes,
+                &digest,
+                timestamp_ms,
+            )
+            .unwrap();
+        digest
+    }
+
+    #[tokio::test]
+    async fn test_type_index_backfilled_from_owner_index() {
+        let working_dir = tempfile::tempdir().unwrap();
+        let path = working_dir.path().join("indexes");
+        let owner = SuiAddress::random_for_testing_only();
+        let mut expected = (0..5)
+            .map(|_| ObjectInfo {
+                object_id: ObjectID::random(),
+                version: Default::default(),
+                digest: ObjectDigest::random(),
+                type_: ObjectType::Struct(GasCoin::type_()),
+                owner: Owner::AddressOwner(owner),
+                previous_transaction: TransactionDigest::random(),
+            })
+            .collect::<Vec<_>>();
+        expected.sort_by_key(|info| info.object_id);
+        {
+            // A store written before the type index existed
+            let store = IndexStore::new(path.clone());
+            store
+                .tables
+                .owner_index
+                .multi_insert(expected.iter().map(|info| ((owner, info.object_id), info)))
+                .unwrap();
+            assert!(store
+                .get_objects_by_type(&GasCoin::type_(), None, 10)
+                .unwrap()
+                .is_empty());
+        }
+
+        let store = IndexStore::new(path);
+        assert_eq!(
+            expected,
+            store
+                .get_objects_by_type(&GasCoin::type_(), None, 10)
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_transactions_from_addr_in_time_range() {
+        let working_dir = tempfile::tempdir().unwrap();
+        let store = IndexStore::new(working_dir.path().join("indexes"));
+        let sender = SuiAddress::random_for_testing_only();
+        let other = SuiAddress::random_for_testing_only();
+
+        let mut expected = vec![];
+        for timestamp in [10, 20, 20, 30, 40, 50] {
+            let digest = index_tx_at(&store, sender, timestamp);
+            if (20..40).contains(&timestamp) {
+                expected.push(digest);
+            }
+            index_tx_at(&store, other, timestamp);
+        }
+
+        // Every other transaction is from the other sender
+        assert_eq!(0, store.first_sequence_at_or_after(0).unwrap());
+        assert_eq!(2, store.first_sequence_at_or_after(20).unwrap());
+        assert_eq!(2, store.first_sequence_at_or_after(11).unwrap());
+        assert_eq!(6, store.first_sequence_at_or_after(21).unwrap());
+        assert_eq!(12, store.first_sequence_at_or_after(51).unwrap());
+
+        let query = |cursor, limit, reverse| {
+            store
+                .get_transactions_from_addr_in_time_range(sender, 20, 40, cursor, limit, reverse)
+                .unwrap()
+        };
+        assert_eq!(expected, query(TxSequenceNumber::MIN, None, false));
+        assert_eq!(
+            expected[..2].to_vec(),
+            query(TxSequenceNumber::MIN, Some(2), false)
+        );
+        // The cursor is inclusive, and the second transaction at 20 has sequence number 4
+        assert_eq!(expected[1..].to_vec(), query(4, None, false));
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        assert_eq!(reversed, query(TxSequenceNumber::MAX, None, true));
+        assert_eq!(reversed[1..].to_vec(), query(4, None, true));
+    }
+
+    #[tokio::test]
+    async fn test_timestamps_follow_sequence_numbers() {
+        let working_dir = tempfile::tempdir().unwrap();
+        let store = IndexStore::new(working_dir.path().join("indexes"));
+        let sender = SuiAddress::random_for_testing_only();
+
+        // The transaction sequenced third was seen before the second one, and is indexed at the
+        // timestamp of the second one
+        let digests = [10, 30, 20, 40].map(|timestamp| index_tx_at(&store, sender, timestamp));
+        let timestamps = digests
+            .iter()
+            .map(|digest| store.get_timestamp_ms(digest).unwrap().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![10, 30, 30, 40], timestamps);
+        assert_eq!(1, store.first_sequence_at_or_after(20).unwrap());
+        assert_eq!(3, store.first_sequence_at_or_after(31).unwrap());
+
+        // Neither query skips the late transaction at a window edge
+        assert_eq!(
+            digests[1..3].to_vec(),
+            store
+                .get_transactions_from_addr_in_time_range(sender, 15, 35, 0, None, false)
+                .unwrap()
+        );
+        assert_eq!(
+            vec![digests[2], digests[1]],
+            store
+                .get_transactions_from_addr_in_time_range(
+                    sender,
+                    15,
+                    35,
+                    TxSequenceNumber::MAX,
+                    None,
+                    true
+                )
+                .unwrap()
+        );
+
+        // Timestamps keep following the sequence numbers once the store is reopened
+        drop(store);
+        let store = IndexStore::new(working_dir.path().join("indexes"));
+        let digest = index_tx_at(&store, sender, 35);
+        assert_eq!(Some(40), store.get_timestamp_ms(&digest).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_transactions_from_addr_by_timestamp() {
+        let working_dir = tempfile::tempdir().unwrap();
+        let store = IndexStore::new(working_dir.path().join("indexes"));
+        let sender = SuiAddress::random_for_testing_only();
+        let other = SuiAddress::random_for_testing_only();
+
+        // Five transactions share a timestamp, which a page of two splits
+        let mut expected = vec![];
+        for timestamp in [10, 20, 20, 20, 20, 20, 30, 40] {
+            expected.push((timestamp, index_tx_at(&store, sender, timestamp)));
+            index_tx_at(&store, other, timestamp);
+        }
+        expected.retain(|(timestamp, _)| (15..40).contains(timestamp));
+        expected.sort();
+
+        for reverse in [false, true] {
+            // Read one extra transaction for the next cursor, as the RPC does
+            let mut paged = vec![];
+            let mut cursor = None;
+            loop {
+                let mut page = store
+                    .get_transactions_from_addr_by_timestamp(sender, 15, 40, cursor, 3, reverse)
+                    .unwrap();
+                cursor = page.get(2).copied();
+                page.truncate(2);
+                paged.extend(page);
+                if cursor.is_none() {
+                    break;
+                }
+            }
+            let mut expected = expected.clone();
+            if reverse {
+                expected.reverse();
+            }
+            assert_eq!(expected, paged);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_transactions_from_addr_by_timestamp_seen_out_of_order() {
+        let working_dir = tempfile::tempdir().unwrap();
+        let store = IndexStore::new(working_dir.path().join("indexes"));
+        let sender = SuiAddress::random_for_testing_only();
+
+        // Transactions sequenced after the one seen at 30 were seen earlier, they are indexed at
+        // 30 and none of them falls between two pages
+        let mut expected = vec![];
+        for timestamp in [10, 30, 20, 20, 25, 40] {
+            let digest = index_tx_at(&store, sender, timestamp);
+            expected.push((store.get_timestamp_ms(&digest).unwrap().unwrap(), digest));
+        }
+        assert_eq!(
+            vec![10, 30, 30, 30, 30, 40],
+            expected
+                .iter()
+                .map(|(timestamp, _)| *timestamp)
+                .collect::<Vec<_>>()
+        );
+        expected.retain(|(timestamp, _)| (15..45).contains(timestamp));
+        expected.sort();
+
+        for reverse in [false, true] {
+            let mut paged = vec![];
+            let mut cursor = None;
+            loop {
+                let mut page = store
+                    .get_transactions_from_addr_by_timestamp(sender, 15, 45, cursor, 2, reverse)
+                    .unwrap();
+                cursor = page.get(1).copied();
+                page.truncate(1);
+                paged.extend(page);
+                if cursor.is_none() {
+                    break;
+                }
+            }
+            let mut expected = expected.clone();
+            if reverse {
+                expected.reverse();
+            }
+            assert_eq!(expected, paged);
+        }
+    }
+}
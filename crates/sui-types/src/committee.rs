@@ -134,6 +134,10 @@ impl Committee {
         self.epoch
     }
 
+    pub fn digest(&self) -> CommitteeDigest {
+        sha3_hash(self)
+    }
+
     pub fn public_key(&self, authority: &AuthorityName) -> SuiResult<AuthorityPublicKey> {
         match self.expanded_keys.get(authority) {
             // TODO: Check if this is unnecessary copying.
@@ -546,6 +546,12 @@ pub enum SuiError {
     #[error("This Move function is currently disabled and not available for call")]
     BlockedMoveFunction,
 
+    #[error(
+        "{TRANSACTION_NOT_FOUND_MSG_PREFIX} with effects digest [{:?}].",
+        digest
+    )]
+    TransactionEffectsNotFound { digest: TransactionEffectsDigest },
+
     #[error("unknown error: {0}")]
     Unknown(String),
 }
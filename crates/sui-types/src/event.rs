@@ -9,7 +9,7 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::IdentStr;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
-use move_core_types::value::MoveStruct;
+use move_core_types::value::{MoveStruct, MoveStructLayout};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -531,7 +531,14 @@ impl Event {
             ObjectFormatOptions::default(),
             resolver,
         )?;
-        MoveStruct::simple_deserialize(contents, &layout).map_err(|e| {
+        Self::move_event_to_move_struct_with_layout(contents, &layout)
+    }
+
+    pub fn move_event_to_move_struct_with_layout(
+        contents: &[u8],
+        layout: &MoveStructLayout,
+    ) -> Result<MoveStruct, SuiError> {
+        MoveStruct::simple_deserialize(contents, layout).map_err(|e| {
             SuiError::ObjectSerializationError {
                 error: e.to_string(),
             }
@@ -43,6 +43,7 @@ use std::{
     iter,
 };
 use strum::IntoStaticStr;
+use strum_macros::EnumDiscriminants;
 use sui_protocol_config::SupportedProtocolVersions;
 use tap::Pipe;
 use tracing::debug;
@@ -215,7 +216,13 @@ impl GenesisObject {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize, IntoStaticStr)]
+#[derive(
+    Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize, IntoStaticStr, EnumDiscriminants,
+)]
+#[strum_discriminants(
+    name(TransactionKindType),
+    derive(Serialize, Deserialize, schemars::JsonSchema, Hash)
+)]
 pub enum SingleTransactionKind {
     /// Initiate an object transfer between addresses
     TransferObject(TransferObject),
@@ -211,6 +211,11 @@ impl MultiSig {
         self.multisig_pk.validate()?;
         Ok(())
     }
+
+    /// Return the indices of the public keys in [struct MultiSigPublicKey] that signed.
+    pub fn signer_indices(&self) -> Vec<u32> {
+        self.bitmap.iter().collect()
+    }
 }
 
 /// The struct that contains the public key used for authenticating a MultiSig.
@@ -7,6 +7,7 @@ use serde::Serialize;
 
 use crate::base_types::{SuiAddress, TransactionDigest};
 use crate::event::EventType;
+use crate::messages::TransactionKindType;
 use crate::object::Owner;
 use crate::ObjectID;
 
@@ -26,8 +27,25 @@ pub enum TransactionQuery {
     MutatedObject(ObjectID),
     /// Query by sender address.
     FromAddress(SuiAddress),
+    /// Query by sender address, returning only transactions with a timestamp in the
+    /// [start_time, end_time) interval.
+    #[serde(rename_all = "camelCase")]
+    FromAddressInTimeRange {
+        /// the sender address
+        address: SuiAddress,
+        /// left endpoint of time interval, milliseconds since epoch, inclusive
+        start_time: u64,
+        /// right endpoint of time interval, milliseconds since epoch, exclusive
+        end_time: u64,
+    },
+    /// Query by any of several sender addresses, merging their transactions in sequence order.
+    FromAnyOf(Vec<SuiAddress>),
     /// Query by recipient address.
     ToAddress(SuiAddress),
+    /// Query by transaction kind, one of `TransferObject`, `Publish`, `Call`, `TransferSui`,
+    /// `Pay`, `PaySui`, `PayAllSui`, `ChangeEpoch`, `Genesis`, `ConsensusCommitPrologue` or
+    /// `ProgrammableTransaction`. A batch transaction matches every kind it contains.
+    Kind(TransactionKindType),
 }
 
 #[derive(Clone, Debug, JsonSchema, Serialize, Deserialize)]
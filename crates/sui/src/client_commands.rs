@@ -541,7 +541,7 @@ impl SuiClientCommands {
                 let client = context.get_client().await?;
                 let df_read = client
                     .read_api()
-                    .get_dynamic_fields(id, cursor, Some(limit))
+                    .get_dynamic_fields(id, cursor, Some(limit), None)
                     .await?;
                 SuiClientCommandResult::DynamicFieldQuery(df_read)
             }
@@ -26,7 +26,8 @@ use sui_types::event::Event;
 use sui_types::message_envelope::Message;
 use sui_types::messages::{
     ExecuteTransactionRequest, ExecuteTransactionRequestType, ExecuteTransactionResponse, GasData,
-    QuorumDriverResponse, SingleTransactionKind, TransactionData, TransactionKind, TransferObject,
+    QuorumDriverResponse, SingleTransactionKind, TransactionData, TransactionKind,
+    TransactionKindType, TransferObject,
 };
 use sui_types::object::{Object, ObjectRead, Owner, PastObjectRead};
 use sui_types::query::{EventQuery, TransactionQuery};
@@ -43,7 +44,7 @@ use test_utils::messages::{
 };
 use test_utils::network::{start_fullnode_from_config, TestClusterBuilder};
 use test_utils::transaction::{
-    create_devnet_nft, delete_devnet_nft, increment_counter,
+    create_devnet_nft, delete_devnet_nft, increment_counter, publish_basics_package,
     publish_basics_package_and_make_counter, transfer_coin,
 };
 use test_utils::transaction::{wait_for_all_txes, wait_for_tx};
@@ -239,6 +240,52 @@ async fn test_full_node_move_function_index() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_full_node_transaction_kind_index() -> Result<(), anyhow::Error> {
+    telemetry_subscribers::init_for_testing();
+    let mut test_cluster = TestClusterBuilder::new().build().await?;
+    let node = &test_cluster.fullnode_handle.sui_node;
+    let sender = test_cluster.get_address_0();
+    let context = &mut test_cluster.wallet;
+
+    let package_ref = publish_basics_package(context, sender).await;
+    let publish_digest = context
+        .get_object_ref(package_ref.0)
+        .await?
+        .into_object()?
+        .previous_transaction;
+    let (_, _, _, transfer_digest, _, _) = transfer_coin(context).await?;
+    wait_for_tx(publish_digest, node.state().clone()).await;
+    wait_for_tx(transfer_digest, node.state().clone()).await;
+
+    let txes = node.state().get_transactions(
+        TransactionQuery::Kind(TransactionKindType::Publish),
+        None,
+        None,
+        false,
+    )?;
+    assert_eq!(txes, vec![publish_digest]);
+
+    let txes = node.state().get_transactions(
+        TransactionQuery::Kind(TransactionKindType::TransferObject),
+        None,
+        None,
+        false,
+    )?;
+    assert_eq!(txes, vec![transfer_digest]);
+
+    let txes = node.state().get_transactions(
+        TransactionQuery::Kind(TransactionKindType::Call),
+        None,
+        None,
+        false,
+    )?;
+    assert!(!txes.contains(&transfer_digest));
+    assert!(!txes.contains(&publish_digest));
+
+    Ok(())
+}
+
 #[sim_test]
 async fn test_full_node_indexes() -> Result<(), anyhow::Error> {
     telemetry_subscribers::init_for_testing();
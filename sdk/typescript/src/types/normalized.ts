@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import {
+  any,
   array,
   Infer,
   object,
@@ -13,6 +14,7 @@ import {
   literal,
   record,
   is,
+  optional,
 } from 'superstruct';
 
 export type SuiMoveFunctionArgTypesResponse = Infer<
@@ -40,6 +42,15 @@ export const SuiMoveVisibility = union([
 ]);
 export type SuiMoveVisibility = Infer<typeof SuiMoveVisibility>;
 
+export const SuiMoveFunctionComplexity = union([
+  literal('Low'),
+  literal('Medium'),
+  literal('High'),
+]);
+export type SuiMoveFunctionComplexity = Infer<
+  typeof SuiMoveFunctionComplexity
+>;
+
 export const SuiMoveAbilitySet = object({
   abilities: array(string()),
 });
@@ -132,13 +143,16 @@ export const SuiMoveNormalizedStructType = define<SuiMoveNormalizedStructType>(
 export const SuiMoveNormalizedFunction = object({
   visibility: SuiMoveVisibility,
   is_entry: boolean(),
+  is_init: optional(boolean()),
   type_parameters: array(SuiMoveAbilitySet),
   parameters: array(SuiMoveNormalizedType),
   return_: array(SuiMoveNormalizedType),
+  complexity_hint: optional(SuiMoveFunctionComplexity),
 });
 export type SuiMoveNormalizedFunction = Infer<typeof SuiMoveNormalizedFunction>;
 
 export const SuiMoveNormalizedField = object({
+  index: optional(number()),
   name: string(),
   type_: SuiMoveNormalizedType,
 });
@@ -148,9 +162,19 @@ export const SuiMoveNormalizedStruct = object({
   abilities: SuiMoveAbilitySet,
   type_parameters: array(SuiMoveStructTypeParameter),
   fields: array(SuiMoveNormalizedField),
+  fixed_size: optional(union([number(), literal(null)])),
 });
 export type SuiMoveNormalizedStruct = Infer<typeof SuiMoveNormalizedStruct>;
 
+export const SuiMoveNormalizedConstant = object({
+  type: SuiMoveNormalizedType,
+  /** The decoded value, null if the constant could not be decoded */
+  value: any(),
+  /** The BCS serialized value, Base64 encoded */
+  bytes: string(),
+});
+export type SuiMoveNormalizedConstant = Infer<typeof SuiMoveNormalizedConstant>;
+
 export const SuiMoveNormalizedModule = object({
   file_format_version: number(),
   address: string(),
@@ -158,6 +182,7 @@ export const SuiMoveNormalizedModule = object({
   friends: array(SuiMoveModuleId),
   structs: record(string(), SuiMoveNormalizedStruct),
   exposed_functions: record(string(), SuiMoveNormalizedFunction),
+  constants: optional(array(SuiMoveNormalizedConstant)),
 });
 export type SuiMoveNormalizedModule = Infer<typeof SuiMoveNormalizedModule>;
 
@@ -92,6 +92,23 @@ export const SuiObject = object({
    */
   storageRebate: number(),
   reference: SuiObjectRef,
+  /** Why the Move type of the object could not be resolved, `data` then has no fields */
+  typeResolutionError: optional(string()),
+  /** Base64 BCS bytes of the object's Move value, present along with `typeResolutionError` */
+  rawContent: optional(string()),
+  /** Whether the object is owned by an address, and so can be used as a `Receiving` argument */
+  receivable: optional(boolean()),
+  /** Whether the object is one of the well-known objects of the system */
+  isSystemObject: optional(boolean()),
+  /** Which well-known object of the system this is, present when `isSystemObject` is set */
+  systemObjectKind: optional(
+    union([
+      literal('MoveStdlib'),
+      literal('SuiFramework'),
+      literal('SystemState'),
+      literal('Clock'),
+    ]),
+  ),
 });
 export type SuiObject = Infer<typeof SuiObject>;
 
@@ -125,6 +125,8 @@ export const SuiTransactionData = object({
   transactions: array(SuiTransactionKind),
   sender: SuiAddress,
   gasData: SuiGasData,
+  gasBudget: optional(number()),
+  gasPrice: optional(number()),
 });
 export type SuiTransactionData = Infer<typeof SuiTransactionData>;
 